@@ -1,83 +1,187 @@
 use crate::plugins::aabb::AABB;
 use crate::plugins::enemy::*;
+use crate::plugins::enemy_behaviour::*;
+use crate::plugins::enemy_kind::EnemySprites;
 use crate::plugins::player::*;
 use crate::plugins::texture_handling::TextureAssets;
 use crate::plugins::timers::*;
 use crate::plugins::weapons::*;
-use crate::plugins::game_state::GameState;
+use crate::plugins::game_state::{gameplay_tick, GameState, GameplaySet};
 use crate::plugins::ground::{setup_ground, update_ground_chunks};
 use bevy::asset::AssetServer;
+use bevy::ecs::schedule::ExecutorKind;
+use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
 use crate::plugins::audio::load_audio_assets;
-use crate::plugins::main_menu::MainMenuPlugin;
-use crate::plugins::score::{setup_score_ui, update_score_ui, GameScore};
-use crate::plugins::weapon_stats::spawn_weapons_for_player;
+use crate::plugins::boss::BossPlugin;
+use crate::plugins::character::{CharacterDefinition, CharacterDefinitions, CharacterId, CharacterPlugin};
+use crate::plugins::character_select::CharacterSelectPlugin;
+use crate::plugins::chest_reward::ChestRewardPlugin;
+use crate::plugins::damage::{apply_damage, DamagePlugin};
+use crate::plugins::localization::{LocalizationPlugin, Localized, UiText};
+use crate::plugins::loot::{LootPlugin, LootTables};
+use crate::plugins::xp_orb::XpOrbPlugin;
+use crate::plugins::main_menu::{button_hover_effect, MainMenuPlugin};
+use crate::plugins::pause_menu::PauseMenuPlugin;
+use crate::plugins::player_damage::{apply_player_damage, play_death_sequence, PlayerDamageEvent, PlayerDamagePlugin, PlayerDeath};
+use crate::plugins::replay::{live_input, PlayerInput, ReplayPlugin, ReplaySettings};
+use crate::plugins::rng::{GameRng, GameRngPlugin, SeedSettings};
+use crate::plugins::headless::HeadlessPlugin;
+use crate::plugins::hit_feedback::HitFeedbackPlugin;
+use crate::plugins::hud::HudPlugin;
+use crate::plugins::input_action::{ActionState, InputAction, InputActionPlugin};
+use crate::plugins::simulation::{SimulationPlugin, SimulationSettings};
+use crate::plugins::score::GameScore;
+use crate::plugins::screen_shake::ScreenShakePlugin;
+use crate::plugins::settings::SettingsPlugin;
+use crate::plugins::settings_menu::SettingsMenuPlugin;
+use crate::plugins::spatial_grid::{rebuild_spatial_grid, SpatialGrid, SpatialLayer};
+use crate::plugins::spawn_director::{run_spawn_director, SpawnDirector, SpawnDirectorPlugin};
+use crate::plugins::passive_item::{PassiveInventory, PassiveItemPlugin, PassiveItems, StartingStats};
+use crate::plugins::power_up_shop::PowerUpShopPlugin;
+use crate::plugins::profile::{bank_run, ProfilePlugin, RunLoadout};
+use crate::plugins::run_save::RunSavePlugin;
+use crate::plugins::status_effect::StatusEffectPlugin;
+use crate::plugins::weapon_definition::{WeaponDefinition, WeaponDefinitionPlugin, WeaponDefinitions};
+use crate::plugins::weapon_evolution::{WeaponEvolutionPlugin, WeaponEvolutions};
+use crate::plugins::weapon_stats::{spawn_starting_weapon, sync_weapon_stats};
 use crate::plugins::weapon_upgrade::*;
 
 mod plugins;
+#[cfg(test)]
+mod tests;
 
 fn main() {
-    App::new()
-        .add_plugins(DefaultPlugins)
-        .init_state::<GameState>()
-        .insert_state(GameState::MainMenu)
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let mut app = App::new();
+    // `--simulate <dakika>`: pencere, render ve ses olmadan botla denge testi
+    match SimulationSettings::from_args(args.iter().cloned()) {
+        Some(simulation) => app.add_plugins((HeadlessPlugin, SimulationPlugin(simulation))),
+        None => app.add_plugins(DefaultPlugins),
+    };
+    app.insert_resource(SeedSettings::from_args(args.iter().cloned()))
+        .insert_resource(ReplaySettings::from_args(args))
+        .add_plugins(GamePlugin)
+        .run();
+}
+
+/// Oyunun state'leri, kaynakları ve sistemleri - pencereli oyun ve headless simülasyon aynı kurulumu kullanır
+pub struct GamePlugin;
+
+impl Plugin for GamePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_state::<GameState>()
+            .insert_state(GameState::MainMenu)
         
-        // Resources
-        .init_resource::<GameScore>()
-        .init_resource::<UpgradeChoices>()
-        .init_resource::<EnemyPowerUpTimer>()
-        // Events
-        .add_message::<LevelUpEvent>()
-        .add_message::<UpgradeSelectedEvent>()
+            // Resources
+            .init_resource::<GameScore>()
+            .init_resource::<UpgradeChoices>()
+            .init_resource::<UpgradeFocus>()
+            // Events
+            .add_message::<LevelUpEvent>()
+            .add_message::<UpgradeSelectedEvent>()
+            .add_message::<RerollEvent>()
+            .add_message::<EnemyDiedEvent>()
+            .add_message::<WeaponHitEvent>()
+            .add_message::<PlayerHealedEvent>()
         
-        // Resources
-        .init_resource::<TextureAssets>()
-        .insert_resource(Atlases::default())
-        .add_systems(Startup, (minimal_setup, setup_score_ui, setup_ground, load_audio_assets))
-        .init_resource::<EnemySpawnTimer>()
-        .init_resource::<MoveTimer>()
-        .init_resource::<PlayerHealthReduceTimer>()
-        .add_plugins(MainMenuPlugin)
-        .add_systems(
-            Update,
-            (
-                prepare_atlases_and_spawn.run_if(in_state(GameState::Loading)),
+            // Resources
+            .init_resource::<TextureAssets>()
+            .init_resource::<EnemySprites>()
+            .insert_resource(Atlases::default())
+            .add_systems(Startup, (minimal_setup, setup_ground, load_audio_assets))
+            .init_resource::<MoveTimer>()
+            .init_resource::<SpatialGrid>()
+            .add_plugins(SettingsPlugin)
+            .add_plugins(InputActionPlugin)
+            .add_plugins(LocalizationPlugin)
+            .add_plugins(GameRngPlugin)
+            .add_plugins(ReplayPlugin)
+            .add_plugins(MainMenuPlugin)
+            .add_plugins(PauseMenuPlugin)
+            .add_plugins(SettingsMenuPlugin)
+            .add_plugins(ProfilePlugin)
+            .add_plugins(PowerUpShopPlugin)
+            .add_plugins(RunSavePlugin)
+            .add_plugins(CharacterPlugin)
+            .add_plugins(CharacterSelectPlugin)
+            .add_plugins(WeaponDefinitionPlugin)
+            .add_plugins(WeaponEvolutionPlugin)
+            .add_plugins(PassiveItemPlugin)
+            .add_plugins(StatusEffectPlugin)
+            .add_plugins(DamagePlugin)
+            .add_plugins(PlayerDamagePlugin)
+            .add_plugins(HitFeedbackPlugin)
+            .add_plugins(HudPlugin)
+            .add_plugins(ScreenShakePlugin)
+            .add_plugins(SpawnDirectorPlugin)
+            .add_plugins(BossPlugin)
+            .add_plugins(LootPlugin)
+            .add_plugins(ChestRewardPlugin)
+            .add_plugins(XpOrbPlugin)
+            // Oyun mantığı sabit tick'te ve tek thread'de: aynı seed + aynı girdi = aynı koşu
+            .configure_sets(FixedUpdate, GameplaySet.run_if(gameplay_tick))
+            .edit_schedule(FixedUpdate, |schedule| {
+                schedule.set_executor_kind(ExecutorKind::SingleThreaded);
+            })
+            .add_systems(Update, prepare_atlases_and_spawn.run_if(in_state(GameState::Loading)))
+            .add_systems(
+                FixedUpdate,
                 (
-                    collect_xp_with_magnet,
-                    magnetite_xp_to_player,
-                    enemy_collision_with_enemy,
-                    update_score_ui,
-                    update_ground_chunks,
-                    follow,
-                    move_player_addicted_weapons,
-                    fire_laser_weapons,
-                    fire_rocket_weapons,
-                    move_player,
-                    spawn_enemies,
-                    reduce_player_health,
-                    move_projectiles,
-                    despawn_explosions,
-                    collect_xp,
-
-                ).run_if(in_state(GameState::Playing)),
-            ),
-        )
-        .add_systems(Update, (show_upgrade_choices_on_level_up,
-                     handle_upgrade_input, apply_weapon_upgrade).run_if(in_state(GameState::UpgradeSelection)))
-        .add_systems(OnEnter(GameState::Loading), cleanup_game)
-        .add_systems(OnEnter(GameState::GameOver), (cleanup_game, show_game_over_screen).chain())
-        .add_systems(OnExit(GameState::UpgradeSelection), cleanup_upgrade_ui_on_choice)
-        .add_systems(OnEnter(GameState::UpgradeSelection), create_table_ui)
-        .add_systems(Update, restart_on_key.run_if(in_state(GameState::GameOver)))
-        .run();
+                    // Çarpışma sorguları bu tick'in grid'ini kullansın
+                    rebuild_spatial_grid,
+                    (
+                        magnetite_xp_to_player,
+                        enemy_collision_with_enemy,
+                        update_ground_chunks,
+                        follow,
+                        animate_enemies,
+                        move_player_addicted_weapons,
+                        fire_laser_weapons,
+                        fire_rocket_weapons,
+                        move_player,
+                        run_spawn_director,
+                        damage_player_on_contact,
+                        move_projectiles,
+                        // İç tuple zincirli değil; hasar bu tick'in isabetlerinden sonra işlensin
+                        apply_damage.after(move_projectiles).after(move_player_addicted_weapons),
+                        // Bu tick'in temas, mermi ve bölge hasarı toplandıktan sonra
+                        apply_player_damage.after(damage_player_on_contact).after(move_enemy_projectiles),
+                        play_death_sequence.after(apply_player_damage),
+                        despawn_explosions,
+                        collect_xp,
+                        (
+                            charger_behaviour,
+                            ranged_enemy_fire,
+                            move_enemy_projectiles,
+                            split_enemies_on_death,
+                        ),
+                        (sync_weapon_stats, attract_xp_in_pickup_range),
+                    ),
+                ).chain().in_set(GameplaySet),
+            )
+            .add_systems(Update, (show_upgrade_choices_on_level_up,
+                         (handle_upgrade_input, navigate_upgrade_choices, handle_reroll_button, button_hover_effect::<RerollButton>).chain().run_if(live_input),
+                         reroll_upgrade_choices, apply_weapon_upgrade).chain().run_if(in_state(GameState::UpgradeSelection)))
+            // Koşudan çıkılan her yolda altın ve rekorlar, oyuncu silinmeden profile eklenir
+            .add_systems(OnEnter(GameState::Loading), (bank_run.before(cleanup_game).before(reset_run_resources), cleanup_game, reset_run_resources))
+            // Duraklatma menüsünden ana menüye dönüldüğünde
+            .add_systems(OnEnter(GameState::MainMenu), (bank_run, cleanup_game).chain())
+            .add_systems(OnEnter(GameState::GameOver), (bank_run, cleanup_game, show_game_over_screen).chain())
+            .add_systems(OnExit(GameState::UpgradeSelection), cleanup_upgrade_ui_on_choice)
+            .add_systems(OnEnter(GameState::UpgradeSelection), (create_table_ui, reset_upgrade_focus))
+            .add_systems(Update, restart_on_key.run_if(in_state(GameState::GameOver)));
+    }
 }
 
 // Marker component - oyun sırasında oluşturulan tüm entity'lere eklenecek
 
+/// `body` ve `shield` düşmanların iskelet sprite'ı, `player` seçilen karakterin sprite sheet'i
 #[derive(Resource, Default)]
 struct Atlases {
     body: Option<Handle<TextureAtlasLayout>>,
     shield: Option<Handle<TextureAtlasLayout>>,
+    player: Option<Handle<TextureAtlasLayout>>,
     ready: bool,
 }
 
@@ -85,9 +189,43 @@ fn minimal_setup(mut commands: Commands) {
     commands.spawn((Camera2d, Camera { ..default() }));
 }
 
+/// Koşunun kullandığı veri dosyaları - hepsi yüklenmeden koşu başlamaz,
+/// yoksa ilk tick'ler veri olmadan geçer ve kayıtlar tekrar oynatılamaz
+#[derive(SystemParam)]
+struct RunData<'w> {
+    asset_server: Res<'w, AssetServer>,
+    director: Res<'w, SpawnDirector>,
+    passive_items: Res<'w, PassiveItems>,
+    evolutions: Res<'w, WeaponEvolutions>,
+    loot: Res<'w, LootTables>,
+    characters: Res<'w, CharacterDefinitions>,
+    character_assets: Res<'w, Assets<CharacterDefinition>>,
+    /// Profilden ya da oynatılan kayıttan gelen güçlendirmeler ve karakter
+    loadout: Res<'w, RunLoadout>,
+}
+
+impl RunData<'_> {
+    fn loaded(&self) -> bool {
+        self.asset_server.is_loaded(&self.director.timeline)
+            && self.asset_server.is_loaded(&self.passive_items.catalogue)
+            && self.asset_server.is_loaded(&self.evolutions.table)
+            && self.asset_server.is_loaded(&self.loot.table)
+            && self.characters.ready
+    }
+
+    /// Seçilen karakter; tanımı yoksa (dosya silinmiş) varsayılan karakter
+    fn character(&self) -> Option<&CharacterDefinition> {
+        let id = &self.loadout.0.character;
+        self.characters.get(id, &self.character_assets).or_else(|| {
+            warn!("No character definition for {}, using the default character", id);
+            self.characters.get(&CharacterId::default(), &self.character_assets)
+        })
+    }
+}
+
 fn prepare_atlases_and_spawn(
     mut commands: Commands,
-    asset_server: Res<AssetServer>,
+    data: RunData,
     images: Res<Assets<Image>>,
     textures: Res<TextureAssets>,
     mut texture_atlases: ResMut<Assets<TextureAtlasLayout>>,
@@ -95,20 +233,34 @@ fn prepare_atlases_and_spawn(
     mut next_state: ResMut<NextState<GameState>>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<ColorMaterial>>,
+    weapon_definitions: Res<WeaponDefinitions>,
+    weapon_assets: Res<Assets<WeaponDefinition>>,
 ) {
     if atlases.ready {
         return;
     }
 
-    if !asset_server.load_state(&textures.body).is_loaded()
-        || !asset_server.load_state(&textures.shield).is_loaded()
+    // Silah tanımları (assets/data/weapons) ve diğer veri dosyaları yüklenene kadar bekle
+    if !weapon_definitions.ready || !data.loaded() {
+        return;
+    }
+
+    let Some(character) = data.character() else {
+        return;
+    };
+    let Some(character_texture) = data.characters.textures.get(&character.id) else {
+        return;
+    };
+
+    if !data.asset_server.load_state(&textures.body).is_loaded()
+        || !data.asset_server.load_state(&textures.shield).is_loaded()
+        || !data.asset_server.load_state(character_texture).is_loaded()
     {
         return;
     }
 
-    let image = match images.get(&textures.body) {
-        Some(img) => img,
-        None => return,
+    let (Some(image), Some(character_image)) = (images.get(&textures.body), images.get(character_texture)) else {
+        return;
     };
 
     let frame_w = (image.texture_descriptor.size.width as f32 / 9.0).round() as u32;
@@ -117,27 +269,43 @@ fn prepare_atlases_and_spawn(
 
     let body_atlas = texture_atlases.add(layout.clone());
     let shield_atlas = texture_atlases.add(layout);
+    let player_atlas = texture_atlases.add(character.sprite.layout(character_image.size()));
 
     atlases.body = Some(body_atlas.clone());
     atlases.shield = Some(shield_atlas.clone());
+    atlases.player = Some(player_atlas.clone());
     atlases.ready = true;
 
     // Player spawn - GameEntity marker ile işaretle
+    let loadout = &data.loadout.0;
+    let max_health = character.health + loadout.bonus_health;
+    let base_stats = character.bonus.map_or(loadout.base_stats(), |bonus| bonus.apply(loadout.base_stats()));
+    let animation = character.sprite.animation();
+    let mut sprite = Sprite::from_atlas_image(
+        character_texture.clone(),
+        TextureAtlas {
+            layout: player_atlas,
+            index: animation.next_frame(0, Vec2::Y, false, false),
+        },
+    );
+    sprite.custom_size = Some(character.sprite.frame_size(character_image.size()).as_vec2() * character.sprite.scale);
+    println!("🧙 Playing as {}", character.name);
     let player_entity = commands.spawn((
         GameEntity,  // ← Marker eklendi
-        Sprite::from_atlas_image(
-            textures.body.clone(),
-            TextureAtlas {
-                layout: body_atlas,
-                index: 0,
-            },
-        ),
+        sprite,
+        animation,
         Transform::from_xyz(0.0, 0.0, 0.0),
         Player {
-            health: 1,
-            movement: 200.,
+            health: max_health,
+            max_health,
+            movement: character.movement,
+            revivals: loadout.revivals,
+            rerolls: loadout.rerolls,
             ..default()
         },
+        PassiveInventory::default(),
+        StartingStats(base_stats),
+        base_stats,
         AABB {
             max_x: 20.,
             max_y: 20.,
@@ -147,15 +315,22 @@ fn prepare_atlases_and_spawn(
             height: 40.,
         },
     )).id();
-    spawn_weapons_for_player(&mut commands, player_entity, Vec3::ZERO, &mut meshes, &mut materials);
+    // Shop'ta seçilen silahın tanımı artık yoksa karakterinki
+    let starting_weapon = loadout
+        .starting_weapon
+        .as_ref()
+        .filter(|weapon| weapon_definitions.by_id.contains_key(*weapon))
+        .unwrap_or(&character.starting_weapon);
+    spawn_starting_weapon(&mut commands, player_entity, starting_weapon, &mut meshes, &mut materials, &weapon_definitions, &weapon_assets);
     next_state.set(GameState::Playing);
 }
 
 
 fn move_player(
-    mut player_query: Query<(&mut Transform, &Player, &mut AABB, &mut Sprite), With<Player>>,
+    // Ölüm animasyonu oyuncuyu kendisi döndürüp küçültür; o sırada sistem hiç çalışmaz
+    mut player_query: Query<MovingPlayer, Without<PlayerDeath>>,
     mut camera_query: Query<&mut Transform, (With<Camera2d>, Without<Player>)>,
-    keyboard_input: Res<ButtonInput<KeyCode>>,
+    input: Res<PlayerInput>,
     time: Res<Time>,
     atlases: Res<Atlases>,
     enemy_move_timer: Res<MoveTimer>,
@@ -165,7 +340,7 @@ fn move_player(
     }
 
     // Single yerine Query kullanıp güvenli kontrol
-    let Ok((mut transform, player, mut aabb, mut sprite)) = player_query.single_mut() else {
+    let Ok(mut player) = player_query.single_mut() else {
         return;
     };
 
@@ -173,51 +348,45 @@ fn move_player(
         return;
     };
 
-    if sprite.texture_atlas.is_none() {
-        if let Some(layout_handle) = &atlases.body {
-            sprite.texture_atlas = Some(TextureAtlas {
+    if player.sprite.texture_atlas.is_none() {
+        if let Some(layout_handle) = &atlases.player {
+            player.sprite.texture_atlas = Some(TextureAtlas {
                 layout: layout_handle.clone(),
                 index: 0,
             });
         }
     }
 
-    player.move_around(
-        &mut transform,
-        &mut aabb,
-        &mut sprite,
-        &mut camera_transform,
-        &keyboard_input,
-        &time,
-        &enemy_move_timer,
-    );
+    player.move_around(&mut camera_transform, &input, &time, &enemy_move_timer);
 }
 
-fn reduce_player_health(
-    mut commands: Commands,
-    mut player_query: Query<(&mut Player, &mut AABB, Entity), With<Player>>,
-    enemy_query: Query<(&AABB, &Enemy), (With<Enemy>, Without<Player>)>,
-    mut player_health_reduce_timer: ResMut<PlayerHealthReduceTimer>,
-    time: Res<Time>,
-    mut next_state: ResMut<NextState<GameState>>,
+/// Üst üste binen düşmanlardan en güçlüsü vurur; sıklığı dokunulmazlık süresi belirler
+fn damage_player_on_contact(
+    player_query: Query<(&AABB, &PlayerMotion), Without<PlayerDeath>>,
+    enemy_query: Query<(&AABB, &Enemy)>,
+    grid: Res<SpatialGrid>,
+    mut damage_events: MessageWriter<PlayerDamageEvent>,
 ) {
-    player_health_reduce_timer.timer.tick(time.delta());
-    if !player_health_reduce_timer.timer.just_finished() {
-        return;
-    }
-
-    let Ok((mut player, aabb, entity)) = player_query.single_mut() else {
+    let Ok((aabb, motion)) = player_query.single() else {
         return;
     };
+    // Atılma ya da önceki isabetin dokunulmazlığı
+    if motion.is_invulnerable() {
+        return;
+    }
 
-    player.take_damage(entity, &mut commands, enemy_query, &aabb);
-
-    if player.health == 0 {
-        next_state.set(GameState::GameOver);
+    let strongest = grid
+        .query_aabb(aabb.min(), aabb.max(), SpatialLayer::Enemy)
+        .into_iter()
+        .filter_map(|candidate| enemy_query.get(candidate.entity).ok())
+        .filter(|(enemy_aabb, enemy)| enemy.damage > 0 && enemy_aabb.self_aabb_intersects(aabb))
+        .max_by_key(|(_, enemy)| enemy.damage);
+    if let Some((enemy_aabb, enemy)) = strongest {
+        damage_events.write(PlayerDamageEvent { amount: enemy.damage as u32, source: Some(enemy_aabb.center()), over_time: false });
     }
 }
 
-// OnExit(GameState::Playing) ile tetiklenir - sadece GameEntity olanları temizle
+// Koşudan çıkarken (Loading, GameOver, MainMenu) - sadece GameEntity olanları temizle
 fn cleanup_game(
     mut commands: Commands,
     game_entities: Query<Entity, With<GameEntity>>,
@@ -230,10 +399,10 @@ fn cleanup_game(
 }
 
 // GameOver ekranını göster
-fn show_game_over_screen(mut commands: Commands) {
+fn show_game_over_screen(mut commands: Commands, rng: Res<GameRng>) {
     commands.spawn((
         GameEntity,  // Bu da oyun entity'si, tekrar restart olunca silinecek
-        Text::new("Game Over! Press R to Restart"),
+        Localized(UiText::GameOver),
         TextFont {
             font_size: 50.0,
             ..default()
@@ -246,28 +415,42 @@ fn show_game_over_screen(mut commands: Commands) {
             ..default()
         },
     ));
+    // Aynı koşuyu tekrar oynamak için: --seed <seed>
+    commands.spawn((
+        GameEntity,
+        Text::new(format!("Seed: {}", rng.seed())),
+        TextFont {
+            font_size: 30.0,
+            ..default()
+        },
+        TextColor(Color::srgb(0.8, 0.8, 0.8)),
+        Node {
+            position_type: PositionType::Absolute,
+            top: px(370.0),
+            left: px(400.0),
+            ..default()
+        },
+    ));
 }
 
-// R tuşu ile restart
+// Restart eylemi (R) ile restart - temizlik ve sıfırlama OnEnter(Loading)'de
 fn restart_on_key(
-    keyboard: Res<ButtonInput<KeyCode>>,
+    actions: Res<ActionState>,
     mut next_state: ResMut<NextState<GameState>>,
-    mut atlases: ResMut<Atlases>,
-    mut spawn_timer: ResMut<EnemySpawnTimer>,
-    mut move_timer: ResMut<MoveTimer>,
-    mut reduce_timer: ResMut<PlayerHealthReduceTimer>,
-    mut enemy_power: ResMut<EnemyPowerUpTimer>,
 ) {
-
-    if keyboard.just_pressed(KeyCode::KeyR) {
-        // Resource'ları resetle
-        *atlases = Atlases::default();
-        *spawn_timer = EnemySpawnTimer::default();
-        *move_timer = MoveTimer::default();
-        *reduce_timer = PlayerHealthReduceTimer::default();
-        *enemy_power = EnemyPowerUpTimer::default();
-        // State'i değiştir - OnExit(Playing) tetiklenmeyecek çünkü Playing'den çıkmıyoruz
-        // GameOver'dan Loading'e geçiyoruz
+    if actions.just_pressed(InputAction::Restart) {
         next_state.set(GameState::Loading);
     }
 }
+
+/// Her koşu başında önceki koşunun kaynaklarını sıfırla - GameOver, duraklatma menüsü
+/// ya da ana menüden gelinmesi fark etmez
+fn reset_run_resources(
+    mut atlases: ResMut<Atlases>,
+    mut move_timer: ResMut<MoveTimer>,
+    mut director: ResMut<SpawnDirector>,
+) {
+    *atlases = Atlases::default();
+    *move_timer = MoveTimer::default();
+    director.reset();
+}
@@ -1,6 +1,10 @@
 pub mod player;
 pub mod  game_state;
 pub mod enemy;
+pub mod enemy_kind;
+pub mod enemy_behaviour;
+pub mod boss;
+pub mod spawn_director;
 pub mod timers;
 pub mod aabb;
 pub mod weapons;
@@ -8,6 +12,35 @@ pub mod texture_handling;
 pub mod score;
 pub mod weapon_upgrade;
 pub mod weapon_stats;
+pub mod weapon_definition;
+pub mod weapon_evolution;
+pub mod passive_item;
+pub mod status_effect;
+pub mod damage;
+pub mod player_damage;
+pub mod loot;
+pub mod chest_reward;
+pub mod xp_orb;
+pub mod hit_feedback;
+pub mod hud;
+pub mod ron_asset;
+pub mod asset_folder;
+pub mod rng;
+pub mod replay;
+pub mod headless;
+pub mod simulation;
+pub mod spatial_grid;
 pub mod ground;
 pub mod audio;
 pub mod main_menu;
+pub mod pause_menu;
+pub mod settings;
+pub mod settings_menu;
+pub mod profile;
+pub mod power_up_shop;
+pub mod run_save;
+pub mod character;
+pub mod character_select;
+pub mod localization;
+pub mod screen_shake;
+pub mod input_action;
@@ -1,4 +1,4 @@
-use bevy::math::Vec3;
+use bevy::math::{Vec2, Vec3};
 use bevy::prelude::Component;
 
 #[derive(Component, Clone)]
@@ -23,6 +23,15 @@ impl AABB {
     pub fn self_aabb_intersects(&self, b: &AABB) -> bool {
         !(self.min_x > b.max_x || self.max_x < b.min_x || self.min_y > b.max_y || self.max_y < b.min_y)
     }
+    pub fn min(&self) -> Vec2 {
+        Vec2::new(self.min_x, self.min_y)
+    }
+    pub fn max(&self) -> Vec2 {
+        Vec2::new(self.max_x, self.max_y)
+    }
+    pub fn center(&self) -> Vec2 {
+        Vec2::new((self.min_x + self.max_x) / 2.0, (self.min_y + self.max_y) / 2.0)
+    }
     pub fn contains_point(&self, point: Vec3) -> bool {
         point.x+5. >= self.min_x
             && point.x-5. <= self.max_x
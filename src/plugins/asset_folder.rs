@@ -0,0 +1,43 @@
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use bevy::asset::{LoadState, LoadedFolder};
+use bevy::prelude::*;
+use crate::plugins::ron_asset::RonAsset;
+
+/// Bir klasörden yüklenip kendi id'siyle indekslenen tanımlar - silahlar, karakterler
+pub trait FolderAsset: RonAsset {
+    type Id: Ord + Clone + Display;
+    /// Log mesajlarında tanımın adı, ör. `"Weapon definition"`
+    const LABEL: &'static str;
+
+    fn id(&self) -> &Self::Id;
+}
+
+/// Klasördeki tanımları id'ye göre indeksle, hatalı dosyaları raporla; aynı id'de sonuncusu kullanılır.
+/// Dosyalardan biri hâlâ yükleniyorsa `None`
+pub fn index_folder<A: FolderAsset>(
+    folder: &LoadedFolder,
+    asset_server: &AssetServer,
+    assets: &Assets<A>,
+) -> Option<BTreeMap<A::Id, Handle<A>>> {
+    let mut by_id = BTreeMap::new();
+    for untyped in folder.handles.iter() {
+        let path = untyped.path().map(|p| p.to_string()).unwrap_or_default();
+        match asset_server.load_state(untyped.id()) {
+            LoadState::Failed(err) => {
+                error!("{} {path} failed to load: {err}", A::LABEL);
+                continue;
+            }
+            state if !state.is_loaded() => return None,
+            _ => {}
+        }
+        let Ok(handle) = untyped.clone().try_typed::<A>() else {
+            continue;
+        };
+        let definition = assets.get(&handle)?;
+        if by_id.insert(definition.id().clone(), handle).is_some() {
+            warn!("Duplicate {} for {} in {path}, using the last one", A::LABEL.to_lowercase(), definition.id());
+        }
+    }
+    Some(by_id)
+}
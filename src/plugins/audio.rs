@@ -5,6 +5,8 @@ use bevy::audio::{AudioSink, PlaybackMode};
 pub struct GameAudio {
     pub enemy_hit: Handle<AudioSource>,
     pub collect_xp: Handle<AudioSource>,
+    /// Oyuncu isabet aldığında
+    pub player_hurt: Handle<AudioSource>,
 }
 #[derive(Component)]
 pub struct GameAudioEntity;
@@ -15,9 +17,12 @@ pub fn load_audio_assets(
 ) {
     let enemy_hit = asset_server.load("sounds/breakout_collision.ogg");
     let collect_xp = asset_server.load("sounds/Epic orchestra music.ogg");
+    // Ayrı bir ses dosyası yok - çarpışma sesi yavaşlatılarak çalınır
+    let player_hurt = asset_server.load("sounds/breakout_collision.ogg");
 
     commands.insert_resource(GameAudio {
         enemy_hit,
         collect_xp,
+        player_hurt,
     });
 }
\ No newline at end of file
@@ -0,0 +1,582 @@
+use std::f32::consts::TAU;
+use bevy::prelude::*;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use crate::plugins::aabb::AABB;
+use crate::plugins::enemy::{spawn_enemy, tint_enemy_sprites, Enemy, EnemySpawner, EnemySprit};
+use crate::plugins::enemy_behaviour::EnemyProjectile;
+use crate::plugins::enemy_kind::{EnemyBehaviour, EnemyKind, EnemyProfile, EnemySpriteSheet};
+use crate::plugins::damage::DamageType;
+use crate::plugins::game_state::{GameState, GameplaySet};
+use crate::plugins::localization::UiText;
+use crate::plugins::player::{Player, PlayerMotion};
+use crate::plugins::player_damage::{apply_player_damage, PlayerDamageEvent, PlayerDeath};
+use crate::plugins::rng::RngStream;
+use crate::plugins::screen_shake::ScreenShake;
+use crate::plugins::settings::Settings;
+use crate::plugins::status_effect::{StatusApplication, StatusEffects, StatusKind};
+use crate::plugins::weapons::GameEntity;
+
+/// Boss davranışları ve can barı - hazine sandığı loot tablosundan düşer
+pub struct BossPlugin;
+
+impl Plugin for BossPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            FixedUpdate,
+            (
+                (boss_movement, boss_attacks).chain(),
+                tick_denial_zones.before(apply_player_damage),
+                update_boss_health_bar,
+            ).in_set(GameplaySet),
+        )
+        .add_systems(Update, shake_on_boss_arrival.run_if(in_state(GameState::Playing)));
+
+        // Test için: F9 sıradaki boss'u oyuncunun yanına çağırır; girdisi kayda girmediği için oynatmada çalışmaz
+        #[cfg(debug_assertions)]
+        app.add_systems(Update, debug_spawn_boss.run_if(in_state(GameState::Playing).and(crate::plugins::replay::live_input)));
+    }
+}
+
+/// Zaman çizelgesinde `kind: Boss(QueenBee)` şeklinde kullanılır
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Debug, Serialize, Deserialize)]
+pub enum BossKind {
+    /// Mermi halkaları atar, tilki sürüsü çağırır, zemini zehirler
+    QueenBee,
+    /// Oyuncuya atılır, slime çağırır
+    KingSlime,
+}
+
+/// Boss'un tek bir saldırısı - fazın listesinden sırayla seçilir
+#[derive(Clone, Copy, Debug)]
+pub enum BossAttack {
+    /// Boss'un etrafına eşit aralıklı `bullets` tane mermi
+    RadialBurst { bullets: u32, speed: f32, damage: i32 },
+    /// Boss'un etrafında halka şeklinde `count` tane `kind`
+    Summon { kind: EnemyKind, count: u32 },
+    /// `windup` saniye titre, sonra oyuncuya doğru `duration` saniye atıl
+    Charge { windup: f32, speed: f32, duration: f32 },
+    /// Oyuncunun çevresine `count` tane hasar veren alan bırak; içindeki oyuncuya `effect` uygulanır
+    DenialZone { count: u32, radius: f32, duration: f32, damage: i32, effect: Option<StatusApplication> },
+}
+
+const BOSS_ARRIVAL_TRAUMA: f32 = 0.8;
+
+/// Queen Bee'nin zehirli zemini
+const HIVE_POISON: StatusApplication = StatusApplication { kind: StatusKind::Poison, duration: 3.0, magnitude: 2.0, chance: 1.0 };
+/// King Slime'ın yapışkan zemini
+const SLIME_SLOW: StatusApplication = StatusApplication { kind: StatusKind::Slow, duration: 1.0, magnitude: 0.5, chance: 1.0 };
+
+/// Can oranı `health_threshold`'un altına düşünce başlayan faz
+#[derive(Clone, Copy, Debug)]
+pub struct BossPhase {
+    pub health_threshold: f32,
+    pub attack_interval: f32,
+    pub speed_multiplier: f32,
+    pub attacks: &'static [BossAttack],
+}
+
+const QUEEN_BEE_PHASES: &[BossPhase] = &[
+    BossPhase {
+        health_threshold: 1.0,
+        attack_interval: 2.5,
+        speed_multiplier: 1.0,
+        attacks: &[
+            BossAttack::RadialBurst { bullets: 12, speed: 220.0, damage: 1 },
+            BossAttack::Summon { kind: EnemyKind::Swarmer, count: 6 },
+        ],
+    },
+    BossPhase {
+        health_threshold: 0.6,
+        attack_interval: 2.0,
+        speed_multiplier: 1.2,
+        attacks: &[
+            BossAttack::RadialBurst { bullets: 16, speed: 260.0, damage: 1 },
+            BossAttack::DenialZone { count: 3, radius: 70.0, duration: 5.0, damage: 1, effect: Some(HIVE_POISON) },
+            BossAttack::Summon { kind: EnemyKind::Swarmer, count: 8 },
+        ],
+    },
+    BossPhase {
+        health_threshold: 0.3,
+        attack_interval: 1.4,
+        speed_multiplier: 1.4,
+        attacks: &[
+            BossAttack::RadialBurst { bullets: 24, speed: 300.0, damage: 2 },
+            BossAttack::DenialZone { count: 5, radius: 80.0, duration: 6.0, damage: 1, effect: Some(HIVE_POISON) },
+            BossAttack::RadialBurst { bullets: 24, speed: 300.0, damage: 2 },
+            BossAttack::Summon { kind: EnemyKind::Swarmer, count: 10 },
+        ],
+    },
+];
+
+const KING_SLIME_PHASES: &[BossPhase] = &[
+    BossPhase {
+        health_threshold: 1.0,
+        attack_interval: 3.0,
+        speed_multiplier: 1.0,
+        attacks: &[
+            BossAttack::Charge { windup: 1.0, speed: 650.0, duration: 0.6 },
+            BossAttack::Summon { kind: EnemyKind::Splitling, count: 6 },
+        ],
+    },
+    BossPhase {
+        health_threshold: 0.5,
+        attack_interval: 2.2,
+        speed_multiplier: 1.2,
+        attacks: &[
+            BossAttack::Charge { windup: 0.7, speed: 750.0, duration: 0.7 },
+            BossAttack::RadialBurst { bullets: 10, speed: 200.0, damage: 1 },
+            BossAttack::Summon { kind: EnemyKind::Splitter, count: 2 },
+        ],
+    },
+    BossPhase {
+        health_threshold: 0.2,
+        attack_interval: 1.6,
+        speed_multiplier: 1.5,
+        attacks: &[
+            BossAttack::Charge { windup: 0.5, speed: 850.0, duration: 0.8 },
+            BossAttack::DenialZone { count: 4, radius: 90.0, duration: 5.0, damage: 1, effect: Some(SLIME_SLOW) },
+            BossAttack::RadialBurst { bullets: 16, speed: 240.0, damage: 2 },
+        ],
+    },
+];
+
+impl BossKind {
+    pub const ALL: [BossKind; 2] = [BossKind::QueenBee, BossKind::KingSlime];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            BossKind::QueenBee => "Queen Bee",
+            BossKind::KingSlime => "King Slime",
+        }
+    }
+
+    /// `EnemyKind::Boss(..)` için seviye 1 statları
+    pub fn profile(&self) -> EnemyProfile {
+        match self {
+            BossKind::QueenBee => EnemyProfile {
+                sprite: Some(EnemySpriteSheet {
+                    path: "textures/rpg/mobs/boss_bee.png",
+                    frame_size: UVec2::new(34, 34),
+                    frames: 1,
+                    scale: 3.0,
+                }),
+                health: 4000,
+                damage: 3,
+                speed: (90.0, 100.0),
+                size: 90.0,
+                xp: 500,
+                armor: 3.0,
+                resistances: &[(DamageType::Poison, 0.25), (DamageType::Fire, 1.25)],
+                knockback_resistance: 1.0,
+                behaviour: EnemyBehaviour::Boss(*self),
+            },
+            BossKind::KingSlime => EnemyProfile {
+                sprite: Some(EnemySpriteSheet {
+                    path: "textures/rpg/mobs/slime-green.png",
+                    frame_size: UVec2::new(16, 24),
+                    frames: 4,
+                    scale: 8.0,
+                }),
+                health: 6000,
+                damage: 4,
+                speed: (60.0, 70.0),
+                size: 110.0,
+                xp: 800,
+                armor: 5.0,
+                resistances: &[(DamageType::Poison, 0.5), (DamageType::Physical, 0.8)],
+                knockback_resistance: 1.0,
+                behaviour: EnemyBehaviour::Boss(*self),
+            },
+        }
+    }
+
+    /// Can eşiğine göre azalan sırada fazlar; ilki her zaman 1.0
+    pub fn phases(&self) -> &'static [BossPhase] {
+        match self {
+            BossKind::QueenBee => QUEEN_BEE_PHASES,
+            BossKind::KingSlime => KING_SLIME_PHASES,
+        }
+    }
+}
+
+/// Boss'un atılma saldırısı sırasındaki durumu
+#[derive(Clone, Serialize, Deserialize)]
+pub struct BossCharge {
+    pub windup: Timer,
+    pub dash: Timer,
+    pub speed: f32,
+    pub direction: Vec3,
+}
+
+/// `Enemy` ile birlikte bulunur; hasar, ölüm ve XP normal düşman yolundan geçer
+#[derive(Component, Clone, Serialize, Deserialize)]
+pub struct Boss {
+    pub kind: BossKind,
+    pub max_health: i32,
+    pub level: i32,
+    pub phase: usize,
+    pub attack_timer: Timer,
+    pub next_attack: usize,
+    pub charge: Option<BossCharge>,
+}
+
+impl Boss {
+    pub fn new(kind: BossKind, max_health: i32, level: i32) -> Self {
+        Self {
+            kind,
+            max_health,
+            level,
+            phase: 0,
+            attack_timer: Timer::from_seconds(kind.phases()[0].attack_interval, TimerMode::Repeating),
+            next_attack: 0,
+            charge: None,
+        }
+    }
+
+    pub fn current_phase(&self) -> &'static BossPhase {
+        &self.kind.phases()[self.phase]
+    }
+}
+
+/// Aktif olmadan önce kısa süre uyarı veren, oyuncuya düzenli hasar veren alan
+#[derive(Component)]
+pub struct DenialZone {
+    pub radius: f32,
+    pub damage: i32,
+    pub effect: Option<StatusApplication>,
+    pub warmup: Timer,
+    pub lifetime: Timer,
+    pub damage_tick: Timer,
+}
+
+#[derive(Component)]
+struct BossHealthBar;
+
+#[derive(Component)]
+struct BossHealthFill;
+
+#[derive(Component)]
+struct BossNameText;
+
+const CHARGE_TINT: Color = Color::srgb(1.0, 0.3, 0.3);
+const ZONE_WARNING_COLOR: Color = Color::srgba(1.0, 0.4, 0.0, 0.15);
+const ZONE_ACTIVE_COLOR: Color = Color::srgba(0.9, 0.1, 0.0, 0.45);
+
+/// Faz geçişleri ve hareket: oyuncuyu kovala ya da süren atılmayı bitir
+fn boss_movement(
+    time: Res<Time>,
+    player_query: Query<&Transform, (With<Player>, Without<Enemy>)>,
+    mut bosses: Query<(&mut Transform, &mut Enemy, &mut AABB, &mut Boss, &Children, &StatusEffects)>,
+    mut sprites: Query<&mut Sprite, With<EnemySprit>>,
+) {
+    let Ok(player_transform) = player_query.single() else {
+        return;
+    };
+    let player_position = player_transform.translation.with_z(0.0);
+
+    for (mut transform, mut enemy, mut aabb, mut boss, children, effects) in bosses.iter_mut() {
+        if enemy.health <= 0 {
+            continue;
+        }
+
+        // Can eşiği geçildiyse bir sonraki faza geç (geri dönüş yok)
+        let fraction = enemy.health as f32 / boss.max_health as f32;
+        let phase = boss.kind.phases().iter().rposition(|phase| fraction <= phase.health_threshold).unwrap_or(0);
+        if phase > boss.phase {
+            boss.phase = phase;
+            boss.next_attack = 0;
+            boss.attack_timer = Timer::from_seconds(boss.current_phase().attack_interval, TimerMode::Repeating);
+            info!("{} entered phase {}", boss.kind.name(), phase + 1);
+        }
+
+        let to_player = (player_position - transform.translation.with_z(0.0)).normalize_or_zero();
+        enemy.direction = to_player;
+
+        // Donmuşken atılma da bekler
+        if effects.is_frozen() {
+            continue;
+        }
+        if let Some(charge) = boss.charge.as_mut() {
+            if !charge.windup.is_finished() {
+                // Titreme süresince yerinde kırmızı bekle, yönü en son anda kilitle
+                charge.windup.tick(time.delta());
+                charge.direction = to_player;
+                tint_enemy_sprites(children, &mut sprites, CHARGE_TINT);
+            } else {
+                charge.dash.tick(time.delta());
+                transform.translation += charge.direction * charge.speed * time.delta_secs();
+                if charge.dash.just_finished() {
+                    boss.charge = None;
+                    tint_enemy_sprites(children, &mut sprites, effects.tint().map_or(Color::WHITE, StatusKind::tint));
+                }
+            }
+        } else {
+            let speed = enemy.speed * boss.current_phase().speed_multiplier * effects.speed_multiplier();
+            transform.translation += to_player * speed * time.delta_secs();
+        }
+        aabb.change_point(transform.translation);
+    }
+}
+
+/// Fazın saldırı listesinden sıradakini `attack_interval` aralıklarla uygula
+fn boss_attacks(
+    mut commands: Commands,
+    time: Res<Time>,
+    player_query: Query<&Transform, (With<Player>, Without<Boss>)>,
+    mut bosses: Query<(&Transform, &Enemy, &mut Boss)>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    mut spawner: EnemySpawner,
+) {
+    let Ok(player_transform) = player_query.single() else {
+        return;
+    };
+    let player_position = player_transform.translation.with_z(0.0);
+
+    for (transform, enemy, mut boss) in bosses.iter_mut() {
+        // Atılırken saldırı sayacı durur
+        if enemy.health <= 0 || boss.charge.is_some() {
+            continue;
+        }
+        boss.attack_timer.tick(time.delta());
+        if !boss.attack_timer.just_finished() {
+            continue;
+        }
+        let attacks = boss.current_phase().attacks;
+        let attack = attacks[boss.next_attack % attacks.len()];
+        boss.next_attack += 1;
+
+        let origin = transform.translation;
+        let level = boss.level;
+        match attack {
+            BossAttack::RadialBurst { bullets, speed, damage } => {
+                let mesh = meshes.add(Circle::new(7.0));
+                let material = materials.add(ColorMaterial::from(Color::srgb(0.9, 0.7, 0.1)));
+                let offset = spawner.rng.stream(RngStream::Spawning).random_range(0.0..TAU);
+                for i in 0..bullets {
+                    let angle = offset + TAU * i as f32 / bullets as f32;
+                    commands.spawn((
+                        GameEntity,
+                        EnemyProjectile {
+                            direction: Vec3::new(angle.cos(), angle.sin(), 0.0),
+                            speed,
+                            damage: damage * level,
+                            lifetime: Timer::from_seconds(5.0, TimerMode::Once),
+                        },
+                        Mesh2d(mesh.clone()),
+                        MeshMaterial2d(material.clone()),
+                        Transform::from_translation(origin + Vec3::new(0.0, 0.0, 10.0)),
+                    ));
+                }
+            }
+            BossAttack::Summon { kind, count } => {
+                for i in 0..count {
+                    let angle = TAU * i as f32 / count as f32;
+                    let position = origin + Vec3::new(angle.cos(), angle.sin(), 0.0) * 120.0;
+                    spawn_enemy(&mut commands, &mut spawner, kind, position, level);
+                }
+            }
+            BossAttack::Charge { windup, speed, duration } => {
+                boss.charge = Some(BossCharge {
+                    windup: Timer::from_seconds(windup, TimerMode::Once),
+                    dash: Timer::from_seconds(duration, TimerMode::Once),
+                    speed,
+                    direction: Vec3::ZERO,
+                });
+            }
+            BossAttack::DenialZone { count, radius, duration, damage, effect } => {
+                let mesh = meshes.add(Circle::new(radius));
+                for i in 0..count {
+                    // İlk alan tam oyuncunun altına, diğerleri çevresine
+                    let position = if i == 0 {
+                        player_position
+                    } else {
+                        let rng = spawner.rng.stream(RngStream::Spawning);
+                        let angle = rng.random_range(0.0..TAU);
+                        player_position + Vec3::new(angle.cos(), angle.sin(), 0.0) * rng.random_range(radius..radius * 3.0)
+                    };
+                    commands.spawn((
+                        GameEntity,
+                        DenialZone {
+                            radius,
+                            damage: damage * level,
+                            effect,
+                            warmup: Timer::from_seconds(0.8, TimerMode::Once),
+                            lifetime: Timer::from_seconds(duration, TimerMode::Once),
+                            damage_tick: Timer::from_seconds(0.5, TimerMode::Repeating),
+                        },
+                        Mesh2d(mesh.clone()),
+                        MeshMaterial2d(materials.add(ColorMaterial::from(ZONE_WARNING_COLOR))),
+                        Transform::from_translation(position.with_z(-50.0)),
+                    ));
+                }
+            }
+        }
+    }
+}
+
+/// Boss gelince ekran sarsılır
+fn shake_on_boss_arrival(bosses: Query<(), Added<Boss>>, mut shake: ResMut<ScreenShake>) {
+    if !bosses.is_empty() {
+        shake.add_trauma(BOSS_ARRIVAL_TRAUMA);
+    }
+}
+
+/// Alanlar uyarı süresinden sonra içindeki oyuncuya hasar verir
+fn tick_denial_zones(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut zones: Query<(Entity, &Transform, &mut DenialZone, &MeshMaterial2d<ColorMaterial>)>,
+    mut player_query: Query<(&Transform, &mut StatusEffects, &PlayerMotion), Without<PlayerDeath>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    mut damage_events: MessageWriter<PlayerDamageEvent>,
+) {
+    let Ok((player_transform, mut effects, motion)) = player_query.single_mut() else {
+        return;
+    };
+
+    for (entity, transform, mut zone, material) in zones.iter_mut() {
+        zone.lifetime.tick(time.delta());
+        if zone.lifetime.is_finished() {
+            commands.entity(entity).try_despawn();
+            continue;
+        }
+
+        zone.warmup.tick(time.delta());
+        if zone.warmup.just_finished()
+            && let Some(material) = materials.get_mut(&material.0)
+        {
+            material.color = ZONE_ACTIVE_COLOR;
+        }
+        if !zone.warmup.is_finished() {
+            continue;
+        }
+
+        zone.damage_tick.tick(time.delta());
+        let inside = transform.translation.truncate().distance(player_transform.translation.truncate()) <= zone.radius;
+        if inside && zone.damage_tick.just_finished() && !motion.is_invulnerable() {
+            if let Some(effect) = &zone.effect {
+                effects.apply(effect, None);
+            }
+            // Alanın merkezinden dışarı itilir
+            damage_events.write(PlayerDamageEvent {
+                amount: zone.damage as u32,
+                source: Some(transform.translation.truncate()),
+                over_time: false,
+            });
+        }
+    }
+}
+
+/// Boss varken ekranın üstünde isim + can barı göster
+fn update_boss_health_bar(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    bosses: Query<(&Boss, &Enemy)>,
+    bars: Query<Entity, With<BossHealthBar>>,
+    mut fills: Query<&mut Node, With<BossHealthFill>>,
+    mut names: Query<&mut Text, With<BossNameText>>,
+    settings: Res<Settings>,
+) {
+    let Some((boss, enemy)) = bosses.iter().find(|(_, enemy)| enemy.health > 0) else {
+        for bar in bars.iter() {
+            commands.entity(bar).try_despawn();
+        }
+        return;
+    };
+
+    let fraction = (enemy.health as f32 / boss.max_health as f32).clamp(0.0, 1.0);
+    let label = format!("{} - {} {}", boss.kind.name(), UiText::Phase.get(settings.language), boss.phase + 1);
+
+    if bars.is_empty() {
+        spawn_boss_health_bar(&mut commands, &asset_server, label, fraction);
+        return;
+    }
+    for mut node in fills.iter_mut() {
+        node.width = Val::Percent(fraction * 100.0);
+    }
+    for mut text in names.iter_mut() {
+        if text.0 != label {
+            text.0 = label.clone();
+        }
+    }
+}
+
+fn spawn_boss_health_bar(commands: &mut Commands, asset_server: &AssetServer, label: String, fraction: f32) {
+    commands.spawn((
+        GameEntity,
+        BossHealthBar,
+        Node {
+            position_type: PositionType::Absolute,
+            // XP çubuğu ve süre sayacının altında
+            top: Val::Px(80.0),
+            left: Val::Percent(25.0),
+            width: Val::Percent(50.0),
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Center,
+            ..default()
+        },
+    )).with_children(|parent| {
+        parent.spawn((
+            BossNameText,
+            Text::new(label),
+            TextFont {
+                font: asset_server.load("fonts/FiraMono-Medium.ttf"),
+                font_size: 22.0,
+                ..default()
+            },
+            TextColor(Color::WHITE),
+        ));
+        parent.spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Px(16.0),
+                margin: UiRect::top(Val::Px(4.0)),
+                ..default()
+            },
+            Outline {
+                width: Val::Px(2.0),
+                offset: Val::Px(0.0),
+                color: Color::srgba(0.0, 0.0, 0.0, 0.8),
+            },
+            BackgroundColor(Color::srgba(0.15, 0.15, 0.15, 0.9)),
+        )).with_children(|bar| {
+            bar.spawn((
+                BossHealthFill,
+                Node {
+                    width: Val::Percent(fraction * 100.0),
+                    height: Val::Percent(100.0),
+                    ..default()
+                },
+                BackgroundColor(Color::srgb(0.8, 0.1, 0.1)),
+            ));
+        });
+    });
+}
+
+#[cfg(debug_assertions)]
+fn debug_spawn_boss(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    player_query: Query<&Transform, With<Player>>,
+    mut spawner: EnemySpawner,
+    director: Res<crate::plugins::spawn_director::SpawnDirector>,
+    mut next_kind: Local<usize>,
+    mut session: ResMut<crate::plugins::replay::ReplaySession>,
+) {
+    if !keyboard.just_pressed(KeyCode::F9) {
+        return;
+    }
+    let Ok(player_transform) = player_query.single() else {
+        return;
+    };
+    let kind = BossKind::ALL[*next_kind % BossKind::ALL.len()];
+    *next_kind += 1;
+    let position = player_transform.translation.with_z(0.0) + Vec3::new(0.0, 400.0, 0.0);
+    if spawn_enemy(&mut commands, &mut spawner, EnemyKind::Boss(kind), position, director.level).is_some() {
+        info!("debug: spawned {}", kind.name());
+        // Spawning akışını kayıt dışı ilerletti
+        session.discard_recording();
+    }
+}
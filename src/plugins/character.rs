@@ -0,0 +1,337 @@
+use std::collections::BTreeMap;
+use bevy::asset::LoadedFolder;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use crate::plugins::asset_folder::{index_folder, FolderAsset};
+use crate::plugins::hud::format_survival_time;
+use crate::plugins::localization::{Language, UiText};
+use crate::plugins::passive_item::{PassiveKind, PlayerStats};
+use crate::plugins::profile::RunRecords;
+use crate::plugins::ron_asset::{RonAsset, RonAssetAppExt};
+use crate::plugins::weapon_definition::WeaponId;
+
+/// Yönlü sprite sheet'lerde satırlar: yukarı, sol, aşağı, sağ
+const ROW_UP: usize = 0;
+const ROW_LEFT: usize = 1;
+const ROW_DOWN: usize = 2;
+const ROW_RIGHT: usize = 3;
+
+/// `assets/data/characters/*.character.ron` dosyalarını yükleyen plugin - seçim ekranı ve oyuncunun spawn'ı bunları kullanır
+pub struct CharacterPlugin;
+
+impl Plugin for CharacterPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_ron_asset::<CharacterDefinition>()
+            .init_resource::<CharacterDefinitions>()
+            .init_resource::<SelectedCharacter>()
+            .add_systems(Update, index_character_definitions);
+    }
+}
+
+/// Tanımı olmayan karakter seçilince ve eski kayıtlarda kullanılan karakter
+pub const DEFAULT_CHARACTER: &str = "skeleton";
+
+/// Karakter kimliği - tanım dosyasındaki `id`; yeni karakter eklemek için kod değişmez
+#[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Debug, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct CharacterId(pub String);
+
+impl CharacterId {
+    pub fn new(id: &str) -> Self {
+        Self(id.to_string())
+    }
+}
+
+impl Default for CharacterId {
+    fn default() -> Self {
+        Self::new(DEFAULT_CHARACTER)
+    }
+}
+
+impl std::fmt::Display for CharacterId {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// Bir karakterin tüm tasarım verisi
+#[derive(Asset, TypePath, Debug, Clone, Deserialize)]
+pub struct CharacterDefinition {
+    pub id: CharacterId,
+    pub name: String,
+    pub description: String,
+    pub sprite: CharacterSprite,
+    pub health: u32,
+    /// Piksel/sn
+    pub movement: f32,
+    /// Shop'ta başlangıç silahı seçildiyse onun yerine o verilir
+    pub starting_weapon: WeaponId,
+    #[serde(default)]
+    pub bonus: Option<StatBonus>,
+    #[serde(default)]
+    pub unlock: Unlock,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CharacterSprite {
+    /// `assets` altındaki sprite sheet
+    pub path: String,
+    pub columns: u32,
+    pub rows: u32,
+    /// Ekrandaki boyutun kare boyutuna oranı
+    #[serde(default = "default_scale")]
+    pub scale: f32,
+    pub animation: SpriteAnimation,
+}
+
+fn default_scale() -> f32 {
+    1.0
+}
+
+impl CharacterSprite {
+    /// Resmi `columns` x `rows` eşit kareye böler
+    pub fn frame_size(&self, image_size: UVec2) -> UVec2 {
+        UVec2::new(
+            (image_size.x as f32 / self.columns as f32).round() as u32,
+            (image_size.y as f32 / self.rows as f32).round() as u32,
+        )
+    }
+
+    pub fn layout(&self, image_size: UVec2) -> TextureAtlasLayout {
+        TextureAtlasLayout::from_grid(self.frame_size(image_size), self.columns, self.rows, None, None)
+    }
+
+    pub fn animation(&self) -> CharacterAnimation {
+        CharacterAnimation { columns: self.columns as usize, animation: self.animation }
+    }
+}
+
+/// Karakterin kalıcı stat bonusu - birimi pasif eşyalarınkiyle aynı (`per_level` gibi)
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+pub struct StatBonus {
+    pub kind: PassiveKind,
+    pub amount: f32,
+}
+
+impl StatBonus {
+    pub fn apply(self, stats: PlayerStats) -> PlayerStats {
+        let mut stats = stats;
+        stats.add(self.kind, self.amount);
+        stats
+    }
+}
+
+/// Yürürken karelerin nasıl seçildiği
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+pub enum SpriteAnimation {
+    /// Her satır bir yön (yukarı, sol, aşağı, sağ), sütunlar yürüme kareleri
+    Directional,
+    /// Tek yön: durunca `idle` karesi, yürürken `run` aralığı (ikisi dahil); sola giderken aynalanır
+    Strip { idle: usize, run: (usize, usize) },
+}
+
+/// Oyuncunun kare seçimi - varsayılanı `BODY_skeleton.png` (9 sütun, yönlü)
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct CharacterAnimation {
+    pub columns: usize,
+    pub animation: SpriteAnimation,
+}
+
+impl Default for CharacterAnimation {
+    fn default() -> Self {
+        Self { columns: 9, animation: SpriteAnimation::Directional }
+    }
+}
+
+impl CharacterAnimation {
+    /// `direction` hareket ya da bakış yönü; `step` ise yürüme karesi ilerler
+    pub fn next_frame(&self, index: usize, direction: Vec2, moving: bool, step: bool) -> usize {
+        match self.animation {
+            SpriteAnimation::Directional => {
+                let frame = index % self.columns;
+                let frame = if moving && step { (frame + 1) % self.columns } else { frame };
+                animation_row(direction) * self.columns + frame
+            }
+            SpriteAnimation::Strip { idle, run: (first, last) } => {
+                if !moving {
+                    idle
+                } else if !(first..=last).contains(&index) {
+                    first
+                } else if !step {
+                    index
+                } else if index == last {
+                    first
+                } else {
+                    index + 1
+                }
+            }
+        }
+    }
+
+    /// Şerit sola bakarken aynalanır; dikey harekette önceki yön korunur
+    pub fn flip_x(&self, direction: Vec2, flipped: bool) -> bool {
+        match self.animation {
+            SpriteAnimation::Directional => false,
+            SpriteAnimation::Strip { .. } if direction.x == 0.0 => flipped,
+            SpriteAnimation::Strip { .. } => direction.x < 0.0,
+        }
+    }
+}
+
+/// Hareket yönüne en yakın animasyon satırı; tam çaprazda dikey satır
+pub fn animation_row(direction: Vec2) -> usize {
+    if direction.x.abs() > direction.y.abs() {
+        if direction.x < 0.0 { ROW_LEFT } else { ROW_RIGHT }
+    } else if direction.y > 0.0 {
+        ROW_UP
+    } else {
+        ROW_DOWN
+    }
+}
+
+/// Karakterin kilidini açan koşul - profildeki rekorlarla karşılaştırılır
+#[derive(Debug, Clone, Copy, PartialEq, Default, Deserialize)]
+pub enum Unlock {
+    #[default]
+    Always,
+    /// Bir koşuda bu seviyeye ulaş
+    ReachLevel(i32),
+    /// Bir koşuda bu kadar dakika hayatta kal
+    SurviveMinutes(f32),
+}
+
+impl Unlock {
+    pub fn is_met(self, records: &RunRecords) -> bool {
+        match self {
+            Unlock::Always => true,
+            Unlock::ReachLevel(level) => records.level >= level,
+            Unlock::SurviveMinutes(minutes) => records.survived >= minutes * 60.0,
+        }
+    }
+
+    /// Kilitli kartta gösterilen koşul
+    pub fn describe(self, language: Language) -> String {
+        match self {
+            Unlock::Always => String::new(),
+            Unlock::ReachLevel(level) => format!("{}: {}", UiText::ReachLevel.get(language), level),
+            Unlock::SurviveMinutes(minutes) => format!("{}: {}", UiText::Survive.get(language), format_survival_time(minutes * 60.0)),
+        }
+    }
+}
+
+impl RonAsset for CharacterDefinition {
+    const EXTENSIONS: &'static [&'static str] = &["character.ron"];
+
+    fn validate(&self) -> Result<(), String> {
+        if self.id.0.trim().is_empty() {
+            return Err("id must not be empty".to_string());
+        }
+        if self.health == 0 {
+            return Err("health must be positive".to_string());
+        }
+        if self.movement <= 0.0 {
+            return Err("movement must be positive".to_string());
+        }
+        let sprite = &self.sprite;
+        if sprite.columns == 0 || sprite.rows == 0 {
+            return Err("sprite needs at least one column and row".to_string());
+        }
+        if sprite.scale <= 0.0 {
+            return Err("sprite scale must be positive".to_string());
+        }
+        let frames = (sprite.columns * sprite.rows) as usize;
+        match sprite.animation {
+            SpriteAnimation::Directional if sprite.rows < 4 => Err("Directional animation needs 4 rows".to_string()),
+            SpriteAnimation::Strip { idle, run: (first, last) } if idle >= frames || first > last || last >= frames => {
+                Err(format!("Strip frames must be within the {frames} frames of the sheet"))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl FolderAsset for CharacterDefinition {
+    type Id = CharacterId;
+    const LABEL: &'static str = "Character definition";
+
+    fn id(&self) -> &CharacterId {
+        &self.id
+    }
+}
+
+/// Yüklü karakter tanımları ve sprite sheet'leri
+#[derive(Resource)]
+pub struct CharacterDefinitions {
+    pub folder: Handle<LoadedFolder>,
+    pub by_id: BTreeMap<CharacterId, Handle<CharacterDefinition>>,
+    pub textures: BTreeMap<CharacterId, Handle<Image>>,
+    /// Seçim ekranındaki resimlerin atlas düzenleri - ekran her açıldığında yenisi eklenmesin
+    layouts: BTreeMap<CharacterId, Handle<TextureAtlasLayout>>,
+    pub ready: bool,
+}
+
+impl FromWorld for CharacterDefinitions {
+    fn from_world(world: &mut World) -> Self {
+        let asset_server = world.get_resource::<AssetServer>()
+            .expect("AssetServer resource not found.");
+        Self {
+            folder: asset_server.load_folder("data/characters"),
+            by_id: BTreeMap::new(),
+            textures: BTreeMap::new(),
+            layouts: BTreeMap::new(),
+            ready: false,
+        }
+    }
+}
+
+impl CharacterDefinitions {
+    pub fn get<'a>(&self, id: &CharacterId, assets: &'a Assets<CharacterDefinition>) -> Option<&'a CharacterDefinition> {
+        self.by_id.get(id).and_then(|handle| assets.get(handle))
+    }
+
+    pub fn iter<'a>(&'a self, assets: &'a Assets<CharacterDefinition>) -> impl Iterator<Item = &'a CharacterDefinition> + 'a {
+        self.by_id.values().filter_map(|handle| assets.get(handle))
+    }
+
+    /// Karakterin atlas düzeni; ilk istendiğinde bir kez eklenir
+    pub fn layout(
+        &mut self,
+        character: &CharacterDefinition,
+        image_size: UVec2,
+        layouts: &mut Assets<TextureAtlasLayout>,
+    ) -> Handle<TextureAtlasLayout> {
+        self.layouts
+            .entry(character.id.clone())
+            .or_insert_with(|| layouts.add(character.sprite.layout(image_size)))
+            .clone()
+    }
+}
+
+/// Seçim ekranında seçilen karakter - sonraki koşular onunla başlar
+#[derive(Resource, Default, Debug)]
+pub struct SelectedCharacter(pub CharacterId);
+
+/// Klasör yüklendiğinde tanımları indeksle ve sprite sheet'leri yüklemeye başla
+fn index_character_definitions(
+    asset_server: Res<AssetServer>,
+    folders: Res<Assets<LoadedFolder>>,
+    character_assets: Res<Assets<CharacterDefinition>>,
+    mut definitions: ResMut<CharacterDefinitions>,
+) {
+    if definitions.ready {
+        return;
+    }
+    let Some(by_id) = folders.get(&definitions.folder).and_then(|folder| index_folder(folder, &asset_server, &character_assets)) else {
+        return;
+    };
+    if !by_id.contains_key(&CharacterId::default()) {
+        error!("No character definition for {}, the default character", DEFAULT_CHARACTER);
+    }
+
+    definitions.textures = by_id
+        .iter()
+        .filter_map(|(id, handle)| Some((id.clone(), asset_server.load(character_assets.get(handle)?.sprite.path.clone()))))
+        .collect();
+    definitions.by_id = by_id;
+    definitions.ready = true;
+}
@@ -0,0 +1,262 @@
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use crate::plugins::character::{CharacterDefinition, CharacterDefinitions, CharacterId, SelectedCharacter};
+use crate::plugins::game_state::GameState;
+use crate::plugins::input_action::{ActionState, InputAction};
+use crate::plugins::localization::{Localized, UiText};
+use crate::plugins::main_menu::{button_hover_effect, spawn_button};
+use crate::plugins::profile::Profile;
+use crate::plugins::settings::Settings;
+use crate::plugins::weapon_definition::{WeaponDefinition, WeaponDefinitions};
+
+const CARD_WIDTH: f32 = 220.0;
+/// Kartlardaki karakter resminin yüksekliği
+const PORTRAIT_HEIGHT: f32 = 72.0;
+
+/// Ana menüde Play ile yükleme arasındaki karakter seçim ekranı.
+/// Kilidi açık karaktere tıklamak koşuyu onunla başlatır; kilitli kart açılma koşulunu gösterir.
+/// Tanımlar ya da resimler ekran açıldıktan sonra yüklenirse kartlar yeniden kurulur
+pub struct CharacterSelectPlugin;
+
+impl Plugin for CharacterSelectPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(GameState::CharacterSelect), setup_character_select)
+            .add_systems(
+                Update,
+                (build_character_cards, handle_character_buttons, back_on_cancel, button_hover_effect::<CharacterButton>)
+                    .run_if(in_state(GameState::CharacterSelect)),
+            )
+            .add_systems(OnExit(GameState::CharacterSelect), cleanup_character_select);
+    }
+}
+
+#[derive(Component)]
+struct CharacterSelectUI;
+
+/// Kartların dizildiği satır
+#[derive(Component, Default)]
+struct CharacterCardRow {
+    /// Kurulduğu andaki `CharacterCards::progress` - değişince kartlar yeniden kurulur
+    built: Option<(usize, usize, bool)>,
+}
+
+#[derive(Component, Clone, PartialEq, Eq, Debug)]
+pub enum CharacterButton {
+    /// Sadece kilidi açık karakterlerin kartı düğmedir
+    Pick(CharacterId),
+    Back,
+}
+
+/// Kartları kurmak için tanımlar, resimler ve kilitleri açan profil
+#[derive(SystemParam)]
+struct CharacterCards<'w> {
+    asset_server: Res<'w, AssetServer>,
+    characters: ResMut<'w, CharacterDefinitions>,
+    character_assets: Res<'w, Assets<CharacterDefinition>>,
+    images: Res<'w, Assets<Image>>,
+    texture_atlases: ResMut<'w, Assets<TextureAtlasLayout>>,
+    weapons: Res<'w, WeaponDefinitions>,
+    weapon_assets: Res<'w, Assets<WeaponDefinition>>,
+    profile: Res<'w, Profile>,
+    settings: Res<'w, Settings>,
+}
+
+impl CharacterCards<'_> {
+    /// Kart sayısı, resmi yüklenmiş karakter sayısı ve silah adlarının hazır olup olmadığı
+    fn progress(&self) -> (usize, usize, bool) {
+        let portraits = self.characters.textures.values().filter(|texture| self.images.contains(*texture)).count();
+        (self.characters.by_id.len(), portraits, self.weapons.ready)
+    }
+
+    /// İlk karesi; resim henüz yüklenmediyse `None`
+    fn portrait(&mut self, character: &CharacterDefinition) -> Option<(ImageNode, Vec2)> {
+        let texture = self.characters.textures.get(&character.id)?.clone();
+        let size = self.images.get(&texture)?.size();
+        let sprite = &character.sprite;
+        let layout = self.characters.layout(character, size, &mut self.texture_atlases);
+        let index = sprite.animation().next_frame(0, Vec2::NEG_Y, false, false);
+        let frame = sprite.frame_size(size).as_vec2();
+        Some((ImageNode::from_atlas_image(texture, TextureAtlas { layout, index }), frame * PORTRAIT_HEIGHT / frame.y))
+    }
+
+    /// Can, hız, silah ve bonus satırları
+    fn stats(&self, character: &CharacterDefinition) -> String {
+        let weapon = self
+            .weapons
+            .get(&character.starting_weapon, &self.weapon_assets)
+            .map_or_else(|| character.starting_weapon.to_string(), |definition| definition.name.clone());
+        let mut lines = vec![
+            format!("{} {}", UiText::MaxHealth.get(self.settings.language), character.health),
+            format!("{} {}", UiText::MoveSpeed.get(self.settings.language), character.movement),
+            weapon,
+        ];
+        if let Some(bonus) = character.bonus {
+            lines.push(format!("{} +{}", bonus.kind.name(), bonus.amount));
+        }
+        lines.join("\n")
+    }
+}
+
+fn setup_character_select(mut commands: Commands, asset_server: Res<AssetServer>) {
+    let font = asset_server.load("fonts/FiraMono-Medium.ttf");
+
+    commands.spawn((
+        CharacterSelectUI,
+        Node {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            flex_direction: FlexDirection::Column,
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            ..default()
+        },
+        BackgroundColor(Color::srgb(0.1, 0.1, 0.1)),
+    )).with_children(|parent| {
+        parent.spawn((
+            Localized(UiText::ChooseCharacter),
+            TextFont {
+                font: font.clone(),
+                font_size: 50.0,
+                ..default()
+            },
+            Node {
+                margin: UiRect::bottom(Val::Px(30.0)),
+                ..default()
+            },
+        ));
+        parent.spawn((
+            CharacterCardRow::default(),
+            Node {
+                flex_wrap: FlexWrap::Wrap,
+                justify_content: JustifyContent::Center,
+                margin: UiRect::bottom(Val::Px(20.0)),
+                ..default()
+            },
+        ));
+        spawn_button(parent, UiText::Back, CharacterButton::Back, font.clone());
+    });
+}
+
+/// Kartları kur; tanımlar ya da resimler sonradan yüklenince yeniden kur
+fn build_character_cards(
+    mut commands: Commands,
+    mut rows: Query<(Entity, &mut CharacterCardRow, Option<&Children>)>,
+    mut cards: CharacterCards,
+) {
+    let Ok((row_entity, mut row, children)) = rows.single_mut() else {
+        return;
+    };
+    let progress = cards.progress();
+    if row.built == Some(progress) {
+        return;
+    }
+    row.built = Some(progress);
+    for child in children.into_iter().flatten() {
+        commands.entity(*child).despawn();
+    }
+
+    let font = cards.asset_server.load("fonts/FiraMono-Medium.ttf");
+    let characters: Vec<CharacterDefinition> = cards.characters.iter(&cards.character_assets).cloned().collect();
+    commands.entity(row_entity).with_children(|row| {
+        for character in &characters {
+            let unlocked = character.unlock.is_met(&cards.profile.records);
+            let mut card = row.spawn((
+                Node {
+                    width: Val::Px(CARD_WIDTH),
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    padding: UiRect::all(Val::Px(12.0)),
+                    margin: UiRect::all(Val::Px(8.0)),
+                    ..default()
+                },
+                BackgroundColor(if unlocked { Color::srgb(0.3, 0.3, 0.3) } else { Color::srgb(0.18, 0.18, 0.18) }),
+            ));
+            if unlocked {
+                card.insert((Button, CharacterButton::Pick(character.id.clone())));
+            }
+            let portrait = cards.portrait(character);
+            let stats = cards.stats(character);
+            let unlock = character.unlock.describe(cards.settings.language);
+            card.with_children(|card| {
+                if let Some((mut image, size)) = portrait {
+                    if !unlocked {
+                        image.color = Color::BLACK;
+                    }
+                    card.spawn((image, Node { width: Val::Px(size.x), height: Val::Px(size.y), ..default() }));
+                }
+                card.spawn((
+                    Text::new(character.name.clone()),
+                    TextFont {
+                        font: font.clone(),
+                        font_size: 28.0,
+                        ..default()
+                    },
+                    Node {
+                        margin: UiRect::vertical(Val::Px(8.0)),
+                        ..default()
+                    },
+                ));
+                if unlocked {
+                    card.spawn((
+                        Text::new(format!("{}\n\n{}", stats, character.description)),
+                        TextFont {
+                            font: font.clone(),
+                            font_size: 16.0,
+                            ..default()
+                        },
+                    ));
+                } else {
+                    card.spawn((
+                        Localized(UiText::Locked),
+                        TextFont {
+                            font: font.clone(),
+                            font_size: 20.0,
+                            ..default()
+                        },
+                        TextColor(Color::srgb(1.0, 0.4, 0.4)),
+                    ));
+                    card.spawn((
+                        Text::new(unlock),
+                        TextFont {
+                            font: font.clone(),
+                            font_size: 16.0,
+                            ..default()
+                        },
+                    ));
+                }
+            });
+        }
+    });
+}
+
+fn handle_character_buttons(
+    interactions_q: Query<(&Interaction, &CharacterButton), Changed<Interaction>>,
+    mut selected: ResMut<SelectedCharacter>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    for (interaction, button) in &interactions_q {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        match button {
+            CharacterButton::Pick(id) => {
+                println!("🧙 {} selected", id);
+                selected.0 = id.clone();
+                next_state.set(GameState::Loading);
+            }
+            CharacterButton::Back => next_state.set(GameState::MainMenu),
+        }
+    }
+}
+
+fn back_on_cancel(actions: Res<ActionState>, mut next_state: ResMut<NextState<GameState>>) {
+    if actions.just_pressed(InputAction::Cancel) {
+        next_state.set(GameState::MainMenu);
+    }
+}
+
+fn cleanup_character_select(mut commands: Commands, screen: Query<Entity, With<CharacterSelectUI>>) {
+    for entity in &screen {
+        commands.entity(entity).despawn();
+    }
+}
@@ -0,0 +1,301 @@
+use bevy::audio::{AudioPlayer, PlaybackSettings};
+use bevy::prelude::*;
+use rand::prelude::IndexedRandom;
+use crate::plugins::audio::{GameAudio, GameAudioEntity};
+use crate::plugins::game_state::{GameState, GameplaySet};
+use crate::plugins::input_action::{ActionState, InputAction};
+use crate::plugins::localization::{Localized, UiText};
+use crate::plugins::loot::{collect_pickups, ChestOpenedEvent};
+use crate::plugins::main_menu::{button_hover_effect, spawn_button};
+use crate::plugins::player::{collect_xp, Player};
+use crate::plugins::replay::live_input;
+use crate::plugins::rng::RngStream;
+use crate::plugins::settings::Settings;
+use crate::plugins::weapon_evolution::EvolutionRecipe;
+use crate::plugins::weapon_upgrade::{UpgradeApplier, UpgradeKind, UpgradePool};
+
+/// Sandık açılmadan önce titrediği süre
+const CHEST_SHAKE_SECS: f32 = 0.6;
+/// Ödüller bu aralıkla tek tek belirir
+const REVEAL_INTERVAL: f32 = 0.4;
+const REWARD_FADE_SECS: f32 = 0.25;
+/// Yükseltilecek bir şey kalmadıysa ödül yerine altın
+pub const FALLBACK_GOLD: u32 = 25;
+
+/// Sandık açılınca oyun durur, ödüller (evrim, yükseltme ya da altın) kareler boyunca tek tek verilir
+/// ve ekranda sırayla belirir. Confirm animasyonu atlar, sonra oyuna döner.
+pub struct ChestRewardPlugin;
+
+impl Plugin for ChestRewardPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ChestRewards>()
+            // Aynı tick'te seviye atlandıysa önce yükseltme ekranı açılır
+            .add_systems(FixedUpdate, open_chests.after(collect_pickups).after(collect_xp).in_set(GameplaySet))
+            .add_systems(OnEnter(GameState::Loading), reset_chest_rewards)
+            .add_systems(OnEnter(GameState::ChestReward), setup_chest_screen)
+            .add_systems(
+                Update,
+                (
+                    (roll_chest_reward, grant_chest_reward).chain(),
+                    animate_chest_screen,
+                    (close_chest_screen, button_hover_effect::<ChestButton>).run_if(live_input),
+                    // Oynatmada ekranı kapatan girdi kayıtlı değil
+                    finish_chest_reward.run_if(not(live_input).and(rewards_granted)),
+                ).run_if(in_state(GameState::ChestReward)),
+            )
+            .add_systems(OnExit(GameState::ChestReward), cleanup_chest_screen);
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum ChestRewardKind {
+    /// Maks seviyedeki silahın evrimi
+    Evolution(Entity, EvolutionRecipe),
+    Upgrade(UpgradeKind),
+    Gold(u32),
+}
+
+/// Verilen bir ödül ve ekranda görünen adı
+#[derive(Clone, Debug)]
+pub struct ChestReward {
+    pub kind: ChestRewardKind,
+    pub label: String,
+}
+
+#[derive(Resource, Default, Debug)]
+pub struct ChestRewards {
+    /// Henüz seçilmemiş ödül sayısı - başka bir ekran açılıyorsa sandık Playing'e dönülünce açılır
+    pub pending: u32,
+    /// Seçilmiş, bu kare verilecek ödül
+    rolled: Option<ChestReward>,
+    pub granted: Vec<ChestReward>,
+    /// Ekran açılalı geçen süre
+    elapsed: f32,
+}
+
+impl ChestRewards {
+    /// Tüm ödüller verildi ve ekranda belirdi
+    pub fn revealed(&self) -> bool {
+        self.pending == 0 && self.rolled.is_none() && self.elapsed >= self.reveal_end()
+    }
+
+    /// Verilen son ödülün tamamen göründüğü an
+    fn reveal_end(&self) -> f32 {
+        let last = self.granted.len().saturating_sub(1) as f32;
+        CHEST_SHAKE_SECS + last * REVEAL_INTERVAL + REWARD_FADE_SECS
+    }
+
+    fn revealed_count(&self) -> usize {
+        if self.elapsed < CHEST_SHAKE_SECS {
+            return 0;
+        }
+        (((self.elapsed - CHEST_SHAKE_SECS) / REVEAL_INTERVAL) as usize).saturating_add(1).min(self.granted.len())
+    }
+}
+
+pub fn rewards_granted(rewards: Res<ChestRewards>) -> bool {
+    rewards.pending == 0 && rewards.rolled.is_none()
+}
+
+#[derive(Component)]
+struct ChestScreenUI;
+
+/// Titreyip açılan sandık resmi
+#[derive(Component)]
+struct ChestImage {
+    opened: bool,
+}
+
+#[derive(Component)]
+struct ChestRewardList;
+
+/// Belirdiği an - yazı bundan sonra görünür olur
+#[derive(Component)]
+struct ChestRewardText {
+    shown_at: f32,
+}
+
+#[derive(Component)]
+pub struct ChestButton;
+
+fn open_chests(
+    mut commands: Commands,
+    mut chest_events: MessageReader<ChestOpenedEvent>,
+    mut rewards: ResMut<ChestRewards>,
+    mut next_state: ResMut<NextState<GameState>>,
+    audio: Res<GameAudio>,
+) {
+    for event in chest_events.read() {
+        rewards.pending += event.rewards;
+        commands.spawn((
+            GameAudioEntity,
+            AudioPlayer(audio.collect_xp.clone()),
+            PlaybackSettings::DESPAWN,
+        ));
+    }
+    if rewards.pending > 0 && matches!(*next_state, NextState::Unchanged) {
+        next_state.set(GameState::ChestReward);
+    }
+}
+
+fn reset_chest_rewards(mut rewards: ResMut<ChestRewards>) {
+    *rewards = ChestRewards::default();
+}
+
+/// Hazır bir evrim varsa o (Loot akışıyla), yoksa rastgele bir yükseltme, o da yoksa altın.
+/// Her kare bir ödül: önceki ödülün yeni silahı bir sonraki seçimde görünsün
+fn roll_chest_reward(mut rewards: ResMut<ChestRewards>, mut pool: UpgradePool, settings: Res<Settings>) {
+    if rewards.pending == 0 || rewards.rolled.is_some() {
+        return;
+    }
+    let text = |text: UiText| text.get(settings.language);
+    let ready = pool.rules.ready_evolutions(pool.inventory());
+    let reward = if let Some((weapon, recipe)) = ready.choose(pool.rng().stream(RngStream::Loot)) {
+        let name = pool.rules.definition(&recipe.evolves_into).map_or_else(|| recipe.evolves_into.to_string(), |definition| definition.name.clone());
+        ChestReward { kind: ChestRewardKind::Evolution(*weapon, recipe.clone()), label: format!("{}: {}", text(UiText::Evolution), name) }
+    } else if let Some(option) = pool.candidates().choose(pool.rng().stream(RngStream::Loot)) {
+        ChestReward { kind: ChestRewardKind::Upgrade(option.kind.clone()), label: format!("{} - {}", option.name, option.description) }
+    } else {
+        ChestReward { kind: ChestRewardKind::Gold(FALLBACK_GOLD), label: format!("+{} {}", FALLBACK_GOLD, text(UiText::Gold)) }
+    };
+    rewards.pending -= 1;
+    rewards.rolled = Some(reward);
+}
+
+fn grant_chest_reward(mut rewards: ResMut<ChestRewards>, mut applier: UpgradeApplier, mut player: Single<&mut Player>) {
+    let Some(reward) = rewards.rolled.take() else {
+        return;
+    };
+    match reward.kind.clone() {
+        ChestRewardKind::Evolution(weapon, recipe) => applier.evolve(weapon, recipe),
+        ChestRewardKind::Upgrade(kind) => applier.apply(kind),
+        ChestRewardKind::Gold(amount) => player.gold += amount,
+    }
+    rewards.granted.push(reward);
+}
+
+fn setup_chest_screen(mut commands: Commands, asset_server: Res<AssetServer>, mut rewards: ResMut<ChestRewards>) {
+    rewards.elapsed = 0.0;
+    let font = asset_server.load("fonts/FiraMono-Medium.ttf");
+
+    commands.spawn((
+        ChestScreenUI,
+        Node {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            position_type: PositionType::Absolute,
+            flex_direction: FlexDirection::Column,
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.7)),
+        // HUD ve boss çubuğunun üstünde
+        GlobalZIndex(10),
+    )).with_children(|parent| {
+        parent.spawn((
+            Localized(UiText::Treasure),
+            TextFont {
+                font: font.clone(),
+                font_size: 50.0,
+                ..default()
+            },
+            TextColor(Color::srgb(1.0, 0.85, 0.2)),
+        ));
+        parent.spawn((
+            ChestImage { opened: false },
+            ImageNode::new(asset_server.load("textures/rpg/props/generic-rpg-treasure-closed.png")),
+            Node {
+                width: Val::Px(100.0),
+                height: Val::Px(108.0),
+                margin: UiRect::vertical(Val::Px(20.0)),
+                ..default()
+            },
+        ));
+        parent.spawn((
+            ChestRewardList,
+            Node {
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(8.0),
+                min_height: Val::Px(200.0),
+                ..default()
+            },
+        ));
+        spawn_button(parent, UiText::Continue, ChestButton, font);
+    });
+}
+
+/// Sandık titrer, açılır, sonra ödüller sırayla solarak belirir
+fn animate_chest_screen(
+    mut commands: Commands,
+    time: Res<Time>,
+    asset_server: Res<AssetServer>,
+    mut rewards: ResMut<ChestRewards>,
+    mut chest: Query<(&mut ChestImage, &mut ImageNode, &mut Node)>,
+    list: Query<Entity, With<ChestRewardList>>,
+    mut texts: Query<(&ChestRewardText, &mut TextColor)>,
+) {
+    rewards.elapsed += time.delta_secs();
+    let elapsed = rewards.elapsed;
+
+    for (mut image, mut node, mut layout) in chest.iter_mut() {
+        if elapsed < CHEST_SHAKE_SECS {
+            layout.left = Val::Px((elapsed * 60.0).sin() * 4.0);
+        } else if !image.opened {
+            image.opened = true;
+            layout.left = Val::Px(0.0);
+            node.image = asset_server.load("textures/rpg/props/generic-rpg-trasure-open.png");
+        }
+    }
+
+    for (text, mut color) in texts.iter_mut() {
+        let alpha = ((elapsed - text.shown_at) / REWARD_FADE_SECS).clamp(0.0, 1.0);
+        color.0.set_alpha(alpha);
+    }
+
+    let Ok(list) = list.single() else {
+        return;
+    };
+    let shown = texts.iter().count();
+    for (index, reward) in rewards.granted.iter().enumerate().take(rewards.revealed_count()).skip(shown) {
+        commands.entity(list).with_child((
+            ChestRewardText { shown_at: CHEST_SHAKE_SECS + index as f32 * REVEAL_INTERVAL },
+            Text::new(reward.label.clone()),
+            TextFont {
+                font_size: 24.0,
+                ..default()
+            },
+            TextColor(Color::WHITE.with_alpha(0.0)),
+        ));
+    }
+}
+
+/// Confirm ya da Devam: animasyon sürüyorsa sona atla, bittiyse oyuna dön
+fn close_chest_screen(
+    actions: Res<ActionState>,
+    buttons: Query<&Interaction, (Changed<Interaction>, With<ChestButton>)>,
+    mut rewards: ResMut<ChestRewards>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    let pressed = actions.just_pressed(InputAction::Confirm) || buttons.iter().any(|interaction| *interaction == Interaction::Pressed);
+    if !pressed {
+        return;
+    }
+    if rewards.revealed() {
+        next_state.set(GameState::Playing);
+    } else {
+        rewards.elapsed = rewards.elapsed.max(rewards.reveal_end());
+    }
+}
+
+fn finish_chest_reward(mut next_state: ResMut<NextState<GameState>>) {
+    next_state.set(GameState::Playing);
+}
+
+fn cleanup_chest_screen(mut commands: Commands, screen: Query<Entity, With<ChestScreenUI>>, mut rewards: ResMut<ChestRewards>) {
+    for entity in &screen {
+        commands.entity(entity).despawn();
+    }
+    rewards.granted.clear();
+}
@@ -0,0 +1,173 @@
+use std::collections::BTreeMap;
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use rand::Rng;
+use serde::Deserialize;
+use crate::plugins::aabb::AABB;
+use crate::plugins::enemy::{Enemy, EnemyDrops};
+use crate::plugins::game_state::GameState;
+use crate::plugins::player::Player;
+use crate::plugins::rng::{GameRng, RngStream};
+use crate::plugins::status_effect::{StatusApplier, StatusEffects};
+use crate::plugins::weapon_definition::{WeaponBehaviour, WeaponId};
+use crate::plugins::weapons::WeaponHitEvent;
+
+/// Kritik isabetlerin hasar çarpanı
+pub const CRIT_MULTIPLIER: f32 = 2.0;
+
+/// Düşmanlara verilen tüm hasar `DamageEvent` olarak yazılır ve tek bir sistemde işlenir
+pub struct DamagePlugin;
+
+impl Plugin for DamagePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_message::<DamageEvent>()
+            .init_resource::<DamageMeter>()
+            .add_systems(Update, tally_damage)
+            .add_systems(OnEnter(GameState::Loading), reset_damage_meter);
+    }
+}
+
+/// Bu koşuda her silahın verdiği toplam hasar - duraklatma menüsünün DPS'i buradan
+#[derive(Resource, Default, Debug)]
+pub struct DamageMeter {
+    pub per_weapon: BTreeMap<WeaponId, i64>,
+}
+
+impl DamageMeter {
+    /// `elapsed` saniyelik koşudaki saniye başı hasar
+    pub fn dps(&self, weapon: &WeaponId, elapsed: f32) -> f32 {
+        let damage = self.per_weapon.get(weapon).copied().unwrap_or(0);
+        if elapsed > 0.0 { damage as f32 / elapsed } else { 0.0 }
+    }
+
+    pub fn total_dps(&self, elapsed: f32) -> f32 {
+        self.per_weapon.keys().map(|weapon| self.dps(weapon, elapsed)).sum()
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, Deserialize)]
+pub enum DamageType {
+    #[default]
+    Physical,
+    Fire,
+    Poison,
+    Energy,
+}
+
+/// Bir düşmana verilecek ham hasar - armor, direnç ve çarpanlar `apply_damage`'de uygulanır
+#[derive(Message, Clone, Debug)]
+pub struct DamageEvent {
+    /// Hasarı veren silahın tipi - istatistik ve öldürme sahipliği için
+    pub source: Option<WeaponId>,
+    /// İsabet eden silah entity'si - isabet etkileri buradan okunur; süreli hasarlarda yok
+    pub weapon: Option<Entity>,
+    pub target: Entity,
+    pub amount: f32,
+    pub damage_type: DamageType,
+    pub crit: bool,
+    /// Süreli hasar tick'i
+    pub over_time: bool,
+    pub knockback: Vec3,
+}
+
+/// Silahın hasar tipi ve kritik şansı - tanımdan gelir
+#[derive(Component, Clone, Copy, Debug, Default)]
+pub struct DamageProfile {
+    pub damage_type: DamageType,
+    pub crit_chance: f32,
+}
+
+/// Silah isabetlerini `DamageEvent`'e çevirir; kritik zarı `Combat` akışından atılır
+#[derive(SystemParam)]
+pub struct DamageWriter<'w, 's> {
+    messages: MessageWriter<'w, DamageEvent>,
+    profiles: Query<'w, 's, &'static DamageProfile>,
+    rng: ResMut<'w, GameRng>,
+}
+
+impl DamageWriter<'_, '_> {
+    pub fn weapon_hit(&mut self, weapon: Entity, source: WeaponId, target: Entity, amount: f32, knockback: Vec3) {
+        let profile = self.profiles.get(weapon).copied().unwrap_or_default();
+        let crit = profile.crit_chance > 0.0 && self.rng.stream(RngStream::Combat).random::<f32>() < profile.crit_chance;
+        self.messages.write(DamageEvent {
+            source: Some(source),
+            weapon: Some(weapon),
+            target,
+            amount,
+            damage_type: profile.damage_type,
+            crit,
+            over_time: false,
+            knockback,
+        });
+    }
+}
+
+/// Hasarın tek işlendiği yer: isabet etkileri, direnç/armor, kritik, geri itme, ölüm ve XP
+pub fn apply_damage(
+    mut commands: Commands,
+    mut damage_events: MessageReader<DamageEvent>,
+    mut targets: Query<(&mut Enemy, &mut Transform, &mut AABB, &mut StatusEffects), Without<Player>>,
+    mut status: StatusApplier,
+    behaviours: Query<&WeaponBehaviour>,
+    mut player: Single<&mut Player>,
+    mut drops: EnemyDrops,
+) {
+    for event in damage_events.read() {
+        let Ok((mut enemy, mut transform, mut aabb, mut effects)) = targets.get_mut(event.target) else {
+            continue;
+        };
+        // Aynı tick'te daha önce öldürülmüş
+        if enemy.health <= 0 {
+            continue;
+        }
+
+        // Önce isabet etkileri - Vulnerable bu isabeti de etkilesin
+        if let (Some(weapon), Some(source)) = (event.weapon, &event.source) {
+            status.apply(weapon, source, &mut effects);
+        }
+
+        let profile = enemy.kind.profile();
+        let mut amount = event.amount * profile.resistance(event.damage_type) * effects.damage_taken_multiplier();
+        if event.crit {
+            amount *= CRIT_MULTIPLIER;
+        }
+        let damage = ((amount - profile.armor).max(1.0) as i32).min(enemy.health);
+
+        if event.knockback != Vec3::ZERO {
+            transform.translation += event.knockback * (1.0 - profile.knockback_resistance).max(0.0);
+            aabb.change_point(transform.translation);
+        }
+
+        enemy.health -= damage;
+        if let Some(weapon) = &event.source {
+            commands.write_message(WeaponHitEvent {
+                weapon: weapon.clone(),
+                target: event.target,
+                position: transform.translation,
+                damage,
+                crit: event.crit,
+                over_time: event.over_time,
+            });
+        }
+        if enemy.health > 0 {
+            continue;
+        }
+
+        player.score += 1;
+        // Soul Inferno: öldürülen her düşman can verir
+        if let Some(WeaponBehaviour::LifeDrain { heal_per_kill }) = event.weapon.and_then(|weapon| behaviours.get(weapon).ok()) {
+            player.heal(*heal_per_kill, &mut commands);
+        }
+        enemy.despawn(event.target, &transform.translation, event.source.clone(), &mut drops, &mut commands);
+    }
+}
+
+fn tally_damage(mut hits: MessageReader<WeaponHitEvent>, mut meter: ResMut<DamageMeter>) {
+    for hit in hits.read() {
+        *meter.per_weapon.entry(hit.weapon.clone()).or_default() += hit.damage as i64;
+    }
+}
+
+fn reset_damage_meter(mut meter: ResMut<DamageMeter>) {
+    meter.per_weapon.clear();
+}
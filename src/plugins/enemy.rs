@@ -1,35 +1,51 @@
-use std::f32::consts::PI;
 use bevy::asset::Assets;
 use bevy::audio::{AudioPlayer, PlaybackSettings};
-use bevy::color::Color;
 use bevy::image::TextureAtlas;
-use bevy::mesh::{Mesh, Mesh2d};
-use bevy::prelude::{Children, Circle, ColorMaterial, Component, InheritedVisibility, MeshMaterial2d, Query, Resource, Sprite, Time, Timer, Transform, Vec3, With};
+use bevy::mesh::Mesh;
+use bevy::prelude::{Children, Color, ColorMaterial, Component, InheritedVisibility, Message, Query, Sprite, Time, Timer, Transform, Vec3, With};
 use bevy::time::TimerMode;
 use bevy_ecs::change_detection::{Res, ResMut};
 use bevy_ecs::entity::Entity;
 use bevy_ecs::prelude::{Commands, Without};
+use bevy_ecs::system::SystemParam;
 use rand::Rng;
+use serde::{Deserialize, Serialize};
 use crate::Atlases;
 use crate::plugins::aabb::AABB;
 use crate::plugins::audio::GameAudio;
+use crate::plugins::boss::Boss;
+use crate::plugins::hit_feedback::HitFlash;
+use crate::plugins::enemy_kind::{Charger, ChargerState, EnemyBehaviour, EnemyKind, EnemySprites, RangedAttacker};
 use crate::plugins::player::Player;
+use crate::plugins::rng::{GameRng, RngStream};
+use crate::plugins::spatial_grid::{SpatialGrid, SpatialLayer};
+use crate::plugins::status_effect::{StatusEffects, StatusKind};
 use crate::plugins::texture_handling::TextureAssets;
-use crate::plugins::timers::{EnemySpawnTimer, MoveTimer};
+use crate::plugins::timers::MoveTimer;
+use crate::plugins::weapon_definition::WeaponId;
 use crate::plugins::weapons::GameEntity;
+use crate::plugins::xp_orb::{spawn_xp_orb, XpOrbAssets};
 
-#[derive(Component)]
+#[derive(Component, Clone, Serialize, Deserialize)]
+#[require(StatusEffects, HitFlash)]
 pub struct Enemy {
     pub health: i32,
     pub speed: f32,
     pub damage: i32,
-}
-#[derive(Resource)]
-pub struct EnemyPowerUpTimer {
-    pub timer: Timer,
-    pub level: i32,
+    pub kind: EnemyKind,
+    pub xp: i32,
+    /// Son hareket yönü - animasyon satırı / sprite yönü için
+    pub direction: Vec3,
 }
 
+/// Bir düşman öldüğünde gönderilir (ör. Splitter'ların bölünmesi için)
+#[derive(Message, Clone, Debug)]
+pub struct EnemyDiedEvent {
+    pub kind: EnemyKind,
+    pub position: Vec3,
+    /// Öldüren silah - süreli hasarlarda etkiyi uygulayan silah
+    pub killer: Option<WeaponId>,
+}
 #[derive(Component)]
 pub struct XP{
     pub amount: i32,
@@ -38,45 +54,22 @@ pub struct XP{
 #[derive(Component)]
 pub struct Collectible;
 
-impl Default for EnemyPowerUpTimer {
-    fn default() -> Self {
-        Self {
-            timer: Timer::from_seconds(40.0, TimerMode::Repeating),
-            level: 1,
-        }
-    }
-}
-
 impl Enemy {
     pub fn despawn(&mut self, 
                    entity: Entity, 
                    translation: &Vec3, 
-                   meshes: &mut Assets<Mesh>, 
-                   mesh_material: &mut Assets<ColorMaterial>, 
+                   killer: Option<WeaponId>,
+                   drops: &mut EnemyDrops,
                    commands: &mut Commands,
-                   audio: &GameAudio,
     ) {
+        spawn_xp_orb(commands, &drops.xp_orbs, self.xp, *translation);
         commands.spawn((
-            GameEntity,
-            Collectible,
-            XP{ amount: 20 },
-            Transform::from_translation(*translation),
-            AABB{
-                max_x: translation.x + 20.,
-                max_y: translation.y + 20.,
-                min_x: translation.x - 20.,
-                min_y: translation.y - 20.,
-                width: 20.,
-                height: 20.,
-            },
-            Mesh2d(meshes.add(Circle::new(5.0))),
-            MeshMaterial2d(mesh_material.add(ColorMaterial::from(Color::srgb(0.8, 0.0, 0.0)))),
-            ));
-        commands.spawn((
-            AudioPlayer(audio.enemy_hit.clone()),
+            AudioPlayer(drops.audio.enemy_hit.clone()),
             PlaybackSettings::DESPAWN,
             ));
         
+        commands.write_message(EnemyDiedEvent { kind: self.kind, position: *translation, killer });
+
         commands.entity(entity).try_despawn();
     }
 }
@@ -84,17 +77,158 @@ impl Enemy {
 #[derive(Component)]
 pub struct EnemySprit {
     pub index: usize,
+    /// Animasyondaki kare sayısı
+    pub frames: usize,
+    /// İskelet atlası gibi 4 yönlü satırlardan mı oluşuyor, yoksa tek satır + flip mi
+    pub directional: bool,
+}
+
+/// Atılmaya hazırlanan ve atılan charger'ın rengi
+const TELEGRAPH_TINT: Color = Color::srgb(1.0, 0.3, 0.3);
+
+/// Düşmanın sprite'larını boya - charge uyarısı, isabet parlaması ve durum etkisi rengi
+pub fn tint_enemy_sprites(children: &Children, sprites: &mut Query<&mut Sprite, With<EnemySprit>>, color: Color) {
+    for &child in children.iter() {
+        if let Ok(mut sprite) = sprites.get_mut(child) {
+            sprite.color = color;
+        }
+    }
 }
 
+/// Parlama bitince ya da durum etkisi değişince dönülecek renk: charge uyarısı durum etkisini ezer
+pub fn resting_tint(effects: &StatusEffects, charger: Option<&Charger>) -> Color {
+    match charger {
+        Some(charger) if matches!(charger.state, ChargerState::Telegraphing | ChargerState::Dashing) => TELEGRAPH_TINT,
+        _ => effects.tint().map_or(Color::WHITE, StatusKind::tint),
+    }
+}
+
+/// Ölen düşmanın XP küresi, sesi ve düşen eşyalar için gereken kaynaklar
+#[derive(SystemParam)]
+pub struct EnemyDrops<'w> {
+    pub meshes: ResMut<'w, Assets<Mesh>>,
+    pub materials: ResMut<'w, Assets<ColorMaterial>>,
+    pub audio: Res<'w, GameAudio>,
+    pub xp_orbs: Res<'w, XpOrbAssets>,
+}
+
+/// Düşman spawn etmek için gereken texture / atlas kaynakları ve spawn RNG'si
+#[derive(SystemParam)]
+pub struct EnemySpawner<'w> {
+    pub atlases: Res<'w, Atlases>,
+    pub textures: Res<'w, TextureAssets>,
+    pub sprites: Res<'w, EnemySprites>,
+    pub rng: ResMut<'w, GameRng>,
+}
+
+impl EnemySpawner<'_> {
+    pub fn ready(&self) -> bool {
+        self.atlases.ready
+    }
+}
+
+/// Verilen arketipten, güç seviyesine göre ölçeklenmiş bir düşman spawn et
+pub fn spawn_enemy(
+    commands: &mut Commands,
+    spawner: &mut EnemySpawner,
+    kind: EnemyKind,
+    position: Vec3,
+    level: i32,
+) -> Option<Entity> {
+    if !spawner.ready() {
+        return None;
+    }
+    let profile = kind.profile();
+    let (min_speed, max_speed) = profile.speed;
+    let half = profile.size / 2.;
+
+    let mut enemy = commands.spawn((
+        GameEntity,
+        Transform::from_translation(position),
+        Enemy {
+            health: profile.health * level,
+            damage: profile.damage * level,
+            speed: spawner.rng.stream(RngStream::Spawning).random_range((min_speed * level as f32)..(max_speed * level as f32)),
+            kind,
+            xp: profile.xp,
+            direction: Vec3::ZERO,
+        },
+        InheritedVisibility::default(),
+        AABB {
+            max_x: position.x + half,
+            max_y: position.y + half,
+            min_x: position.x - half,
+            min_y: position.y - half,
+            width: profile.size,
+            height: profile.size,
+        },
+    ));
+
+    match profile.behaviour {
+        EnemyBehaviour::Ranged { preferred_distance, fire_interval, projectile_speed, projectile_damage } => {
+            enemy.insert(RangedAttacker {
+                preferred_distance,
+                fire_timer: Timer::from_seconds(fire_interval, TimerMode::Repeating),
+                projectile_speed,
+                projectile_damage: projectile_damage * level,
+            });
+        }
+        EnemyBehaviour::Charger { trigger_range, windup, dash_speed, dash_duration, cooldown } => {
+            enemy.insert(Charger {
+                state: ChargerState::Approaching,
+                timer: Timer::from_seconds(windup, TimerMode::Once),
+                dash_direction: Vec3::ZERO,
+                trigger_range,
+                windup,
+                dash_speed,
+                dash_duration,
+                cooldown,
+            });
+        }
+        EnemyBehaviour::Boss(boss) => {
+            enemy.insert(Boss::new(boss, profile.health * level, level));
+        }
+        EnemyBehaviour::Chase | EnemyBehaviour::Splitter { .. } => {}
+    }
+
+    match profile.sprite {
+        Some(sheet) => {
+            let (image, layout) = spawner.sprites.sheets.get(&kind)?.clone();
+            enemy.with_children(|parent| {
+                parent.spawn((
+                    Sprite::from_atlas_image(image, TextureAtlas { layout, index: 0 }),
+                    Transform::from_scale(Vec3::splat(sheet.scale)),
+                    EnemySprit { index: 0, frames: sheet.frames as usize, directional: false },
+                ));
+            });
+        }
+        None => {
+            let body_atlas = spawner.atlases.body.as_ref()?.clone();
+            let shield_atlas = spawner.atlases.shield.as_ref()?.clone();
+            enemy.with_children(|parent| {
+                parent.spawn((
+                    Sprite::from_atlas_image(spawner.textures.body.clone(), TextureAtlas { layout: body_atlas.clone(), index: 15 }),
+                    EnemySprit { index: 0, frames: 9, directional: true },
+                ));
+                parent.spawn((
+                    Sprite::from_atlas_image(spawner.textures.shield.clone(), TextureAtlas { layout: shield_atlas.clone(), index: 15 }),
+                    EnemySprit { index: 0, frames: 9, directional: true },
+                ));
+            });
+        }
+    }
+
+    Some(enemy.id())
+}
 
+/// Oyuncuyu kovalayan düşmanlar - charger ve boss kendi sistemlerinde hareket eder
+type Followers = (Without<Player>, Without<Charger>, Without<Boss>);
 
 pub fn follow(
     player_query: Query<&Transform, With<Player>>,
-    mut enemy_query: Query<(&mut Transform, &Enemy, &Children, &mut AABB), (With<Enemy>, Without<Player>)>,
+    mut enemy_query: Query<(&mut Transform, &mut Enemy, &mut AABB, Option<&RangedAttacker>, &StatusEffects), Followers>,
     time: Res<Time>,
     mut enemy_move_timer: ResMut<MoveTimer>,
-    mut enemy_sprit_query: Query<(&mut Sprite, &mut EnemySprit), With<EnemySprit>>,
-
 ) {
     let Ok(player_transform) = player_query.single() else {
         return;
@@ -102,36 +236,59 @@ pub fn follow(
     let player_position = player_transform.translation;
 
     enemy_move_timer.timer.tick(time.delta());
-    for (mut enemy_position, enemy, children, mut aabb) in enemy_query.iter_mut(){
+    for (mut enemy_position, mut enemy, mut aabb, ranged, effects) in enemy_query.iter_mut(){
         let diff: Vec3  = player_position - enemy_position.translation;
         if diff.length_squared() < 1e-6 {
             continue;
         }
         let direction = diff.normalize();
-        enemy_position.translation += direction * enemy.speed * time.delta_secs();
+        enemy.direction = direction;
+
+        // Menzilli düşmanlar tercih ettikleri mesafede durur, çok yakınsa geri çekilir
+        let step = match ranged {
+            Some(ranged) if diff.length() < ranged.preferred_distance * 0.8 => -direction,
+            Some(ranged) if diff.length() <= ranged.preferred_distance => Vec3::ZERO,
+            _ => direction,
+        };
+        enemy_position.translation += step * enemy.speed * effects.speed_multiplier() * time.delta_secs();
         aabb.change_point(enemy_position.translation);
+    }
+}
+
+/// Düşman sprite'larını son hareket yönüne göre canlandır
+pub fn animate_enemies(
+    enemy_query: Query<(&Enemy, &Children, &StatusEffects)>,
+    mut enemy_sprit_query: Query<(&mut Sprite, &mut EnemySprit), With<EnemySprit>>,
+    enemy_move_timer: Res<MoveTimer>,
+) {
+    if !enemy_move_timer.timer.just_finished() {
+        return;
+    }
 
-        if !enemy_move_timer.timer.just_finished() {
+    for (enemy, children, effects) in enemy_query.iter() {
+        // Donmuş düşmanlar kıpırdamaz
+        if effects.is_frozen() {
             continue;
         }
-
+        let direction = enemy.direction;
         for &child in children.iter() {
             if let Ok((mut sprite, mut enemy_sprit)) = enemy_sprit_query.get_mut(child) {
-                let i = (enemy_sprit.index + 1) % 9;
+                let i = (enemy_sprit.index + 1) % enemy_sprit.frames;
                 enemy_sprit.index = i;
 
-                let atlas_index = if direction.x.abs() > direction.y.abs() {
+                let atlas_index = if !enemy_sprit.directional {
+                    sprite.flip_x = direction.x < 0.0;
+                    i
+                } else if direction.x.abs() > direction.y.abs() {
                     if direction.x > 0.0 {
                         27 + i
                     } else {
                         9 + i
                     }
+                } else if direction.y > 0.0 {
+                    i
                 } else {
-                    if direction.y > 0.0 {
-                        0 + i
-                    } else {
-                        18 + i
-                    }
+                    18 + i
                 };
 
                 if let Some(ref mut atlas) = sprite.texture_atlas {
@@ -141,70 +298,20 @@ pub fn follow(
         }
     }
 }
-pub fn spawn_enemies(
-    mut commands: Commands,
-    time: Res<Time>,
-    mut spawn_timer: ResMut<EnemySpawnTimer>,
-    player_query: Query<&Transform, With<Player>>,
-    atlases: Res<Atlases>,
-    textures: Res<TextureAssets>,
-    mut enemy_power: ResMut<EnemyPowerUpTimer>
-) {
-    enemy_power.timer.tick(time.delta());
-    if enemy_power.timer.just_finished() {
-        enemy_power.level += 1;
-    }
-    let level = enemy_power.level;
-    
-    spawn_timer.timer.tick(time.delta());
-    if !spawn_timer.timer.just_finished() { return; }
-    if !atlases.ready { return; }
-
-    // Query'den güvenli bir şekilde al
-    let Ok(player_transform) = player_query.single() else {
-        return;
-    };
-
-    let radius = rand::rng().random_range(500.0..800.0);
-    let angle = rand::rng().random_range(0.0..2. * PI);
-    let x = player_transform.translation.x + radius * angle.cos();
-    let y = player_transform.translation.y + radius * angle.sin();
-
-    let body_atlas = atlases.body.as_ref().unwrap().clone();
-    let shield_atlas = atlases.shield.as_ref().unwrap().clone();
-
-    commands
-        .spawn((
-            GameEntity,
-            Transform::from_xyz(x, y, 0.0),
-            Enemy { health: 100 * level, damage: 1 * level, speed: rand::rng().random_range((100.0 * level as f32) ..200.0 * level as f32) },
-            InheritedVisibility::default(),
-            AABB { max_x: x + 25., max_y: y + 25., min_x: x - 25., min_y: y - 25., width: 50., height: 50. },
-        ))
-        .with_children(|parent| {
-            parent.spawn((
-                Sprite::from_atlas_image(textures.body.clone(), TextureAtlas { layout: body_atlas.clone(), index: 15 }),
-                EnemySprit { index: 0 },
-            ));
-            parent.spawn((
-                Sprite::from_atlas_image(textures.shield.clone(), TextureAtlas { layout: shield_atlas.clone(), index: 15 }),
-                EnemySprit { index: 0 },
-            ));
-        });
-}
 
 pub fn enemy_collision_with_enemy(
-    mut enemy_query: Query<(&mut Transform, &AABB), With<Enemy>>,
+    mut enemy_query: Query<&mut Transform, With<Enemy>>,
+    grid: Res<SpatialGrid>,
 ){
-    let mut combinations = enemy_query.iter_combinations_mut();
-
-    while let Some([(mut transform1, aabb1), (mut transform2, aabb2)]) = combinations.fetch_next() {
-        if aabb1.self_aabb_intersects(aabb2) {
-            let direction = (transform1.translation - transform2.translation).normalize();
-            let push_strength = 2.0;
+    let push_strength = 2.0;
+    for (a, b) in grid.overlapping_pairs(SpatialLayer::Enemy) {
+        let direction = (a.center() - b.center()).normalize_or_zero().extend(0.0);
 
-            transform1.translation += direction * push_strength;
-            transform2.translation -= direction * push_strength;
+        if let Ok(mut transform) = enemy_query.get_mut(a.entity) {
+            transform.translation += direction * push_strength;
+        }
+        if let Ok(mut transform) = enemy_query.get_mut(b.entity) {
+            transform.translation -= direction * push_strength;
         }
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,168 @@
+use std::f32::consts::TAU;
+use bevy::prelude::*;
+use crate::plugins::aabb::AABB;
+use crate::plugins::enemy::{resting_tint, spawn_enemy, tint_enemy_sprites, Enemy, EnemyDiedEvent, EnemySpawner, EnemySprit};
+use crate::plugins::enemy_kind::{Charger, ChargerState, EnemyBehaviour, RangedAttacker};
+use crate::plugins::player::{Player, PlayerMotion};
+use crate::plugins::player_damage::{PlayerDamageEvent, PlayerDeath};
+use crate::plugins::spawn_director::SpawnDirector;
+use crate::plugins::status_effect::StatusEffects;
+use crate::plugins::weapons::GameEntity;
+
+/// Menzilli düşmanların attığı mermi
+#[derive(Component)]
+pub struct EnemyProjectile {
+    pub direction: Vec3,
+    pub speed: f32,
+    pub damage: i32,
+    pub lifetime: Timer,
+}
+
+/// Charger durum makinesi: yaklaş -> titre (kırmızı) -> dash -> toparlan
+pub fn charger_behaviour(
+    time: Res<Time>,
+    player_query: Query<&Transform, (With<Player>, Without<Enemy>)>,
+    mut chargers: Query<(&mut Transform, &mut Enemy, &mut AABB, &mut Charger, &Children, &StatusEffects)>,
+    mut sprites: Query<&mut Sprite, With<EnemySprit>>,
+) {
+    let Ok(player_transform) = player_query.single() else {
+        return;
+    };
+    let player_position = player_transform.translation;
+
+    for (mut transform, mut enemy, mut aabb, mut charger, children, effects) in chargers.iter_mut() {
+        // Donmuşken durum makinesi de bekler
+        if effects.is_frozen() {
+            continue;
+        }
+        let to_player = (player_position - transform.translation).normalize_or_zero();
+        let slow = effects.speed_multiplier();
+        charger.timer.tick(time.delta());
+
+        match charger.state {
+            ChargerState::Approaching | ChargerState::Recovering => {
+                // Toparlanırken yarı hızda yürür
+                let speed = if charger.state == ChargerState::Recovering { enemy.speed * 0.5 } else { enemy.speed };
+                transform.translation += to_player * speed * slow * time.delta_secs();
+                enemy.direction = to_player;
+
+                if charger.state == ChargerState::Recovering && charger.timer.just_finished() {
+                    charger.state = ChargerState::Approaching;
+                } else if charger.state == ChargerState::Approaching
+                    && transform.translation.distance(player_position) <= charger.trigger_range
+                {
+                    charger.state = ChargerState::Telegraphing;
+                    charger.timer = Timer::from_seconds(charger.windup, TimerMode::Once);
+                    tint_enemy_sprites(children, &mut sprites, resting_tint(effects, Some(&*charger)));
+                }
+            }
+            ChargerState::Telegraphing => {
+                enemy.direction = to_player;
+                if charger.timer.just_finished() {
+                    charger.state = ChargerState::Dashing;
+                    charger.dash_direction = to_player;
+                    charger.timer = Timer::from_seconds(charger.dash_duration, TimerMode::Once);
+                }
+            }
+            ChargerState::Dashing => {
+                transform.translation += charger.dash_direction * charger.dash_speed * slow * time.delta_secs();
+                if charger.timer.just_finished() {
+                    charger.state = ChargerState::Recovering;
+                    charger.timer = Timer::from_seconds(charger.cooldown, TimerMode::Once);
+                    tint_enemy_sprites(children, &mut sprites, resting_tint(effects, Some(&*charger)));
+                }
+            }
+        }
+        aabb.change_point(transform.translation);
+    }
+}
+
+/// Menzilli düşmanlar menzildeyken oyuncuya mermi atar
+pub fn ranged_enemy_fire(
+    mut commands: Commands,
+    time: Res<Time>,
+    player_query: Query<&Transform, (With<Player>, Without<Enemy>)>,
+    mut shooters: Query<(&Transform, &mut RangedAttacker), With<Enemy>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    let Ok(player_transform) = player_query.single() else {
+        return;
+    };
+
+    for (transform, mut ranged) in shooters.iter_mut() {
+        ranged.fire_timer.tick(time.delta());
+        if !ranged.fire_timer.just_finished() {
+            continue;
+        }
+        let diff = player_transform.translation - transform.translation;
+        if diff.length() > ranged.preferred_distance * 1.2 {
+            continue;
+        }
+
+        commands.spawn((
+            GameEntity,
+            EnemyProjectile {
+                direction: diff.normalize_or_zero(),
+                speed: ranged.projectile_speed,
+                damage: ranged.projectile_damage,
+                lifetime: Timer::from_seconds(4.0, TimerMode::Once),
+            },
+            Mesh2d(meshes.add(Circle::new(6.0))),
+            MeshMaterial2d(materials.add(ColorMaterial::from(Color::srgb(0.6, 0.1, 0.8)))),
+            Transform::from_translation(transform.translation + Vec3::new(0.0, 0.0, 10.0)),
+        ));
+    }
+}
+
+/// Düşman mermilerini hareket ettir, oyuncuya çarpanlara hasar ver
+pub fn move_enemy_projectiles(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut projectiles: Query<(Entity, &mut Transform, &mut EnemyProjectile)>,
+    player_query: Query<(&AABB, &PlayerMotion), Without<PlayerDeath>>,
+    mut damage_events: MessageWriter<PlayerDamageEvent>,
+) {
+    let Ok((player_aabb, motion)) = player_query.single() else {
+        return;
+    };
+
+    for (entity, mut transform, mut projectile) in projectiles.iter_mut() {
+        transform.translation += projectile.direction * projectile.speed * time.delta_secs();
+
+        projectile.lifetime.tick(time.delta());
+        if projectile.lifetime.just_finished() {
+            commands.entity(entity).try_despawn();
+            continue;
+        }
+
+        // Dokunulmaz oyuncunun içinden geçer
+        if !motion.is_invulnerable() && player_aabb.contains_point(transform.translation) {
+            damage_events.write(PlayerDamageEvent {
+                amount: projectile.damage as u32,
+                source: Some(transform.translation.truncate()),
+                over_time: false,
+            });
+            commands.entity(entity).try_despawn();
+        }
+    }
+}
+
+/// Splitter'lar öldüğü yerde çocuklarını halka şeklinde spawn eder
+pub fn split_enemies_on_death(
+    mut commands: Commands,
+    mut died_events: MessageReader<EnemyDiedEvent>,
+    mut spawner: EnemySpawner,
+    director: Res<SpawnDirector>,
+) {
+    for event in died_events.read() {
+        let EnemyBehaviour::Splitter { child, count } = event.kind.profile().behaviour else {
+            continue;
+        };
+        for i in 0..count {
+            let angle = TAU * i as f32 / count as f32;
+            let offset = Vec3::new(angle.cos(), angle.sin(), 0.0) * 25.0;
+            spawn_enemy(&mut commands, &mut spawner, child, event.position + offset, director.level);
+        }
+    }
+}
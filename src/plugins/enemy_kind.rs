@@ -0,0 +1,287 @@
+use std::collections::HashMap;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use crate::plugins::boss::BossKind;
+use crate::plugins::damage::DamageType;
+
+/// Düşman arketipleri - her biri kendi sprite'ı, statları ve davranışıyla
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Debug, Serialize, Deserialize)]
+pub enum EnemyKind {
+    /// Eski iskelet + kalkan düşmanı
+    Skeleton,
+    /// Hızlı, zayıf, kalabalık gelen tilkiler
+    Swarmer,
+    /// Yavaş, dayanıklı, büyük slime
+    Tank,
+    /// Mesafede durup oyuncuya mermi atan kobold
+    Ranged,
+    /// Önce titreyip sonra oyuncuya atılan solucan
+    Charger,
+    /// Ölünce küçük slime'lara bölünen slime
+    Splitter,
+    /// Splitter'dan çıkan küçük slime
+    Splitling,
+    /// Fazlı saldırıları olan boss, bkz. `boss.rs`
+    Boss(BossKind),
+}
+
+/// Arketipin hareket / saldırı davranışı
+#[derive(Clone, Copy, Debug)]
+pub enum EnemyBehaviour {
+    /// Oyuncuya doğru düz yürü
+    Chase,
+    /// `preferred_distance` mesafede dur ve ateş et
+    Ranged {
+        preferred_distance: f32,
+        fire_interval: f32,
+        projectile_speed: f32,
+        projectile_damage: i32,
+    },
+    /// Yaklaş, `windup` süresince titre, sonra dash at
+    Charger {
+        trigger_range: f32,
+        windup: f32,
+        dash_speed: f32,
+        dash_duration: f32,
+        cooldown: f32,
+    },
+    /// Öldüğünde `count` tane `child` spawn et
+    Splitter { child: EnemyKind, count: u32 },
+    /// `Boss` component'i ile fazlı saldırılar
+    Boss(BossKind),
+}
+
+/// Sprite sheet bilgisi - tek satırlık animasyon şeritleri için
+#[derive(Clone, Copy, Debug)]
+pub struct EnemySpriteSheet {
+    pub path: &'static str,
+    pub frame_size: UVec2,
+    pub frames: u32,
+    pub scale: f32,
+}
+
+/// Bir arketipin seviye 1'deki statları
+#[derive(Clone, Copy, Debug)]
+pub struct EnemyProfile {
+    /// `None` ise iskelet gövde + kalkan atlasları kullanılır
+    pub sprite: Option<EnemySpriteSheet>,
+    pub health: i32,
+    pub damage: i32,
+    pub speed: (f32, f32),
+    pub size: f32,
+    pub xp: i32,
+    pub behaviour: EnemyBehaviour,
+    /// Her isabetten düşülen sabit hasar (en az 1 hasar her zaman geçer)
+    pub armor: f32,
+    /// Hasar tipi çarpanları - listede olmayan tip 1.0
+    pub resistances: &'static [(DamageType, f32)],
+    /// 1.0 tamamen itilemez
+    pub knockback_resistance: f32,
+}
+
+impl EnemyProfile {
+    pub fn resistance(&self, damage_type: DamageType) -> f32 {
+        self.resistances
+            .iter()
+            .find(|(kind, _)| *kind == damage_type)
+            .map_or(1.0, |(_, multiplier)| *multiplier)
+    }
+}
+
+impl EnemyKind {
+    pub const ALL: [EnemyKind; 9] = [
+        EnemyKind::Skeleton,
+        EnemyKind::Swarmer,
+        EnemyKind::Tank,
+        EnemyKind::Ranged,
+        EnemyKind::Charger,
+        EnemyKind::Splitter,
+        EnemyKind::Splitling,
+        EnemyKind::Boss(BossKind::QueenBee),
+        EnemyKind::Boss(BossKind::KingSlime),
+    ];
+
+    pub fn profile(&self) -> EnemyProfile {
+        match self {
+            EnemyKind::Skeleton => EnemyProfile {
+                sprite: None,
+                health: 100,
+                damage: 1,
+                speed: (100.0, 200.0),
+                size: 50.0,
+                xp: 20,
+                armor: 0.0,
+                resistances: &[(DamageType::Poison, 0.5)],
+                knockback_resistance: 0.0,
+                behaviour: EnemyBehaviour::Chase,
+            },
+            EnemyKind::Swarmer => EnemyProfile {
+                sprite: Some(EnemySpriteSheet {
+                    path: "textures/rpg/mobs/fox-run.png",
+                    frame_size: UVec2::new(24, 24),
+                    frames: 6,
+                    scale: 2.0,
+                }),
+                health: 30,
+                damage: 1,
+                speed: (220.0, 280.0),
+                size: 30.0,
+                xp: 8,
+                armor: 0.0,
+                resistances: &[],
+                knockback_resistance: 0.0,
+                behaviour: EnemyBehaviour::Chase,
+            },
+            EnemyKind::Tank => EnemyProfile {
+                sprite: Some(EnemySpriteSheet {
+                    path: "textures/rpg/mobs/slime-orange.png",
+                    frame_size: UVec2::new(16, 24),
+                    frames: 4,
+                    scale: 5.0,
+                }),
+                health: 600,
+                damage: 3,
+                speed: (50.0, 70.0),
+                size: 70.0,
+                xp: 80,
+                armor: 5.0,
+                resistances: &[(DamageType::Physical, 0.75)],
+                knockback_resistance: 0.6,
+                behaviour: EnemyBehaviour::Chase,
+            },
+            EnemyKind::Ranged => EnemyProfile {
+                sprite: Some(EnemySpriteSheet {
+                    path: "textures/rpg/mobs/kobold-idle.png",
+                    frame_size: UVec2::new(24, 24),
+                    frames: 15,
+                    scale: 2.5,
+                }),
+                health: 80,
+                damage: 1,
+                speed: (90.0, 120.0),
+                size: 40.0,
+                xp: 30,
+                armor: 0.0,
+                resistances: &[],
+                knockback_resistance: 0.0,
+                behaviour: EnemyBehaviour::Ranged {
+                    preferred_distance: 300.0,
+                    fire_interval: 2.0,
+                    projectile_speed: 250.0,
+                    projectile_damage: 1,
+                },
+            },
+            EnemyKind::Charger => EnemyProfile {
+                sprite: Some(EnemySpriteSheet {
+                    path: "textures/rpg/mobs/worm-run-idle.png",
+                    frame_size: UVec2::new(16, 24),
+                    frames: 31,
+                    scale: 3.0,
+                }),
+                health: 150,
+                damage: 2,
+                speed: (80.0, 110.0),
+                size: 45.0,
+                xp: 35,
+                armor: 2.0,
+                resistances: &[],
+                knockback_resistance: 0.3,
+                behaviour: EnemyBehaviour::Charger {
+                    trigger_range: 350.0,
+                    windup: 0.8,
+                    dash_speed: 700.0,
+                    dash_duration: 0.5,
+                    cooldown: 1.5,
+                },
+            },
+            EnemyKind::Splitter => EnemyProfile {
+                sprite: Some(EnemySpriteSheet {
+                    path: "textures/rpg/mobs/slime-blue.png",
+                    frame_size: UVec2::new(16, 24),
+                    frames: 4,
+                    scale: 3.5,
+                }),
+                health: 200,
+                damage: 1,
+                speed: (90.0, 120.0),
+                size: 50.0,
+                xp: 25,
+                armor: 0.0,
+                resistances: &[(DamageType::Poison, 0.5)],
+                knockback_resistance: 0.2,
+                behaviour: EnemyBehaviour::Splitter { child: EnemyKind::Splitling, count: 3 },
+            },
+            EnemyKind::Splitling => EnemyProfile {
+                sprite: Some(EnemySpriteSheet {
+                    path: "textures/rpg/mobs/slime-green.png",
+                    frame_size: UVec2::new(16, 24),
+                    frames: 4,
+                    scale: 2.0,
+                }),
+                health: 40,
+                damage: 1,
+                speed: (150.0, 190.0),
+                size: 28.0,
+                xp: 5,
+                armor: 0.0,
+                resistances: &[(DamageType::Poison, 0.5)],
+                knockback_resistance: 0.0,
+                behaviour: EnemyBehaviour::Chase,
+            },
+            EnemyKind::Boss(boss) => boss.profile(),
+        }
+    }
+}
+
+/// Mesafeden ateş eden düşmanlar
+#[derive(Component, Clone, Serialize, Deserialize)]
+pub struct RangedAttacker {
+    pub preferred_distance: f32,
+    pub fire_timer: Timer,
+    pub projectile_speed: f32,
+    pub projectile_damage: i32,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub enum ChargerState {
+    Approaching,
+    Telegraphing,
+    Dashing,
+    Recovering,
+}
+
+/// Oyuncuya atılan düşmanların durum makinesi
+#[derive(Component, Clone, Serialize, Deserialize)]
+pub struct Charger {
+    pub state: ChargerState,
+    pub timer: Timer,
+    pub dash_direction: Vec3,
+    pub trigger_range: f32,
+    pub windup: f32,
+    pub dash_speed: f32,
+    pub dash_duration: f32,
+    pub cooldown: f32,
+}
+
+/// Sprite sheet'li arketiplerin texture ve atlas handle'ları
+#[derive(Resource)]
+pub struct EnemySprites {
+    pub sheets: HashMap<EnemyKind, (Handle<Image>, Handle<TextureAtlasLayout>)>,
+}
+
+impl FromWorld for EnemySprites {
+    fn from_world(world: &mut World) -> Self {
+        let mut sheets = HashMap::new();
+        for kind in EnemyKind::ALL {
+            let Some(sheet) = kind.profile().sprite else {
+                continue;
+            };
+            let image = world.resource::<AssetServer>().load(sheet.path);
+            let layout = world
+                .resource_mut::<Assets<TextureAtlasLayout>>()
+                .add(TextureAtlasLayout::from_grid(sheet.frame_size, sheet.frames, 1, None, None));
+            sheets.insert(kind, (image, layout));
+        }
+        Self { sheets }
+    }
+}
@@ -1,11 +1,28 @@
-use bevy::prelude::States;
+use bevy::prelude::{NextState, Res, State, States, SystemSet};
 
 #[derive(States, Debug, Clone, Copy, Eq, PartialEq, Hash, Default)]
 pub enum GameState {
     #[default]
     MainMenu,
+    /// Ana menüde Play ile yükleme arası
+    CharacterSelect,
     Loading,
     Playing,
     GameOver,
     UpgradeSelection,
+    /// Escape ile açılan duraklatma menüsü - oyun mantığı ve sanal zaman durur
+    Paused,
+    /// Açılan sandığın ödül ekranı - oyun mantığı durur
+    ChestReward,
+}
+
+/// Oyun mantığı sistemleri - `FixedUpdate`'te, sadece `gameplay_tick` doğruyken çalışır
+#[derive(SystemSet, Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct GameplaySet;
+
+/// Playing'deyiz ve bekleyen bir state geçişi yok.
+/// Geçiş frame sonunda uygulandığı için, aynı frame'in kalan tick'leri atlanır;
+/// böylece tick sayısı frame hızından bağımsız kalır (replay'ler için şart)
+pub fn gameplay_tick(state: Res<State<GameState>>, next_state: Res<NextState<GameState>>) -> bool {
+    *state.get() == GameState::Playing && matches!(*next_state, NextState::Unchanged)
 }
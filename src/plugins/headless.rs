@@ -0,0 +1,38 @@
+use std::time::Duration;
+use bevy::app::ScheduleRunnerPlugin;
+use bevy::audio::{AudioLoader, AudioSource};
+use bevy::image::{CompressedImageFormats, ImageLoader, ImagePlugin};
+use bevy::input::InputPlugin;
+use bevy::prelude::*;
+use bevy::state::app::StatesPlugin;
+use bevy::text::FontLoader;
+use bevy::time::TimeUpdateStrategy;
+
+/// Pencere, render ve ses olmadan oyunu çalıştırmak için gereken en az plugin seti.
+/// Oyun sistemlerinin dokunduğu asset tipleri kaydedilir ama hiçbir şey çizilmez/çalınmaz.
+/// Her `update` tam bir sabit tick ilerler, yani simülasyon gerçek zamandan bağımsız ve olabildiğince hızlı koşar.
+pub struct HeadlessPlugin;
+
+impl Plugin for HeadlessPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins((
+            MinimalPlugins.set(ScheduleRunnerPlugin::run_loop(Duration::ZERO)),
+            AssetPlugin::default(),
+            ImagePlugin::default(),
+            InputPlugin,
+            StatesPlugin,
+        ))
+        // Render cihazı olmadığından sıkıştırılmış formatlar yok; png'ler yine de CPU'da çözülür
+        .register_asset_loader(ImageLoader::new(CompressedImageFormats::NONE))
+        .init_asset::<TextureAtlasLayout>()
+        .init_asset::<Mesh>()
+        .init_asset::<ColorMaterial>()
+        .init_asset::<Font>()
+        .init_asset_loader::<FontLoader>()
+        .init_asset::<AudioSource>()
+        .init_asset_loader::<AudioLoader>();
+
+        let timestep = app.world().resource::<Time<Fixed>>().timestep();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(timestep));
+    }
+}
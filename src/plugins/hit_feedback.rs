@@ -0,0 +1,228 @@
+use std::collections::VecDeque;
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use crate::plugins::enemy::{resting_tint, tint_enemy_sprites, Enemy, EnemySprit};
+use crate::plugins::enemy_kind::Charger;
+use crate::plugins::game_state::GameState;
+use crate::plugins::loot::{Pickup, PickupCollectedEvent};
+use crate::plugins::player::{Player, PlayerHealedEvent};
+use crate::plugins::player_damage::PlayerHurtEvent;
+use crate::plugins::settings::Settings;
+use crate::plugins::status_effect::StatusEffects;
+use crate::plugins::weapons::WeaponHitEvent;
+
+/// Aynı anda ekranda olabilecek en fazla hasar sayısı - dolunca en eskisi yeniden kullanılır
+pub const MAX_DAMAGE_NUMBERS: usize = 64;
+pub const DAMAGE_NUMBER_LIFETIME: f32 = 0.7;
+const DAMAGE_NUMBER_RISE_SPEED: f32 = 60.0;
+const DAMAGE_NUMBER_Z: f32 = 20.0;
+pub const HIT_FLASH_SECS: f32 = 0.08;
+/// Sprite rengi dokuyla çarpıldığı için 1'in üstü sprite'ı beyaza yaklaştırır
+pub const HIT_FLASH_COLOR: Color = Color::linear_rgb(4.0, 4.0, 4.0);
+
+const NORMAL_COLOR: Color = Color::srgb(1.0, 1.0, 1.0);
+const CRIT_COLOR: Color = Color::srgb(1.0, 0.35, 0.1);
+const OVER_TIME_COLOR: Color = Color::srgb(0.75, 0.55, 1.0);
+const HEAL_COLOR: Color = Color::srgb(0.3, 1.0, 0.4);
+const HURT_COLOR: Color = Color::srgb(1.0, 0.2, 0.2);
+const GOLD_COLOR: Color = Color::srgb(1.0, 0.85, 0.2);
+
+/// Hasar sayıları ve isabet parlaması
+pub struct HitFeedbackPlugin;
+
+impl Plugin for HitFeedbackPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DamageNumberPool>()
+            .add_systems(
+                Update,
+                (
+                    (spawn_damage_numbers, animate_damage_numbers).chain(),
+                    (flash_enemies_on_hit, fade_hit_flash).chain(),
+                ).run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(OnEnter(GameState::Loading), hide_damage_numbers)
+            .add_systems(OnEnter(GameState::GameOver), hide_damage_numbers)
+            .add_systems(OnEnter(GameState::MainMenu), hide_damage_numbers);
+    }
+}
+
+/// Gizli bekleyen ve ekranda olan hasar sayısı entity'leri
+#[derive(Resource, Default, Debug)]
+pub struct DamageNumberPool {
+    pub free: Vec<Entity>,
+    pub active: VecDeque<Entity>,
+    /// Üst üste binmesin diye sayıları sırayla yana kaydırmak için
+    shown: u32,
+}
+
+#[derive(Component)]
+pub struct DamageNumber {
+    timer: Timer,
+    color: Color,
+}
+
+/// Düşman isabet sonrası kısa süre beyaz parlar. Her düşmanda hep vardır; eklenip
+/// silinseydi düşmanların archetype'ı ve sorgu sırası frame hızına bağlı değişirdi.
+#[derive(Component, Default)]
+pub struct HitFlash {
+    pub remaining: f32,
+}
+
+/// Bir sayının görünümü: renk ve yazı boyutu
+fn number_style(crit: bool, over_time: bool) -> (Color, f32) {
+    if crit {
+        (CRIT_COLOR, 30.0)
+    } else if over_time {
+        (OVER_TIME_COLOR, 16.0)
+    } else {
+        (NORMAL_COLOR, 20.0)
+    }
+}
+
+/// Oyuncunun üstünde çıkan sayılar: iyileşme, alınan hasar ve toplanan altın
+#[derive(SystemParam)]
+struct PlayerNumbers<'w, 's> {
+    heals: MessageReader<'w, 's, PlayerHealedEvent>,
+    hurts: MessageReader<'w, 's, PlayerHurtEvent>,
+    pickups: MessageReader<'w, 's, PickupCollectedEvent>,
+    player: Query<'w, 's, &'static Transform, (With<Player>, Without<DamageNumber>)>,
+}
+
+fn spawn_damage_numbers(
+    mut commands: Commands,
+    mut hits: MessageReader<WeaponHitEvent>,
+    mut player_numbers: PlayerNumbers,
+    mut pool: ResMut<DamageNumberPool>,
+    mut numbers: Query<(&mut DamageNumber, &mut Text2d, &mut TextFont, &mut TextColor, &mut Transform, &mut Visibility)>,
+    settings: Res<Settings>,
+) {
+    if !settings.damage_numbers {
+        hits.clear();
+        player_numbers.heals.clear();
+        player_numbers.hurts.clear();
+        player_numbers.pickups.clear();
+        return;
+    }
+    let player_position = player_numbers.player.single().map(|transform| transform.translation).ok();
+    let hits = hits
+        .read()
+        .filter(|hit| hit.damage > 0)
+        .map(|hit| (hit.position, hit.damage.to_string(), number_style(hit.crit, hit.over_time)));
+    let heals = player_numbers
+        .heals
+        .read()
+        .filter_map(|heal| player_position.map(|position| (position, format!("+{}", heal.amount), (HEAL_COLOR, 20.0))));
+
+    let hurts = player_numbers
+        .hurts
+        .read()
+        .filter(|hurt| hurt.amount > 0)
+        .map(|hurt| (hurt.position, format!("-{}", hurt.amount), (HURT_COLOR, if hurt.over_time { 16.0 } else { 22.0 })));
+
+    let gold = player_numbers.pickups.read().filter_map(|pickup| match pickup.pickup {
+        Pickup::Gold(amount) => Some((pickup.position, format!("+{}", amount), (GOLD_COLOR, 18.0))),
+        _ => None,
+    });
+
+    for (position, label, (color, size)) in hits.chain(heals).chain(hurts).chain(gold).collect::<Vec<_>>() {
+        let offset = Vec3::new((pool.shown % 5) as f32 * 8.0 - 16.0, 20.0, 0.0);
+        let translation = (position + offset).with_z(DAMAGE_NUMBER_Z);
+        pool.shown = pool.shown.wrapping_add(1);
+
+        // Boşta olanı, yoksa yenisini, sınıra gelindiyse en eskisini kullan
+        let reused = match pool.free.pop() {
+            Some(entity) => Some(entity),
+            None if pool.active.len() >= MAX_DAMAGE_NUMBERS => pool.active.pop_front(),
+            None => None,
+        };
+        let timer = Timer::from_seconds(DAMAGE_NUMBER_LIFETIME, TimerMode::Once);
+        if let Some(entity) = reused {
+            pool.active.push_back(entity);
+            // Bu frame'de spawn edilen entity henüz sorgulanamaz; o zaman bu sayı atlanır
+            if let Ok((mut number, mut text, mut font, mut text_color, mut transform, mut visibility)) = numbers.get_mut(entity) {
+                *number = DamageNumber { timer, color };
+                text.0 = label;
+                font.font_size = size;
+                text_color.0 = color;
+                transform.translation = translation;
+                *visibility = Visibility::Visible;
+            }
+            continue;
+        }
+        let entity = commands
+            .spawn((
+                DamageNumber { timer, color },
+                Text2d::new(label),
+                TextFont { font_size: size, ..default() },
+                TextColor(color),
+                Transform::from_translation(translation),
+                Visibility::Visible,
+            ))
+            .id();
+        pool.active.push_back(entity);
+    }
+}
+
+/// Sayıları yukarı kaydır ve soldur; süresi bitenleri gizleyip havuza geri koy
+fn animate_damage_numbers(
+    time: Res<Time>,
+    mut pool: ResMut<DamageNumberPool>,
+    mut numbers: Query<(Entity, &mut DamageNumber, &mut TextColor, &mut Transform, &mut Visibility)>,
+) {
+    for (entity, mut number, mut text_color, mut transform, mut visibility) in numbers.iter_mut() {
+        if *visibility == Visibility::Hidden {
+            continue;
+        }
+        number.timer.tick(time.delta());
+        transform.translation.y += DAMAGE_NUMBER_RISE_SPEED * time.delta_secs();
+        text_color.0 = number.color.with_alpha(1.0 - number.timer.fraction());
+        if number.timer.is_finished() {
+            *visibility = Visibility::Hidden;
+            pool.active.retain(|active| *active != entity);
+            pool.free.push(entity);
+        }
+    }
+}
+
+/// Koşu bittiğinde ekranda kalan sayıları gizle
+fn hide_damage_numbers(mut pool: ResMut<DamageNumberPool>, mut numbers: Query<&mut Visibility, With<DamageNumber>>) {
+    while let Some(entity) = pool.active.pop_front() {
+        if let Ok(mut visibility) = numbers.get_mut(entity) {
+            *visibility = Visibility::Hidden;
+        }
+        pool.free.push(entity);
+    }
+}
+
+/// Doğrudan isabet alan düşmanın sprite'larını beyaza boya (süreli hasarlar parlatmaz)
+fn flash_enemies_on_hit(
+    mut hits: MessageReader<WeaponHitEvent>,
+    mut enemies: Query<(&mut HitFlash, &Children), With<Enemy>>,
+    mut sprites: Query<&mut Sprite, With<EnemySprit>>,
+) {
+    for hit in hits.read().filter(|hit| !hit.over_time) {
+        let Ok((mut flash, children)) = enemies.get_mut(hit.target) else {
+            continue;
+        };
+        flash.remaining = HIT_FLASH_SECS;
+        tint_enemy_sprites(children, &mut sprites, HIT_FLASH_COLOR);
+    }
+}
+
+/// Parlama bitince sprite'lara charge uyarısını, durum etkisinin rengini ya da beyazı geri ver
+fn fade_hit_flash(
+    time: Res<Time>,
+    mut enemies: Query<(&mut HitFlash, &StatusEffects, Option<&Charger>, &Children)>,
+    mut sprites: Query<&mut Sprite, With<EnemySprit>>,
+) {
+    for (mut flash, effects, charger, children) in enemies.iter_mut() {
+        if flash.remaining <= 0.0 {
+            continue;
+        }
+        flash.remaining -= time.delta_secs();
+        if flash.remaining > 0.0 {
+            continue;
+        }
+        tint_enemy_sprites(children, &mut sprites, resting_tint(effects, charger));
+    }
+}
@@ -0,0 +1,326 @@
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use bevy::sprite::Anchor;
+use crate::plugins::game_state::GameState;
+use crate::plugins::localization::UiText;
+use crate::plugins::passive_item::PassiveInventory;
+use crate::plugins::player::Player;
+use crate::plugins::settings::Settings;
+use crate::plugins::spawn_director::SpawnDirector;
+use crate::plugins::weapon_definition::{WeaponDefinition, WeaponDefinitions};
+use crate::plugins::weapon_upgrade::WeaponLevel;
+use crate::plugins::weapons::{GameEntity, Weapon};
+
+const HUD_FONT: &str = "fonts/FiraMono-Medium.ttf";
+pub const XP_BAR_HEIGHT: f32 = 22.0;
+pub const HEALTH_BAR_WIDTH: f32 = 48.0;
+const HEALTH_BAR_HEIGHT: f32 = 6.0;
+/// Oyuncu sprite'ının altında
+const HEALTH_BAR_OFFSET: f32 = -36.0;
+const SLOT_SIZE: f32 = 44.0;
+const PASSIVE_SLOT_COLOR: Color = Color::srgb(0.3, 0.3, 0.45);
+
+/// XP/seviye çubuğu, süre, öldürme ve altın sayaçları, silah/pasif slotları ve oyuncunun can çubuğu.
+/// Hepsi sadece ilgili veri değişince güncellenir.
+pub struct HudPlugin;
+
+impl Plugin for HudPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                spawn_hud,
+                (update_player_hud, update_health_bar, update_timer, update_slots),
+            ).chain().run_if(in_state(GameState::Playing).or(in_state(GameState::UpgradeSelection))),
+        );
+    }
+}
+
+#[derive(Component)]
+pub struct Hud;
+
+#[derive(Component)]
+pub struct XpFill;
+
+/// Oyuncunun altındaki can çubuğunun dolu kısmı
+#[derive(Component)]
+pub struct HealthBarFill;
+
+#[derive(Component, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum HudText {
+    Level,
+    Timer,
+    Kills,
+    Gold,
+}
+
+#[derive(Component)]
+pub struct WeaponSlots;
+
+#[derive(Component)]
+pub struct PassiveSlots;
+
+/// Bir slot: ikon rengi, kısa ad ve seviye
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct HudSlot {
+    pub label: String,
+    pub level: i32,
+}
+
+/// Slot ikonları için silah tanımları ve font
+#[derive(SystemParam)]
+pub struct SlotAssets<'w> {
+    definitions: Res<'w, WeaponDefinitions>,
+    weapon_assets: Res<'w, Assets<WeaponDefinition>>,
+    asset_server: Res<'w, AssetServer>,
+}
+
+/// `mm:ss`
+pub fn format_survival_time(seconds: f32) -> String {
+    let total = seconds.max(0.0) as u32;
+    format!("{:02}:{:02}", total / 60, total % 60)
+}
+
+/// Koşu başında (oyuncu varken HUD yoksa) HUD'u ve oyuncunun can çubuğunu kur
+fn spawn_hud(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    player: Single<Entity, With<Player>>,
+    huds: Query<(), With<Hud>>,
+) {
+    if !huds.is_empty() {
+        return;
+    }
+    let font = asset_server.load(HUD_FONT);
+    let text = |size: f32| TextFont { font: font.clone(), font_size: size, ..default() };
+
+    commands.spawn((
+        GameEntity,
+        Hud,
+        Node {
+            position_type: PositionType::Absolute,
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            flex_direction: FlexDirection::Column,
+            ..default()
+        },
+    )).with_children(|parent| {
+        // Tam genişlik XP çubuğu, seviye sağda
+        parent.spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Px(XP_BAR_HEIGHT),
+                justify_content: JustifyContent::FlexEnd,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.05, 0.05, 0.15, 0.85)),
+        )).with_children(|bar| {
+            bar.spawn((
+                XpFill,
+                Node {
+                    position_type: PositionType::Absolute,
+                    left: Val::Px(0.0),
+                    width: Val::Percent(0.0),
+                    height: Val::Percent(100.0),
+                    ..default()
+                },
+                BackgroundColor(Color::srgb(0.2, 0.45, 1.0)),
+            ));
+            bar.spawn((
+                HudText::Level,
+                Text::default(),
+                text(16.0),
+                TextColor(Color::WHITE),
+                Node { margin: UiRect::right(Val::Px(8.0)), ..default() },
+            ));
+        });
+
+        // Süre ortada, sayaçlar sağda
+        parent.spawn(Node {
+            width: Val::Percent(100.0),
+            justify_content: JustifyContent::Center,
+            padding: UiRect::all(Val::Px(6.0)),
+            ..default()
+        }).with_children(|row| {
+            row.spawn((HudText::Timer, Text::new("00:00"), text(28.0), TextColor(Color::WHITE)));
+        });
+        parent.spawn(Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(XP_BAR_HEIGHT + 6.0),
+            right: Val::Px(10.0),
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::FlexEnd,
+            ..default()
+        }).with_children(|counters| {
+            counters.spawn((HudText::Kills, Text::default(), text(18.0), TextColor(Color::WHITE)));
+            counters.spawn((HudText::Gold, Text::default(), text(18.0), TextColor(Color::srgb(1.0, 0.85, 0.2))));
+        });
+
+        // Silah ve pasif slotları sol üstte
+        parent.spawn(Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(XP_BAR_HEIGHT + 6.0),
+            left: Val::Px(10.0),
+            flex_direction: FlexDirection::Column,
+            row_gap: Val::Px(4.0),
+            ..default()
+        }).with_children(|slots| {
+            slots.spawn((WeaponSlots, Node { column_gap: Val::Px(4.0), ..default() }));
+            slots.spawn((PassiveSlots, Node { column_gap: Val::Px(4.0), ..default() }));
+        });
+    });
+
+    // Can çubuğu oyuncunun çocuğu - onunla birlikte hareket eder ve silinir
+    commands.entity(*player).with_children(|parent| {
+        parent.spawn((
+            Sprite::from_color(Color::srgb(0.25, 0.0, 0.0), Vec2::new(HEALTH_BAR_WIDTH, HEALTH_BAR_HEIGHT)),
+            Transform::from_xyz(0.0, HEALTH_BAR_OFFSET, 1.0),
+        ));
+        parent.spawn((
+            HealthBarFill,
+            Sprite::from_color(Color::srgb(0.9, 0.1, 0.1), Vec2::new(HEALTH_BAR_WIDTH, HEALTH_BAR_HEIGHT)),
+            Anchor::CENTER_LEFT,
+            Transform::from_xyz(-HEALTH_BAR_WIDTH / 2.0, HEALTH_BAR_OFFSET, 2.0),
+        ));
+    });
+}
+
+fn set_text(text: &mut Text, value: String) {
+    if text.0 != value {
+        text.0 = value;
+    }
+}
+
+/// XP çubuğu, seviye, öldürme ve altın - sadece `Player` ya da dil değişince veya HUD yeni kurulunca
+fn update_player_hud(
+    player: Single<Ref<Player>>,
+    settings: Res<Settings>,
+    new_huds: Query<(), Added<Hud>>,
+    mut fills: Query<&mut Node, With<XpFill>>,
+    mut texts: Query<(&mut Text, &HudText)>,
+) {
+    if !player.is_changed() && !settings.is_changed() && new_huds.is_empty() {
+        return;
+    }
+    let label = |text: UiText| text.get(settings.language);
+    let fraction = (player.xp / player.xp_to_next_level).clamp(0.0, 1.0);
+    for mut node in fills.iter_mut() {
+        node.width = Val::Percent(fraction * 100.0);
+    }
+    for (mut text, kind) in texts.iter_mut() {
+        match kind {
+            HudText::Level => set_text(&mut text, format!("{} {}", label(UiText::LevelShort).to_uppercase(), player.level)),
+            HudText::Kills => set_text(&mut text, format!("{} {}", label(UiText::Kills), player.score)),
+            HudText::Gold => set_text(&mut text, format!("{} {}", label(UiText::Gold), player.gold)),
+            HudText::Timer => {}
+        }
+    }
+}
+
+fn update_health_bar(player: Single<Ref<Player>>, mut fills: Query<(&mut Sprite, Ref<HealthBarFill>)>) {
+    if !player.is_changed() && !fills.iter().any(|(_, fill)| fill.is_added()) {
+        return;
+    }
+    let fraction = (player.health as f32 / player.max_health.max(1) as f32).clamp(0.0, 1.0);
+    for (mut sprite, _) in fills.iter_mut() {
+        sprite.custom_size = Some(Vec2::new(HEALTH_BAR_WIDTH * fraction, HEALTH_BAR_HEIGHT));
+    }
+}
+
+/// Süre saniyede bir değişir; metin sadece o zaman yazılır
+fn update_timer(director: Res<SpawnDirector>, mut texts: Query<(&mut Text, &HudText)>) {
+    let label = format_survival_time(director.elapsed);
+    for (mut text, kind) in texts.iter_mut() {
+        if *kind == HudText::Timer {
+            set_text(&mut text, label.clone());
+        }
+    }
+}
+
+/// Silah seviyesi ya da pasif envanteri değişince slotları yeniden kur
+fn update_slots(
+    mut commands: Commands,
+    player: Single<(Entity, Ref<PassiveInventory>), With<Player>>,
+    weapons: Query<(Ref<WeaponLevel>, &Weapon)>,
+    mut removed: RemovedComponents<WeaponLevel>,
+    weapon_rows: Query<(Entity, Option<&Children>), With<WeaponSlots>>,
+    passive_rows: Query<(Entity, Option<&Children>), With<PassiveSlots>>,
+    assets: SlotAssets,
+) {
+    let (player, inventory) = player.into_inner();
+    let weapons_changed = removed.read().count() > 0 || weapons.iter().any(|(level, _)| level.is_changed());
+    // HUD yeni kurulduysa satırlar boş
+    let empty = weapon_rows.iter().any(|(_, children)| children.is_none());
+    if !weapons_changed && !inventory.is_changed() && !empty {
+        return;
+    }
+    let font = assets.asset_server.load(HUD_FONT);
+
+    let mut owned: Vec<_> = weapons.iter().filter(|(_, weapon)| weapon.owner == player).map(|(level, _)| level.into_inner()).collect();
+    owned.sort_by(|a, b| a.weapon_type.cmp(&b.weapon_type));
+    let weapon_slots = owned.iter().map(|level| {
+        let definition = assets.definitions.get(&level.weapon_type, &assets.weapon_assets);
+        let name = definition.map_or_else(|| level.weapon_type.to_string(), |definition| definition.name.clone());
+        let color = definition.map_or(Color::WHITE, |definition| {
+            let (r, g, b, _) = definition.color;
+            Color::srgb(r, g, b)
+        });
+        (HudSlot { label: short_name(&name), level: level.level }, color)
+    });
+    let passive_slots = inventory
+        .items
+        .iter()
+        .map(|(kind, level)| (HudSlot { label: short_name(&format!("{:?}", kind)), level: *level }, PASSIVE_SLOT_COLOR));
+
+    let rows = [
+        (weapon_rows.single(), weapon_slots.collect::<Vec<_>>()),
+        (passive_rows.single(), passive_slots.collect()),
+    ];
+    for (row, slots) in rows {
+        let Ok((row, children)) = row else {
+            continue;
+        };
+        for child in children.into_iter().flatten() {
+            commands.entity(*child).despawn();
+        }
+        // Boş satır da bir çocukla işaretlenir ki her frame yeniden kurulmasın
+        if slots.is_empty() {
+            commands.spawn((Node::default(), ChildOf(row)));
+        }
+        for (slot, color) in slots {
+            spawn_slot(&mut commands, row, &font, slot, color);
+        }
+    }
+}
+
+fn short_name(name: &str) -> String {
+    name.chars().filter(|c| !c.is_whitespace()).take(3).collect()
+}
+
+fn spawn_slot(commands: &mut Commands, row: Entity, font: &Handle<Font>, slot: HudSlot, color: Color) {
+    let label = slot.label.clone();
+    let level = slot.level.to_string();
+    commands.spawn((
+        slot,
+        Node {
+            width: Val::Px(SLOT_SIZE),
+            height: Val::Px(SLOT_SIZE),
+            flex_direction: FlexDirection::Column,
+            justify_content: JustifyContent::SpaceBetween,
+            padding: UiRect::all(Val::Px(3.0)),
+            ..default()
+        },
+        BackgroundColor(color.with_alpha(0.85)),
+        BorderRadius::all(Val::Px(4.0)),
+        ChildOf(row),
+    )).with_children(|icon| {
+        icon.spawn((Text::new(label), TextFont { font: font.clone(), font_size: 13.0, ..default() }, TextColor(Color::WHITE)));
+        icon.spawn((
+            Text::new(level),
+            TextFont { font: font.clone(), font_size: 13.0, ..default() },
+            TextColor(Color::srgb(1.0, 0.9, 0.3)),
+            Node { align_self: AlignSelf::FlexEnd, ..default() },
+        ));
+    });
+}
@@ -0,0 +1,257 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use bevy::input::InputSystems;
+use bevy::prelude::*;
+use serde::{Deserialize, Deserializer, Serialize};
+use crate::plugins::localization::UiText;
+use crate::plugins::settings::Settings;
+
+/// Analog değer bu eşiği geçince yön tuşu basılmış sayılır (~22.5°, 8 yön)
+pub const AXIS_PRESS_THRESHOLD: f32 = 0.38;
+const DEFAULT_STICK_DEADZONE: f32 = 0.2;
+
+/// Klavye ve gamepad girdisini oyunun eylemlerine çevirir. Oyun ve menüler tuşları değil
+/// `ActionState`'i okur; tuş atamaları `Settings` ile birlikte kaydedilir.
+pub struct InputActionPlugin;
+
+impl Plugin for InputActionPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ActionState>()
+            .configure_sets(PreUpdate, InputActionSystems.after(InputSystems))
+            .add_systems(PreUpdate, update_action_state.in_set(InputActionSystems));
+    }
+}
+
+/// `ActionState`'in güncellendiği yer - tuşlara sahte basanlar (simülasyon botu) bundan önce çalışmalı
+#[derive(SystemSet, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct InputActionSystems;
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize, Deserialize)]
+pub enum InputAction {
+    MoveUp,
+    MoveDown,
+    MoveLeft,
+    MoveRight,
+    Dash,
+    Pause,
+    Confirm,
+    Cancel,
+    Restart,
+}
+
+impl InputAction {
+    pub const ALL: [InputAction; 9] = [
+        InputAction::MoveUp,
+        InputAction::MoveDown,
+        InputAction::MoveLeft,
+        InputAction::MoveRight,
+        InputAction::Dash,
+        InputAction::Pause,
+        InputAction::Confirm,
+        InputAction::Cancel,
+        InputAction::Restart,
+    ];
+
+    pub fn label(self) -> UiText {
+        match self {
+            InputAction::MoveUp => UiText::MoveUp,
+            InputAction::MoveDown => UiText::MoveDown,
+            InputAction::MoveLeft => UiText::MoveLeft,
+            InputAction::MoveRight => UiText::MoveRight,
+            InputAction::Dash => UiText::Dash,
+            InputAction::Pause => UiText::Pause,
+            InputAction::Confirm => UiText::Confirm,
+            InputAction::Cancel => UiText::Cancel,
+            InputAction::Restart => UiText::Restart,
+        }
+    }
+
+    fn bit(self) -> u16 {
+        1 << self as u16
+    }
+}
+
+/// Her eylemin tuşları; ayarlar menüsünden sadece ilk klavye tuşu değiştirilir
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct InputBindings {
+    #[serde(deserialize_with = "known_actions")]
+    pub keys: BTreeMap<InputAction, Vec<KeyCode>>,
+    #[serde(deserialize_with = "known_actions")]
+    pub gamepad: BTreeMap<InputAction, Vec<GamepadButton>>,
+    /// Sol analog bu büyüklüğün altında sayılmaz
+    pub stick_deadzone: f32,
+}
+
+impl Default for InputBindings {
+    fn default() -> Self {
+        use InputAction::*;
+        let keys = [
+            (MoveUp, vec![KeyCode::KeyW, KeyCode::ArrowUp]),
+            (MoveDown, vec![KeyCode::KeyS, KeyCode::ArrowDown]),
+            (MoveLeft, vec![KeyCode::KeyA, KeyCode::ArrowLeft]),
+            (MoveRight, vec![KeyCode::KeyD, KeyCode::ArrowRight]),
+            (Dash, vec![KeyCode::ShiftLeft, KeyCode::ShiftRight]),
+            (Pause, vec![KeyCode::Escape]),
+            (Confirm, vec![KeyCode::Enter, KeyCode::Space]),
+            (Cancel, vec![KeyCode::Escape]),
+            (Restart, vec![KeyCode::KeyR]),
+        ];
+        let gamepad = [
+            (MoveUp, vec![GamepadButton::DPadUp]),
+            (MoveDown, vec![GamepadButton::DPadDown]),
+            (MoveLeft, vec![GamepadButton::DPadLeft]),
+            (MoveRight, vec![GamepadButton::DPadRight]),
+            (Dash, vec![GamepadButton::RightTrigger]),
+            (Pause, vec![GamepadButton::Start]),
+            (Confirm, vec![GamepadButton::South]),
+            (Cancel, vec![GamepadButton::East]),
+            (Restart, vec![GamepadButton::North]),
+        ];
+        Self {
+            keys: keys.into_iter().collect(),
+            gamepad: gamepad.into_iter().collect(),
+            stick_deadzone: DEFAULT_STICK_DEADZONE,
+        }
+    }
+}
+
+impl InputBindings {
+    /// Eylemin ilk klavye tuşunu `key` yap; tuş başka bir eyleme bağlıysa oradan kaldırılır
+    pub fn rebind(&mut self, action: InputAction, key: KeyCode) {
+        for keys in self.keys.values_mut() {
+            keys.retain(|bound| *bound != key);
+        }
+        let keys = self.keys.entry(action).or_default();
+        match keys.first_mut() {
+            Some(first) => *first = key,
+            None => keys.push(key),
+        }
+    }
+
+    /// Ayar dosyasında olmayan (sonradan eklenmiş) eylemlere varsayılan tuşları ver
+    pub fn fill_missing(&mut self) {
+        let defaults = Self::default();
+        for (action, keys) in defaults.keys {
+            self.keys.entry(action).or_insert(keys);
+        }
+        for (action, buttons) in defaults.gamepad {
+            self.gamepad.entry(action).or_insert(buttons);
+        }
+    }
+
+    pub fn primary_key(&self, action: InputAction) -> Option<KeyCode> {
+        self.keys.get(&action).and_then(|keys| keys.first()).copied()
+    }
+
+    /// Ölü bölge çıkarılıp 0-1 aralığına yeniden ölçeklenmiş analog
+    fn stick(&self, raw: Vec2) -> Vec2 {
+        let length = raw.length();
+        if length <= self.stick_deadzone {
+            return Vec2::ZERO;
+        }
+        let scaled = ((length - self.stick_deadzone) / (1.0 - self.stick_deadzone).max(f32::EPSILON)).min(1.0);
+        raw / length * scaled
+    }
+}
+
+/// Eski ayar dosyalarındaki kaldırılmış eylemler (ör. `Magnet`) atlanır, diğer ayarlar kaybolmaz
+fn known_actions<'de, D: Deserializer<'de>, T: Deserialize<'de>>(deserializer: D) -> Result<BTreeMap<InputAction, T>, D::Error> {
+    let stored = BTreeMap::<StoredAction, T>::deserialize(deserializer)?;
+    Ok(stored.into_iter().filter_map(|(action, value)| action.0.map(|action| (action, value))).collect())
+}
+
+/// Ayar dosyasındaki eylem adı; tanınmayan ad `None`
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+struct StoredAction(Option<InputAction>);
+
+impl<'de> Deserialize<'de> for StoredAction {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct ActionName;
+
+        impl serde::de::Visitor<'_> for ActionName {
+            type Value = StoredAction;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("an input action name")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, name: &str) -> Result<StoredAction, E> {
+                Ok(StoredAction(InputAction::ALL.into_iter().find(|action| format!("{:?}", action) == name)))
+            }
+        }
+
+        deserializer.deserialize_identifier(ActionName)
+    }
+}
+
+/// Menü ve ayarlar ekranı için tuşun kısa adı: `KeyW` -> `W`, `Digit1` -> `1`
+pub fn key_name(key: KeyCode) -> String {
+    let name = format!("{:?}", key);
+    ["Key", "Digit"]
+        .iter()
+        .find_map(|prefix| name.strip_prefix(prefix).filter(|rest| !rest.is_empty()).map(str::to_string))
+        .unwrap_or(name)
+}
+
+/// Bu frame'in eylemleri
+#[derive(Resource, Default, Debug)]
+pub struct ActionState {
+    pressed: u16,
+    just_pressed: u16,
+    /// Uzunluğu en fazla 1 olan hareket yönü
+    movement: Vec2,
+}
+
+impl ActionState {
+    pub fn just_pressed(&self, action: InputAction) -> bool {
+        self.just_pressed & action.bit() != 0
+    }
+
+    pub fn movement(&self) -> Vec2 {
+        self.movement
+    }
+}
+
+fn update_action_state(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    gamepads: Query<&Gamepad>,
+    settings: Res<Settings>,
+    mut actions: ResMut<ActionState>,
+) {
+    let bindings = &settings.bindings;
+    let stick = gamepads.iter().map(|gamepad| bindings.stick(gamepad.left_stick())).find(|stick| *stick != Vec2::ZERO).unwrap_or_default();
+
+    let mut pressed = 0;
+    // Aynı frame'de basılıp bırakılan tuşlar
+    let mut tapped = 0;
+    for action in InputAction::ALL {
+        let keys = bindings.keys.get(&action).map(Vec::as_slice).unwrap_or_default();
+        let buttons = bindings.gamepad.get(&action).map(Vec::as_slice).unwrap_or_default();
+        let key = keyboard.any_pressed(keys.iter().copied());
+        let button = gamepads.iter().any(|gamepad| gamepad.any_pressed(buttons.iter().copied()));
+        if keyboard.any_just_pressed(keys.iter().copied()) || gamepads.iter().any(|gamepad| gamepad.any_just_pressed(buttons.iter().copied())) {
+            tapped |= action.bit();
+        }
+        let axis = match action {
+            InputAction::MoveUp => stick.y > AXIS_PRESS_THRESHOLD,
+            InputAction::MoveDown => stick.y < -AXIS_PRESS_THRESHOLD,
+            InputAction::MoveLeft => stick.x < -AXIS_PRESS_THRESHOLD,
+            InputAction::MoveRight => stick.x > AXIS_PRESS_THRESHOLD,
+            _ => false,
+        };
+        if key || button || axis {
+            pressed |= action.bit();
+        }
+    }
+    // Analog kenar geçişleri de tuş basışı gibi sayılır (menülerde gezinme için)
+    actions.just_pressed = (pressed & !actions.pressed) | tapped;
+    actions.pressed = pressed;
+
+    let held = |action: InputAction| (pressed & action.bit() != 0) as i32;
+    let digital = Vec2::new(
+        (held(InputAction::MoveRight) - held(InputAction::MoveLeft)) as f32,
+        (held(InputAction::MoveUp) - held(InputAction::MoveDown)) as f32,
+    );
+    actions.movement = if stick != Vec2::ZERO { stick } else { digital.normalize_or_zero() };
+}
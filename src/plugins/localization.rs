@@ -0,0 +1,257 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use crate::plugins::settings::Settings;
+
+/// `Localized` yazıları seçili dile göre doldurur; dil değişince hepsi yenilenir
+pub struct LocalizationPlugin;
+
+impl Plugin for LocalizationPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(PostUpdate, localize_texts);
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, Serialize, Deserialize)]
+pub enum Language {
+    #[default]
+    English,
+    Turkish,
+}
+
+impl Language {
+    pub const ALL: [Language; 2] = [Language::English, Language::Turkish];
+
+    /// Dilin kendi dilindeki adı
+    pub fn name(self) -> &'static str {
+        match self {
+            Language::English => "English",
+            Language::Turkish => "Türkçe",
+        }
+    }
+}
+
+/// Menülerdeki çevrilen yazılar
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum UiText {
+    Play,
+    Settings,
+    Quit,
+    Resume,
+    RestartRun,
+    MainMenu,
+    Paused,
+    Back,
+    GameOver,
+    Time,
+    Level,
+    Weapons,
+    Passives,
+    TotalDps,
+    MasterVolume,
+    MusicVolume,
+    SfxVolume,
+    WindowMode,
+    Resolution,
+    VSync,
+    DamageNumbers,
+    ScreenShake,
+    Language,
+    On,
+    Off,
+    Windowed,
+    Borderless,
+    Fullscreen,
+    Controls,
+    ResetControls,
+    PressKey,
+    MoveUp,
+    MoveDown,
+    MoveLeft,
+    MoveRight,
+    Dash,
+    Pause,
+    Confirm,
+    Cancel,
+    Restart,
+    Treasure,
+    Continue,
+    PowerUps,
+    Gold,
+    MaxHealth,
+    Might,
+    MoveSpeed,
+    Revival,
+    Reroll,
+    WeaponChoice,
+    StartingWeapon,
+    Locked,
+    SaveAndQuit,
+    ChooseCharacter,
+    ReachLevel,
+    Survive,
+    /// HUD ve seçeneklerdeki kısa seviye öneki
+    LevelShort,
+    Kills,
+    NewWeapon,
+    UpgradeOption,
+    Phase,
+    Seed,
+    SeedClear,
+    SeedRandom,
+    Evolution,
+    /// Evrim ipucunda pasif zaten alınmışsa
+    Owned,
+}
+
+impl UiText {
+    pub fn get(self, language: Language) -> &'static str {
+        use Language::*;
+        match (self, language) {
+            (UiText::Play, English) => "Play",
+            (UiText::Play, Turkish) => "Oyna",
+            (UiText::Settings, English) => "Settings",
+            (UiText::Settings, Turkish) => "Ayarlar",
+            (UiText::Quit, English) => "Quit",
+            (UiText::Quit, Turkish) => "Çıkış",
+            (UiText::Resume, English) => "Resume",
+            (UiText::Resume, Turkish) => "Devam",
+            (UiText::RestartRun, English) => "Restart Run",
+            (UiText::RestartRun, Turkish) => "Yeniden Başla",
+            (UiText::MainMenu, English) => "Main Menu",
+            (UiText::MainMenu, Turkish) => "Ana Menü",
+            (UiText::Paused, English) => "Paused",
+            (UiText::Paused, Turkish) => "Duraklatıldı",
+            (UiText::Back, English) => "Back",
+            (UiText::Back, Turkish) => "Geri",
+            (UiText::GameOver, English) => "Game Over! Press R to Restart",
+            (UiText::GameOver, Turkish) => "Oyun Bitti! Yeniden başlamak için R",
+            (UiText::Time, English) => "Time",
+            (UiText::Time, Turkish) => "Süre",
+            (UiText::Level, English) => "Level",
+            (UiText::Level, Turkish) => "Seviye",
+            (UiText::Weapons, English) => "Weapons",
+            (UiText::Weapons, Turkish) => "Silahlar",
+            (UiText::Passives, English) => "Passives",
+            (UiText::Passives, Turkish) => "Pasifler",
+            (UiText::TotalDps, English) => "Total DPS",
+            (UiText::TotalDps, Turkish) => "Toplam DPS",
+            (UiText::MasterVolume, English) => "Master Volume",
+            (UiText::MasterVolume, Turkish) => "Ana Ses",
+            (UiText::MusicVolume, English) => "Music Volume",
+            (UiText::MusicVolume, Turkish) => "Müzik",
+            (UiText::SfxVolume, English) => "SFX Volume",
+            (UiText::SfxVolume, Turkish) => "Efektler",
+            (UiText::WindowMode, English) => "Window Mode",
+            (UiText::WindowMode, Turkish) => "Pencere",
+            (UiText::Resolution, English) => "Resolution",
+            (UiText::Resolution, Turkish) => "Çözünürlük",
+            (UiText::VSync, English) => "VSync",
+            (UiText::VSync, Turkish) => "Dikey Senkron",
+            (UiText::DamageNumbers, English) => "Damage Numbers",
+            (UiText::DamageNumbers, Turkish) => "Hasar Sayıları",
+            (UiText::ScreenShake, English) => "Screen Shake",
+            (UiText::ScreenShake, Turkish) => "Ekran Sarsıntısı",
+            (UiText::Language, English) => "Language",
+            (UiText::Language, Turkish) => "Dil",
+            (UiText::On, English) => "On",
+            (UiText::On, Turkish) => "Açık",
+            (UiText::Off, English) => "Off",
+            (UiText::Off, Turkish) => "Kapalı",
+            (UiText::Windowed, English) => "Windowed",
+            (UiText::Windowed, Turkish) => "Pencereli",
+            (UiText::Borderless, English) => "Borderless",
+            (UiText::Borderless, Turkish) => "Çerçevesiz",
+            (UiText::Fullscreen, English) => "Fullscreen",
+            (UiText::Fullscreen, Turkish) => "Tam Ekran",
+            (UiText::Controls, English) => "Controls",
+            (UiText::Controls, Turkish) => "Kontroller",
+            (UiText::ResetControls, English) => "Reset Controls",
+            (UiText::ResetControls, Turkish) => "Varsayılan Tuşlar",
+            (UiText::PressKey, English) => "Press a key...",
+            (UiText::PressKey, Turkish) => "Bir tuşa basın...",
+            (UiText::MoveUp, English) => "Move Up",
+            (UiText::MoveUp, Turkish) => "Yukarı",
+            (UiText::MoveDown, English) => "Move Down",
+            (UiText::MoveDown, Turkish) => "Aşağı",
+            (UiText::MoveLeft, English) => "Move Left",
+            (UiText::MoveLeft, Turkish) => "Sol",
+            (UiText::MoveRight, English) => "Move Right",
+            (UiText::MoveRight, Turkish) => "Sağ",
+            (UiText::Dash, English) => "Dash",
+            (UiText::Dash, Turkish) => "Atılma",
+            (UiText::Pause, English) => "Pause",
+            (UiText::Pause, Turkish) => "Duraklat",
+            (UiText::Confirm, English) => "Confirm",
+            (UiText::Confirm, Turkish) => "Onayla",
+            (UiText::Cancel, English) => "Cancel",
+            (UiText::Cancel, Turkish) => "İptal",
+            (UiText::Restart, English) => "Restart",
+            (UiText::Restart, Turkish) => "Yeniden Başla",
+            (UiText::Treasure, English) => "Treasure!",
+            (UiText::Treasure, Turkish) => "Hazine!",
+            (UiText::Continue, English) => "Continue",
+            (UiText::Continue, Turkish) => "Devam Et",
+            (UiText::PowerUps, English) => "Power Ups",
+            (UiText::PowerUps, Turkish) => "Dükkan",
+            (UiText::Gold, English) => "Gold",
+            (UiText::Gold, Turkish) => "Altın",
+            (UiText::MaxHealth, English) => "Max Health",
+            (UiText::MaxHealth, Turkish) => "Maks Can",
+            (UiText::Might, English) => "Might",
+            (UiText::Might, Turkish) => "Güç",
+            (UiText::MoveSpeed, English) => "Move Speed",
+            (UiText::MoveSpeed, Turkish) => "Hareket Hızı",
+            (UiText::Revival, English) => "Revival",
+            (UiText::Revival, Turkish) => "Diriliş",
+            (UiText::Reroll, English) => "Reroll",
+            (UiText::Reroll, Turkish) => "Yeniden Çek",
+            (UiText::WeaponChoice, English) => "Weapon Choice",
+            (UiText::WeaponChoice, Turkish) => "Silah Seçimi",
+            (UiText::StartingWeapon, English) => "Starting Weapon",
+            (UiText::StartingWeapon, Turkish) => "Başlangıç Silahı",
+            (UiText::Locked, English) => "Locked",
+            (UiText::Locked, Turkish) => "Kilitli",
+            (UiText::SaveAndQuit, English) => "Save & Quit",
+            (UiText::SaveAndQuit, Turkish) => "Kaydet ve Çık",
+            (UiText::ChooseCharacter, English) => "Choose a Character",
+            (UiText::ChooseCharacter, Turkish) => "Karakter Seç",
+            (UiText::ReachLevel, English) => "Reach level",
+            (UiText::ReachLevel, Turkish) => "Ulaşılacak seviye",
+            (UiText::Survive, English) => "Survive",
+            (UiText::Survive, Turkish) => "Hayatta kal",
+            (UiText::LevelShort, English) => "Lv",
+            (UiText::LevelShort, Turkish) => "Sv",
+            (UiText::Kills, English) => "Kills",
+            (UiText::Kills, Turkish) => "Öldürme",
+            (UiText::NewWeapon, English) => "New weapon",
+            (UiText::NewWeapon, Turkish) => "Yeni silah",
+            (UiText::UpgradeOption, English) => "Option",
+            (UiText::UpgradeOption, Turkish) => "Seçenek",
+            (UiText::Phase, English) => "Phase",
+            (UiText::Phase, Turkish) => "Faz",
+            (UiText::Seed, English) => "Seed",
+            (UiText::Seed, Turkish) => "Seed",
+            (UiText::SeedClear, English) => "Backspace to clear",
+            (UiText::SeedClear, Turkish) => "silmek için Backspace",
+            (UiText::SeedRandom, English) => "random (type digits to set)",
+            (UiText::SeedRandom, Turkish) => "rastgele (ayarlamak için rakam yazın)",
+            (UiText::Evolution, English) => "Evolution",
+            (UiText::Evolution, Turkish) => "Evrim",
+            (UiText::Owned, English) => "owned",
+            (UiText::Owned, Turkish) => "var",
+        }
+    }
+}
+
+/// Yazısı seçili dile göre doldurulan `Text`
+#[derive(Component, Clone, Copy, Debug)]
+#[require(Text)]
+pub struct Localized(pub UiText);
+
+fn localize_texts(settings: Res<Settings>, mut texts: Query<(Ref<Localized>, &mut Text)>) {
+    for (localized, mut text) in texts.iter_mut() {
+        if settings.is_changed() || localized.is_added() {
+            text.0 = localized.0.get(settings.language).to_string();
+        }
+    }
+}
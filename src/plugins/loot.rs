@@ -0,0 +1,324 @@
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use crate::plugins::aabb::AABB;
+use crate::plugins::damage::{apply_damage, DamageEvent, DamageType};
+use crate::plugins::enemy::{Collectible, Enemy, EnemyDiedEvent, EnemyDrops, XP};
+use crate::plugins::enemy_kind::EnemyKind;
+use crate::plugins::game_state::{GameState, GameplaySet};
+use crate::plugins::passive_item::PlayerStats;
+use crate::plugins::player::{Player, XPMagnetite};
+use crate::plugins::player_damage::PlayerDeath;
+use crate::plugins::rng::{GameRng, RngStream};
+use crate::plugins::ron_asset::{RonAsset, RonAssetAppExt};
+use crate::plugins::screen_shake::ScreenShake;
+use crate::plugins::spatial_grid::{SpatialGrid, SpatialLayer};
+use crate::plugins::status_effect::{StatusApplication, StatusEffects, StatusKind};
+use crate::plugins::weapons::GameEntity;
+
+/// Bomba bu yarıçaptaki düşmanlara vurur - 1280x720 ekranın köşelerine kadar
+pub const BOMB_RADIUS: f32 = 800.0;
+const BOMB_TRAUMA: f32 = 0.6;
+/// Luck, 1'den fazla ödüllü sandıkların ağırlığını bu oranda artırır
+const LUCKY_CHEST_BONUS: f32 = 2.0;
+
+/// `assets/data/*.loot.ron` tablosuna göre düşmanlardan eşya düşüren ve toplanan eşyaları uygulayan plugin
+pub struct LootPlugin;
+
+impl Plugin for LootPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_ron_asset::<LootTable>()
+            .init_resource::<LootTables>()
+            .add_message::<PickupCollectedEvent>()
+            .add_message::<ChestOpenedEvent>()
+            .add_systems(FixedUpdate, (drop_loot.after(apply_damage), collect_pickups).in_set(GameplaySet))
+            .add_systems(Update, shake_on_bomb.run_if(in_state(GameState::Playing)));
+    }
+}
+
+/// Yerden toplanan eşyalar. XP küreleri ayrıdır (`XP`), her düşman bir tane bırakır
+#[derive(Component, Clone, Copy, PartialEq, Debug, Serialize, Deserialize)]
+pub enum Pickup {
+    /// Yerdeki tavuk: verilen kadar can
+    Chicken(u32),
+    /// Altın
+    Gold(u32),
+    /// Yerdeki tüm XP'leri oyuncuya çeker
+    Magnet,
+    /// Ekrandaki tüm düşmanlara verilen kadar hasar
+    Bomb(f32),
+    /// Tüm düşmanları verilen saniye kadar dondurur
+    Clock(f32),
+    /// Ödül ekranını açan hazine sandığı
+    Chest,
+}
+
+/// Düşman türlerine göre eşya düşme kuralları ve sandık ödül sayıları
+#[derive(Asset, TypePath, Debug, Clone, Deserialize)]
+pub struct LootTable {
+    /// Düşmana uyan ilk kural kullanılır
+    pub rules: Vec<LootRule>,
+    /// Sandıktan kaç yükseltme çıkacağının ağırlıkları
+    pub chest_rewards: Vec<ChestRewardWeight>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct LootRule {
+    /// Boşsa her düşmana uyar
+    #[serde(default)]
+    pub enemies: Vec<EnemyKind>,
+    /// Bir eşya düşme şansı; Luck ile `(1 + luck)` katına çıkar
+    pub drop_chance: f32,
+    pub drops: Vec<LootDrop>,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct LootDrop {
+    pub item: Pickup,
+    pub weight: u32,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct ChestRewardWeight {
+    pub count: u32,
+    pub weight: u32,
+}
+
+impl RonAsset for LootTable {
+    const EXTENSIONS: &'static [&'static str] = &["loot.ron"];
+
+    fn validate(&self) -> Result<(), String> {
+        for (i, rule) in self.rules.iter().enumerate() {
+            if !(0.0..=1.0).contains(&rule.drop_chance) {
+                return Err(format!("rule {i}: drop_chance must be between 0 and 1"));
+            }
+            if rule.drops.is_empty() || rule.drops.iter().any(|drop| drop.weight == 0) {
+                return Err(format!("rule {i}: drops must be non-empty with positive weights"));
+            }
+        }
+        if self.chest_rewards.is_empty() || self.chest_rewards.iter().any(|reward| reward.count == 0 || reward.weight == 0) {
+            return Err("chest_rewards must be non-empty with positive counts and weights".to_string());
+        }
+        Ok(())
+    }
+}
+
+impl LootTable {
+    pub fn rule_for(&self, kind: EnemyKind) -> Option<&LootRule> {
+        self.rules.iter().find(|rule| rule.enemies.is_empty() || rule.enemies.contains(&kind))
+    }
+
+    /// Düşen eşya - şans tutmazsa `None`
+    pub fn roll_drop(&self, kind: EnemyKind, luck: f32, rng: &mut impl Rng) -> Option<Pickup> {
+        let rule = self.rule_for(kind)?;
+        let chance = (rule.drop_chance * (1.0 + luck)).min(1.0);
+        if rng.random::<f32>() >= chance {
+            return None;
+        }
+        let total: u32 = rule.drops.iter().map(|drop| drop.weight).sum();
+        let mut roll = rng.random_range(0..total);
+        for drop in &rule.drops {
+            if roll < drop.weight {
+                return Some(drop.item);
+            }
+            roll -= drop.weight;
+        }
+        None
+    }
+
+    /// Sandığın ödül sayısı
+    pub fn roll_chest_rewards(&self, luck: f32, rng: &mut impl Rng) -> u32 {
+        let weight = |reward: &ChestRewardWeight| {
+            if reward.count > 1 { reward.weight as f32 * (1.0 + luck * LUCKY_CHEST_BONUS) } else { reward.weight as f32 }
+        };
+        let total: f32 = self.chest_rewards.iter().map(weight).sum();
+        let mut roll = rng.random::<f32>() * total;
+        for reward in &self.chest_rewards {
+            if roll < weight(reward) {
+                return reward.count;
+            }
+            roll -= weight(reward);
+        }
+        self.chest_rewards.last().map_or(1, |reward| reward.count)
+    }
+}
+
+#[derive(Resource)]
+pub struct LootTables {
+    pub table: Handle<LootTable>,
+}
+
+impl FromWorld for LootTables {
+    fn from_world(world: &mut World) -> Self {
+        let asset_server = world.get_resource::<AssetServer>()
+            .expect("AssetServer resource not found.");
+        Self {
+            table: asset_server.load("data/default.loot.ron"),
+        }
+    }
+}
+
+/// Açılan sandık - ödüller `chest_reward.rs`'de verilir
+#[derive(Message, Clone, Copy, Debug)]
+pub struct ChestOpenedEvent {
+    pub rewards: u32,
+}
+
+/// Oyuncu bir eşya topladı (geri bildirim için)
+#[derive(Message, Clone, Copy, Debug)]
+pub struct PickupCollectedEvent {
+    pub pickup: Pickup,
+    pub position: Vec3,
+}
+
+/// Loot tablosu ve Loot RNG akışı
+#[derive(SystemParam)]
+pub struct LootRoller<'w> {
+    tables: Res<'w, LootTables>,
+    assets: Res<'w, Assets<LootTable>>,
+    rng: ResMut<'w, GameRng>,
+}
+
+impl LootRoller<'_> {
+    fn drop_for(&mut self, kind: EnemyKind, luck: f32) -> Option<Pickup> {
+        let table = self.assets.get(&self.tables.table)?;
+        table.roll_drop(kind, luck, self.rng.stream(RngStream::Loot))
+    }
+
+    fn chest_rewards(&mut self, luck: f32) -> u32 {
+        let Some(table) = self.assets.get(&self.tables.table) else {
+            return 1;
+        };
+        table.roll_chest_rewards(luck, self.rng.stream(RngStream::Loot))
+    }
+}
+
+/// Yerdeki bir eşyanın görünümü: tavuk ve sandık sprite, diğerleri renkli daire
+pub fn spawn_pickup(commands: &mut Commands, pickup: Pickup, position: Vec3, drops: &mut EnemyDrops, asset_server: &AssetServer) {
+    let sprite = match pickup {
+        Pickup::Chicken(_) => Some(("textures/rpg/props/generic-rpg-loot05.png", 24.0)),
+        Pickup::Chest => Some(("textures/rpg/props/generic-rpg-treasure-closed.png", 50.0)),
+        _ => None,
+    };
+    let size = sprite.map_or(24.0, |(_, size)| size);
+    let mut aabb = AABB { min_x: 0.0, min_y: 0.0, max_x: 0.0, max_y: 0.0, width: size, height: size };
+    aabb.change_point(position);
+
+    let mut entity = commands.spawn((GameEntity, Collectible, pickup, aabb));
+    if let Some((path, _)) = sprite {
+        entity.insert((Sprite::from_image(asset_server.load(path)), Transform::from_translation(position).with_scale(Vec3::splat(2.0))));
+        return;
+    }
+    let (radius, color) = match pickup {
+        Pickup::Gold(_) => (6.0, Color::srgb(1.0, 0.85, 0.2)),
+        Pickup::Magnet => (9.0, Color::srgb(0.2, 0.5, 1.0)),
+        Pickup::Bomb(_) => (10.0, Color::srgb(0.15, 0.15, 0.15)),
+        _ => (9.0, Color::srgb(0.5, 0.95, 1.0)),
+    };
+    entity.insert((
+        Transform::from_translation(position),
+        Mesh2d(drops.meshes.add(Circle::new(radius))),
+        MeshMaterial2d(drops.materials.add(ColorMaterial::from(color))),
+    ));
+}
+
+/// Ölen düşmanın loot kuralına göre zar at; eşya XP küresinin biraz yanına düşer
+fn drop_loot(
+    mut commands: Commands,
+    mut died_events: MessageReader<EnemyDiedEvent>,
+    mut roller: LootRoller,
+    mut drops: EnemyDrops,
+    asset_server: Res<AssetServer>,
+    stats: Query<&PlayerStats, With<Player>>,
+) {
+    let luck = stats.single().map_or(0.0, |stats| stats.luck);
+    for event in died_events.read() {
+        let Some(pickup) = roller.drop_for(event.kind, luck) else {
+            continue;
+        };
+        let position = event.position.with_z(0.0) + Vec3::new(12.0, 0.0, 0.0);
+        spawn_pickup(&mut commands, pickup, position, &mut drops, &asset_server);
+    }
+}
+
+/// Eşyaların oyuncuya ve dünyaya etkisi
+#[derive(SystemParam)]
+pub struct PickupEffects<'w, 's> {
+    xp: Query<'w, 's, Entity, With<XP>>,
+    enemies: Query<'w, 's, (Entity, &'static Transform, &'static mut StatusEffects), With<Enemy>>,
+    damage_events: MessageWriter<'w, DamageEvent>,
+    chest_events: MessageWriter<'w, ChestOpenedEvent>,
+    collected_events: MessageWriter<'w, PickupCollectedEvent>,
+    roller: LootRoller<'w>,
+}
+
+/// Oyuncuya değen eşyaları topla. Aynı tick'te en fazla bir sandık açılır, diğeri sonraki tick'e kalır
+pub fn collect_pickups(
+    mut commands: Commands,
+    mut player_query: Query<(&mut Player, &Transform, &AABB, &PlayerStats), Without<PlayerDeath>>,
+    pickups: Query<(&AABB, &Pickup)>,
+    grid: Res<SpatialGrid>,
+    mut effects: PickupEffects,
+) {
+    let Ok((mut player, player_transform, player_aabb, stats)) = player_query.single_mut() else {
+        return;
+    };
+    let player_position = player_transform.translation;
+    let mut chest_opened = false;
+
+    for candidate in grid.query_aabb(player_aabb.min(), player_aabb.max(), SpatialLayer::Collectible) {
+        let Ok((aabb, &pickup)) = pickups.get(candidate.entity) else {
+            continue;
+        };
+        if !aabb.self_aabb_intersects(player_aabb) || (pickup == Pickup::Chest && chest_opened) {
+            continue;
+        }
+        match pickup {
+            Pickup::Chicken(amount) => player.heal(amount, &mut commands),
+            Pickup::Gold(amount) => player.gold += amount,
+            Pickup::Magnet => {
+                for entity in effects.xp.iter() {
+                    commands.entity(entity).try_insert(XPMagnetite::default());
+                }
+            }
+            Pickup::Bomb(damage) => {
+                for (target, transform, _) in effects.enemies.iter() {
+                    if transform.translation.truncate().distance(player_position.truncate()) > BOMB_RADIUS {
+                        continue;
+                    }
+                    effects.damage_events.write(DamageEvent {
+                        source: None,
+                        weapon: None,
+                        target,
+                        amount: damage,
+                        damage_type: DamageType::Physical,
+                        crit: false,
+                        over_time: false,
+                        knockback: Vec3::ZERO,
+                    });
+                }
+            }
+            Pickup::Clock(seconds) => {
+                let freeze = StatusApplication { kind: StatusKind::Freeze, duration: seconds, magnitude: 0.0, chance: 1.0 };
+                for (_, _, mut status) in effects.enemies.iter_mut() {
+                    status.apply(&freeze, None);
+                }
+            }
+            Pickup::Chest => {
+                chest_opened = true;
+                let rewards = effects.roller.chest_rewards(stats.luck);
+                println!("🎁 Chest opened: {} reward(s)", rewards);
+                effects.chest_events.write(ChestOpenedEvent { rewards });
+            }
+        }
+        effects.collected_events.write(PickupCollectedEvent { pickup, position: aabb.center().extend(0.0) });
+        commands.entity(candidate.entity).try_despawn();
+    }
+}
+
+fn shake_on_bomb(mut collected_events: MessageReader<PickupCollectedEvent>, mut shake: ResMut<ScreenShake>) {
+    if collected_events.read().any(|event| matches!(event.pickup, Pickup::Bomb(_))) {
+        shake.add_trauma(BOMB_TRAUMA);
+    }
+}
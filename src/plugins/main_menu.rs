@@ -1,13 +1,19 @@
 use bevy::prelude::*;
 use bevy_ecs::relationship::RelatedSpawnerCommands;
 use crate::plugins::game_state::GameState;
+use crate::plugins::localization::{Language, Localized, UiText};
+use crate::plugins::power_up_shop::{open_power_up_shop, power_up_shop_open};
+use crate::plugins::rng::SeedSettings;
+use crate::plugins::run_save::{continue_run, RunSaveFile};
+use crate::plugins::settings::Settings;
+use crate::plugins::settings_menu::{open_settings_menu, settings_menu_open};
 
 pub struct MainMenuPlugin;
 
 impl Plugin for MainMenuPlugin {
     fn build(&self, app: &mut App) {
         app.add_systems(OnEnter(GameState::MainMenu), setup_main_menu)
-            .add_systems(Update, (handle_menu_buttons, button_hover_effect).run_if(in_state(GameState::MainMenu)))
+            .add_systems(Update, (handle_menu_buttons, button_hover_effect::<MenuButton>, edit_seed).run_if(in_state(GameState::MainMenu).and(not(settings_menu_open)).and(not(power_up_shop_open))))
             .add_systems(OnExit(GameState::MainMenu), cleanup_menu);
     }
 }
@@ -15,14 +21,27 @@ impl Plugin for MainMenuPlugin {
 #[derive(Component)]
 struct MainMenuUI;
 
+/// Menüdeki seed satırı - rakam tuşlarıyla yazılır
 #[derive(Component)]
-enum MenuButton {
+struct SeedText;
+
+#[derive(Component, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum MenuButton {
+    /// Sadece yarım kalan koşu kaydı varsa
+    Continue,
     Play,
+    PowerUps,
     Settings,
     Quit,
 }
 
-fn setup_main_menu(mut commands: Commands, asset_server: Res<AssetServer>) {
+fn setup_main_menu(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    seed: Res<SeedSettings>,
+    settings: Res<Settings>,
+    run_save: Res<RunSaveFile>,
+) {
     let font = asset_server.load("fonts/FiraMono-Medium.ttf");
 
     commands.spawn((
@@ -49,13 +68,31 @@ fn setup_main_menu(mut commands: Commands, asset_server: Res<AssetServer>) {
                 ..default()
             }
             ));
-            spawn_button(parent, "Play", MenuButton::Play, font.clone());
-            spawn_button(parent, "Settings", MenuButton::Settings, font.clone());
-            spawn_button(parent, "Quit", MenuButton::Quit, font.clone());
+            if run_save.exists() {
+                spawn_button(parent, UiText::Continue, MenuButton::Continue, font.clone());
+            }
+            spawn_button(parent, UiText::Play, MenuButton::Play, font.clone());
+            spawn_button(parent, UiText::PowerUps, MenuButton::PowerUps, font.clone());
+            spawn_button(parent, UiText::Settings, MenuButton::Settings, font.clone());
+            spawn_button(parent, UiText::Quit, MenuButton::Quit, font.clone());
+            parent.spawn((
+                SeedText,
+                Text::new(seed_label(&seed, settings.language)),
+                TextFont{
+                    font: font.clone(),
+                    font_size: 24.0,
+                    ..default()
+                },
+                Node{
+                    margin: UiRect::top(Val::Px(30.0)),
+                    ..default()
+                }
+                ));
     });
 }
 
-fn spawn_button(parent: &mut RelatedSpawnerCommands<ChildOf>, text: &str, button_type: MenuButton, font: Handle<Font>){
+/// Menü düğmesi - `button_type` hangi düğme olduğunu belirten component
+pub fn spawn_button(parent: &mut RelatedSpawnerCommands<ChildOf>, text: UiText, button_type: impl Component, font: Handle<Font>){
     parent.spawn((
         Button,
         button_type,
@@ -70,7 +107,7 @@ fn spawn_button(parent: &mut RelatedSpawnerCommands<ChildOf>, text: &str, button
         BackgroundColor(Color::srgb(0.3, 0.3, 0.3)),
         )).with_children(|btn| {
             btn.spawn((
-                Text::new(text),
+                Localized(text),
                 TextFont{
                     font,
                     font_size: 30.0,
@@ -81,23 +118,80 @@ fn spawn_button(parent: &mut RelatedSpawnerCommands<ChildOf>, text: &str, button
 }
 
 fn handle_menu_buttons(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
     interactions_q: Query<(&Interaction, &MenuButton), Changed<Interaction>>,
     mut next_state: ResMut<NextState<GameState>>,
     mut exit: MessageWriter<AppExit>,
+    run_save: Res<RunSaveFile>,
 ){
     for (interaction, button) in &interactions_q {
         if *interaction == Interaction::Pressed {
             match button {
-                MenuButton::Play => next_state.set(GameState::Loading),
-                MenuButton::Settings => println!("Settings clicked"),
+                MenuButton::Continue => {
+                    if continue_run(&mut commands, &run_save) {
+                        next_state.set(GameState::Loading);
+                    }
+                }
+                MenuButton::Play => next_state.set(GameState::CharacterSelect),
+                MenuButton::PowerUps => open_power_up_shop(&mut commands, &asset_server),
+                MenuButton::Settings => open_settings_menu(&mut commands, &asset_server),
                 MenuButton::Quit => {exit.write(AppExit::Success);},
             };
         }
     }
 }
 
-fn button_hover_effect(
-    interactions_q: Query<(&Interaction, Entity), (With<MenuButton>,Changed<Interaction>)>,
+fn seed_label(seed: &SeedSettings, language: Language) -> String {
+    let text = |text: UiText| text.get(language);
+    match seed.fixed {
+        Some(seed) => format!("{}: {} ({})", text(UiText::Seed), seed, text(UiText::SeedClear)),
+        None => format!("{}: {}", text(UiText::Seed), text(UiText::SeedRandom)),
+    }
+}
+
+const DIGIT_KEYS: [(KeyCode, KeyCode); 10] = [
+    (KeyCode::Digit0, KeyCode::Numpad0),
+    (KeyCode::Digit1, KeyCode::Numpad1),
+    (KeyCode::Digit2, KeyCode::Numpad2),
+    (KeyCode::Digit3, KeyCode::Numpad3),
+    (KeyCode::Digit4, KeyCode::Numpad4),
+    (KeyCode::Digit5, KeyCode::Numpad5),
+    (KeyCode::Digit6, KeyCode::Numpad6),
+    (KeyCode::Digit7, KeyCode::Numpad7),
+    (KeyCode::Digit8, KeyCode::Numpad8),
+    (KeyCode::Digit9, KeyCode::Numpad9),
+];
+
+/// Rakamlar seed'in sonuna eklenir, Backspace son rakamı siler; boş seed = rastgele.
+/// Yazı seed ya da dil değişince yenilenir
+fn edit_seed(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    settings: Res<Settings>,
+    mut seed: ResMut<SeedSettings>,
+    mut text: Single<&mut Text, With<SeedText>>,
+){
+    let mut fixed = seed.fixed;
+    for (digit, (key, numpad)) in DIGIT_KEYS.iter().enumerate() {
+        if keyboard.any_just_pressed([*key, *numpad]) {
+            let current = fixed.unwrap_or(0);
+            fixed = Some(current.checked_mul(10).and_then(|value| value.checked_add(digit as u64)).unwrap_or(current));
+        }
+    }
+    if keyboard.just_pressed(KeyCode::Backspace) {
+        fixed = fixed.map(|value| value / 10).filter(|value| *value > 0);
+    }
+    if fixed != seed.fixed || settings.is_changed() {
+        seed.fixed = fixed;
+        text.0 = seed_label(&seed, settings.language);
+    }
+}
+
+/// Etkileşimi bu kare değişen `B` düğmeleri
+type InteractedButtons<B> = (With<B>, Changed<Interaction>);
+
+pub fn button_hover_effect<B: Component>(
+    interactions_q: Query<(&Interaction, Entity), InteractedButtons<B>>,
     mut colors: Query<&mut BackgroundColor>,
 ){
     for (interaction, button) in &interactions_q {
@@ -0,0 +1,257 @@
+use std::collections::BTreeMap;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use crate::plugins::game_state::GameplaySet;
+use crate::plugins::player::Player;
+use crate::plugins::ron_asset::{RonAsset, RonAssetAppExt};
+
+/// Aynı anda taşınabilecek farklı pasif eşya sayısı
+pub const MAX_PASSIVE_SLOTS: usize = 6;
+
+/// `assets/data/*.passives.ron` kataloğunu yükleyen ve `PlayerStats`'ı güncel tutan plugin
+pub struct PassiveItemPlugin;
+
+impl Plugin for PassiveItemPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_ron_asset::<PassiveCatalogue>()
+            .init_resource::<PassiveItems>()
+            .add_systems(FixedUpdate, (update_player_stats, regenerate_health).in_set(GameplaySet));
+    }
+}
+
+/// Pasif eşya tipleri - evrim tariflerinde de bu isimler kullanılır
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Debug, Serialize, Deserialize)]
+pub enum PassiveKind {
+    /// Tüm silah hasarı
+    Might,
+    /// Alınan her darbeden düşülen sabit hasar
+    Armor,
+    /// Silahların ateş aralığı
+    Cooldown,
+    /// Patlama / alan yarıçapları
+    Area,
+    /// Hareket hızı
+    Speed,
+    /// Atış başına ekstra mermi
+    Amount,
+    /// XP toplama yarıçapı
+    Magnet,
+    /// Dördüncü yükseltme seçeneği şansı
+    Luck,
+    /// Kazanılan XP
+    Growth,
+    /// Saniye başına can yenilenmesi
+    Recovery,
+}
+
+impl PassiveKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            PassiveKind::Might => "Might",
+            PassiveKind::Armor => "Armor",
+            PassiveKind::Cooldown => "Cooldown",
+            PassiveKind::Area => "Area",
+            PassiveKind::Speed => "Speed",
+            PassiveKind::Amount => "Amount",
+            PassiveKind::Magnet => "Magnet",
+            PassiveKind::Luck => "Luck",
+            PassiveKind::Growth => "Growth",
+            PassiveKind::Recovery => "Recovery",
+        }
+    }
+}
+
+/// Tüm pasiflerin seviye başına etkisi ve menü metinleri
+#[derive(Asset, TypePath, Debug, Clone, Deserialize)]
+pub struct PassiveCatalogue {
+    pub items: Vec<PassiveDefinition>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct PassiveDefinition {
+    pub kind: PassiveKind,
+    pub description: String,
+    pub max_level: i32,
+    /// Her seviyenin etkisi; birimi tipe göre değişir (oran, hasar, piksel, can/sn)
+    pub per_level: f32,
+}
+
+impl RonAsset for PassiveCatalogue {
+    const EXTENSIONS: &'static [&'static str] = &["passives.ron"];
+
+    fn validate(&self) -> Result<(), String> {
+        for (i, item) in self.items.iter().enumerate() {
+            if self.items[..i].iter().any(|other| other.kind == item.kind) {
+                return Err(format!("{:?} is defined more than once", item.kind));
+            }
+            if item.max_level < 1 {
+                return Err(format!("{:?}: max_level must be at least 1", item.kind));
+            }
+            if item.per_level <= 0.0 {
+                return Err(format!("{:?}: per_level must be positive", item.kind));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl PassiveCatalogue {
+    pub fn get(&self, kind: PassiveKind) -> Option<&PassiveDefinition> {
+        self.items.iter().find(|item| item.kind == kind)
+    }
+}
+
+#[derive(Resource)]
+pub struct PassiveItems {
+    pub catalogue: Handle<PassiveCatalogue>,
+}
+
+impl FromWorld for PassiveItems {
+    fn from_world(world: &mut World) -> Self {
+        let asset_server = world.get_resource::<AssetServer>()
+            .expect("AssetServer resource not found.");
+        Self {
+            catalogue: asset_server.load("data/default.passives.ron"),
+        }
+    }
+}
+
+/// Oyuncunun sahip olduğu pasif eşyalar ve seviyeleri
+#[derive(Component, Clone, Default, Serialize, Deserialize)]
+pub struct PassiveInventory {
+    pub items: BTreeMap<PassiveKind, i32>,
+}
+
+impl PassiveInventory {
+    pub fn level(&self, kind: PassiveKind) -> i32 {
+        self.items.get(&kind).copied().unwrap_or(0)
+    }
+
+    pub fn has(&self, kind: PassiveKind) -> bool {
+        self.level(kind) > 0
+    }
+
+    pub fn has_free_slot(&self) -> bool {
+        self.items.len() < MAX_PASSIVE_SLOTS
+    }
+}
+
+/// Magnet pasifi olmadan XP'lerin çekildiği yarıçap
+pub const BASE_PICKUP_RADIUS: f32 = 60.0;
+
+/// Pasiflerin toplam etkisi - silahlar, hareket ve toplama bunu okur
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+#[require(StartingStats)]
+pub struct PlayerStats {
+    /// Hasar çarpanı
+    pub might: f32,
+    /// Darbe başına düşülen hasar
+    pub armor: u32,
+    /// Ateş aralığı çarpanı (küçük = hızlı)
+    pub cooldown: f32,
+    /// Yarıçap çarpanı
+    pub area: f32,
+    /// Hareket hızı çarpanı
+    pub move_speed: f32,
+    /// Atış başına ekstra mermi
+    pub amount: u32,
+    /// XP'lerin kendiliğinden çekildiği yarıçap
+    pub pickup_radius: f32,
+    /// 0..1 arası şans
+    pub luck: f32,
+    /// XP çarpanı
+    pub growth: f32,
+    /// Saniye başına can
+    pub recovery: f32,
+}
+
+impl Default for PlayerStats {
+    fn default() -> Self {
+        Self {
+            might: 1.0,
+            armor: 0,
+            cooldown: 1.0,
+            area: 1.0,
+            move_speed: 1.0,
+            amount: 0,
+            pickup_radius: BASE_PICKUP_RADIUS,
+            luck: 0.0,
+            growth: 1.0,
+            recovery: 0.0,
+        }
+    }
+}
+
+impl PlayerStats {
+    /// `base` karakter bonusu ve profilden gelen kalıcı güçlendirmeler; pasifler onun üstüne eklenir
+    pub fn from_inventory(base: PlayerStats, inventory: &PassiveInventory, catalogue: &PassiveCatalogue) -> Self {
+        let mut stats = base;
+        for (&kind, &level) in inventory.items.iter() {
+            let Some(definition) = catalogue.get(kind) else {
+                continue;
+            };
+            stats.add(kind, definition.per_level * level as f32);
+        }
+        stats
+    }
+
+    /// `total` pasifin birimindedir (karakter bonusları da aynı birimle eklenir)
+    pub fn add(&mut self, kind: PassiveKind, total: f32) {
+        match kind {
+            PassiveKind::Might => self.might += total,
+            PassiveKind::Armor => self.armor += total as u32,
+            PassiveKind::Cooldown => self.cooldown = (self.cooldown - total).max(0.2),
+            PassiveKind::Area => self.area += total,
+            PassiveKind::Speed => self.move_speed += total,
+            PassiveKind::Amount => self.amount += total as u32,
+            PassiveKind::Magnet => self.pickup_radius += total,
+            PassiveKind::Luck => self.luck = (self.luck + total).min(1.0),
+            PassiveKind::Growth => self.growth += total,
+            PassiveKind::Recovery => self.recovery += total,
+        }
+    }
+
+    /// Zırhtan sonra kalan hasar - her darbe en az 1 vurur
+    pub fn reduce_damage(&self, damage: u32) -> u32 {
+        damage.saturating_sub(self.armor).max(1)
+    }
+}
+
+/// Pasifler eklenmeden önceki statlar: karakter bonusu ve profil güçlendirmeleri - oyuncuyla spawn olur
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq)]
+pub struct StartingStats(pub PlayerStats);
+
+/// Envanter değişince toplam statları yeniden hesapla
+fn update_player_stats(
+    passive_items: Res<PassiveItems>,
+    catalogues: Res<Assets<PassiveCatalogue>>,
+    mut players: Query<(&PassiveInventory, &StartingStats, &mut PlayerStats), Changed<PassiveInventory>>,
+) {
+    let Some(catalogue) = catalogues.get(&passive_items.catalogue) else {
+        return;
+    };
+    for (inventory, base, mut stats) in players.iter_mut() {
+        stats.set_if_neq(PlayerStats::from_inventory(base.0, inventory, catalogue));
+    }
+}
+
+/// Recovery: kesirli canı biriktirip tam sayı olarak ekle
+fn regenerate_health(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut players: Query<(&mut Player, &PlayerStats)>,
+    mut pending: Local<f32>,
+) {
+    for (mut player, stats) in players.iter_mut() {
+        if stats.recovery <= 0.0 || player.health == 0 || player.health >= player.max_health {
+            *pending = 0.0;
+            continue;
+        }
+        *pending += stats.recovery * time.delta_secs();
+        let heal = pending.floor();
+        if heal >= 1.0 {
+            *pending -= heal;
+            player.heal(heal as u32, &mut commands);
+        }
+    }
+}
@@ -0,0 +1,217 @@
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use crate::plugins::damage::DamageMeter;
+use crate::plugins::game_state::GameState;
+use crate::plugins::hud::format_survival_time;
+use crate::plugins::input_action::{ActionState, InputAction};
+use crate::plugins::localization::{Localized, UiText};
+use crate::plugins::main_menu::{button_hover_effect, spawn_button};
+use crate::plugins::passive_item::PassiveInventory;
+use crate::plugins::player::Player;
+use crate::plugins::replay::ReplaySession;
+use crate::plugins::settings::Settings;
+use crate::plugins::settings_menu::{open_settings_menu, settings_menu_open};
+use crate::plugins::spawn_director::SpawnDirector;
+use crate::plugins::weapon_definition::{WeaponDefinition, WeaponDefinitions};
+use crate::plugins::weapon_upgrade::WeaponLevel;
+use crate::plugins::weapons::Weapon;
+
+/// Pause eylemiyle (Escape / Start) açılan duraklatma menüsü: oyun mantığı (`gameplay_tick`) ve sanal zaman durur,
+/// o anki build ve DPS gösterilir
+pub struct PauseMenuPlugin;
+
+impl Plugin for PauseMenuPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, open_pause_menu.run_if(in_state(GameState::Playing)))
+            .add_systems(OnEnter(GameState::Paused), (pause_virtual_time, setup_pause_menu))
+            .add_systems(
+                Update,
+                (handle_pause_buttons, resume_on_escape, button_hover_effect::<PauseButton>)
+                    .run_if(in_state(GameState::Paused).and(not(settings_menu_open))),
+            )
+            // Ayarlardan dil değişirse özet de yeni dilde yazılsın
+            .add_systems(Update, refresh_pause_stats.run_if(in_state(GameState::Paused).and(resource_changed::<Settings>)))
+            .add_systems(OnExit(GameState::Paused), (resume_virtual_time, cleanup_pause_menu));
+    }
+}
+
+#[derive(Component)]
+struct PauseMenuUI;
+
+/// Build özeti satırları
+#[derive(Component)]
+pub struct PauseStats;
+
+#[derive(Component, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PauseButton {
+    Resume,
+    Settings,
+    Restart,
+    SaveAndQuit,
+    Quit,
+}
+
+/// Build özeti için oyuncu, silahlar ve hasar sayacı
+#[derive(SystemParam)]
+pub struct BuildStats<'w, 's> {
+    player: Query<'w, 's, (Entity, &'static Player, &'static PassiveInventory)>,
+    weapons: Query<'w, 's, (&'static WeaponLevel, &'static Weapon)>,
+    definitions: Res<'w, WeaponDefinitions>,
+    weapon_assets: Res<'w, Assets<WeaponDefinition>>,
+    meter: Res<'w, DamageMeter>,
+    director: Res<'w, SpawnDirector>,
+    settings: Res<'w, Settings>,
+}
+
+impl BuildStats<'_, '_> {
+    /// Süre ve seviye, silahlar (seviye + DPS), pasifler ve toplam DPS
+    pub fn lines(&self) -> Vec<String> {
+        let Ok((owner, player, inventory)) = self.player.single() else {
+            return Vec::new();
+        };
+        let text = |text: UiText| text.get(self.settings.language);
+        let elapsed = self.director.elapsed;
+        let mut lines = vec![
+            format!("{} {}   {} {}", text(UiText::Time), format_survival_time(elapsed), text(UiText::Level), player.level),
+            String::new(),
+            text(UiText::Weapons).to_string(),
+        ];
+
+        let mut owned: Vec<_> = self.weapons.iter().filter(|(_, weapon)| weapon.owner == owner).map(|(level, _)| level).collect();
+        owned.sort_by(|a, b| a.weapon_type.cmp(&b.weapon_type));
+        for level in owned {
+            let name = self
+                .definitions
+                .get(&level.weapon_type, &self.weapon_assets)
+                .map_or_else(|| level.weapon_type.to_string(), |definition| definition.name.clone());
+            lines.push(format!("  {:<16} Lv {:<2} {:>7.1} DPS", name, level.level, self.meter.dps(&level.weapon_type, elapsed)));
+        }
+
+        lines.push(String::new());
+        lines.push(text(UiText::Passives).to_string());
+        if inventory.items.is_empty() {
+            lines.push("  -".to_string());
+        }
+        for (kind, level) in &inventory.items {
+            lines.push(format!("  {:<16} Lv {}", kind.name(), level));
+        }
+
+        lines.push(String::new());
+        lines.push(format!("{}: {:.1}", text(UiText::TotalDps), self.meter.total_dps(elapsed)));
+        lines
+    }
+}
+
+/// Bekleyen bir geçiş varsa (level up, ölüm) onu ezmemek için duraklatma açılmaz
+fn open_pause_menu(actions: Res<ActionState>, mut next_state: ResMut<NextState<GameState>>) {
+    if actions.just_pressed(InputAction::Pause) && matches!(*next_state, NextState::Unchanged) {
+        next_state.set(GameState::Paused);
+    }
+}
+
+fn resume_on_escape(actions: Res<ActionState>, mut next_state: ResMut<NextState<GameState>>) {
+    if actions.just_pressed(InputAction::Pause) || actions.just_pressed(InputAction::Cancel) {
+        next_state.set(GameState::Playing);
+    }
+}
+
+fn pause_virtual_time(mut virtual_time: ResMut<Time<Virtual>>) {
+    virtual_time.pause();
+}
+
+fn resume_virtual_time(mut virtual_time: ResMut<Time<Virtual>>) {
+    virtual_time.unpause();
+}
+
+fn setup_pause_menu(mut commands: Commands, asset_server: Res<AssetServer>, stats: BuildStats) {
+    let font = asset_server.load("fonts/FiraMono-Medium.ttf");
+
+    commands.spawn((
+        PauseMenuUI,
+        Node {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            position_type: PositionType::Absolute,
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            column_gap: Val::Px(60.0),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.7)),
+        // HUD ve boss çubuğunun üstünde
+        GlobalZIndex(10),
+    )).with_children(|parent| {
+        parent.spawn((
+            PauseStats,
+            Text::new(stats.lines().join("\n")),
+            TextFont {
+                font: font.clone(),
+                font_size: 20.0,
+                ..default()
+            },
+        ));
+        parent.spawn(Node {
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Center,
+            ..default()
+        }).with_children(|column| {
+            column.spawn((
+                Localized(UiText::Paused),
+                TextFont {
+                    font: font.clone(),
+                    font_size: 50.0,
+                    ..default()
+                },
+                Node {
+                    margin: UiRect::bottom(Val::Px(30.0)),
+                    ..default()
+                },
+            ));
+            spawn_button(column, UiText::Resume, PauseButton::Resume, font.clone());
+            spawn_button(column, UiText::Settings, PauseButton::Settings, font.clone());
+            spawn_button(column, UiText::RestartRun, PauseButton::Restart, font.clone());
+            spawn_button(column, UiText::SaveAndQuit, PauseButton::SaveAndQuit, font.clone());
+            spawn_button(column, UiText::MainMenu, PauseButton::Quit, font.clone());
+        });
+    });
+}
+
+/// Restart ve ana menü, koşu temizliğini state geçişlerine bırakır:
+/// OnEnter(Loading) / OnEnter(MainMenu) GameEntity'leri siler ve kaynakları sıfırlar.
+/// Kaydet ve çık'ta koşuyu `run_save` yazar, dönüş normal çıkışla aynı
+fn handle_pause_buttons(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    interactions_q: Query<(&Interaction, &PauseButton), Changed<Interaction>>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut session: ResMut<ReplaySession>,
+    mut virtual_time: ResMut<Time<Virtual>>,
+) {
+    for (interaction, button) in &interactions_q {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        match button {
+            PauseButton::Resume => next_state.set(GameState::Playing),
+            PauseButton::Settings => open_settings_menu(&mut commands, &asset_server),
+            PauseButton::Restart | PauseButton::SaveAndQuit | PauseButton::Quit => {
+                // Yarıda bırakılan kaydın kalan girdileri yeni koşuya uymaz
+                session.stop_playback();
+                virtual_time.set_relative_speed(1.0);
+                next_state.set(if *button == PauseButton::Restart { GameState::Loading } else { GameState::MainMenu });
+            }
+        }
+    }
+}
+
+fn refresh_pause_stats(stats: BuildStats, mut texts: Query<&mut Text, With<PauseStats>>) {
+    for mut text in texts.iter_mut() {
+        text.0 = stats.lines().join("\n");
+    }
+}
+
+fn cleanup_pause_menu(mut commands: Commands, menu: Query<Entity, With<PauseMenuUI>>) {
+    for entity in &menu {
+        commands.entity(entity).despawn();
+    }
+}
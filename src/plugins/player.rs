@@ -1,98 +1,181 @@
 use bevy::audio::{AudioPlayer, PlaybackSettings};
-use bevy::prelude::{ButtonInput, Commands, Component, Entity, KeyCode, NextState, Query, Sprite, Time, Transform, With, Without};
+use bevy::ecs::query::QueryData;
+use bevy::prelude::{Commands, Component, Entity, Message, NextState, Query, Sprite, Time, Transform, Vec2, With, Without};
 use bevy_ecs::prelude::{MessageWriter, Res};
 use bevy_ecs::system::{ResMut, Single};
+use serde::{Deserialize, Serialize};
 use crate::plugins::aabb::AABB;
 use crate::plugins::audio::{GameAudio, GameAudioEntity};
-use crate::plugins::enemy::{Collectible, Enemy, XP};
+use crate::plugins::character::CharacterAnimation;
+use crate::plugins::enemy::{Collectible, XP};
 use crate::plugins::game_state::GameState;
+use crate::plugins::passive_item::PlayerStats;
+use crate::plugins::player_damage::PlayerDeath;
+use crate::plugins::replay::PlayerInput;
+use crate::plugins::spatial_grid::{SpatialGrid, SpatialLayer};
+use crate::plugins::status_effect::StatusEffects;
 use crate::plugins::timers::{MoveTimer};
 use crate::plugins::weapon_upgrade::LevelUpEvent;
 
-#[derive(Component)]
+/// Çekilen XP'nin saniyedeki hız artışı ve tavanı - atılan oyuncuya da yetişir
+const XP_ATTRACT_ACCELERATION: f32 = 1600.0;
+const XP_ATTRACT_MAX_SPEED: f32 = 1200.0;
+/// Durmaktan tam hıza çıkma süresi (sn)
+const ACCELERATION_TIME: f32 = 0.08;
+/// Girdi bırakılınca tam hızdan durma süresi (sn)
+const FRICTION_TIME: f32 = 0.12;
+const DASH_SPEED: f32 = 750.0;
+const DASH_DURATION: f32 = 0.15;
+const DASH_COOLDOWN: f32 = 1.5;
+/// Atılma başladıktan sonra hasar alınmayan süre
+const DASH_INVULNERABILITY: f32 = 0.25;
+/// Bu hızın altında yürüme animasyonu ilerlemez
+const ANIMATION_MIN_SPEED: f32 = 5.0;
+
+#[derive(Component, Clone, Serialize, Deserialize)]
+#[require(StatusEffects, PlayerMotion, CharacterAnimation)]
 pub struct Player {
     pub health: u32,
+    pub max_health: u32,
     pub score: u32,
+    pub gold: u32,
     pub movement: f32,
     pub xp: f32,
     pub level: i32,
     pub xp_to_next_level: f32,
+    /// Kalan dirilme hakkı
+    pub revivals: u32,
+    /// Seviye atlama seçeneklerini yeniden çekme hakkı
+    pub rerolls: u32,
 }
 
-impl Default for Player {
+/// Oyuncunun hızı ve atılma durumu - hareket girdiyi doğrudan konuma değil hıza uygular
+#[derive(Component, Clone, Debug, Serialize, Deserialize)]
+pub struct PlayerMotion {
+    pub velocity: Vec2,
+    /// Son hareket yönü; duran oyuncu bu yöne atılır
+    pub facing: Vec2,
+    dash_remaining: f32,
+    dash_cooldown: f32,
+    invulnerable: f32,
+}
+
+impl Default for PlayerMotion {
     fn default() -> Self {
-        Self { health: 100, score: 0, movement: 200., xp: 0., level: 1, xp_to_next_level: 100. }
+        // Başlangıç karesi (0) yukarı bakar
+        Self { velocity: Vec2::ZERO, facing: Vec2::Y, dash_remaining: 0.0, dash_cooldown: 0.0, invulnerable: 0.0 }
     }
 }
 
-impl Player {
-    pub fn move_around(
-        &self,
-        transform: &mut Transform,
-        aabb: &mut AABB,
-        sprite: &mut Sprite,
-        camera_transform: &mut Transform,
-        keyboard_input: &ButtonInput<KeyCode>,
-        time: &Time,
-        move_timer: &MoveTimer,
-    ) {
-        let mut pos = transform.translation;
-
-
-        let mut dir= 5;
-
-        if keyboard_input.pressed(KeyCode::KeyA) {
-            pos.x -= self.movement * time.delta_secs();
-            dir = -1;
+impl PlayerMotion {
+    pub fn is_dashing(&self) -> bool {
+        self.dash_remaining > 0.0
+    }
+
+    pub fn is_invulnerable(&self) -> bool {
+        self.invulnerable > 0.0
+    }
+
+    pub fn dash_ready(&self) -> bool {
+        self.dash_cooldown <= 0.0
+    }
+
+    /// Kalan dokunulmazlık `secs`'ten kısaysa uzat
+    pub fn make_invulnerable(&mut self, secs: f32) {
+        self.invulnerable = self.invulnerable.max(secs);
+    }
+
+    /// Atılma sırasında itilmez
+    pub fn knock_back(&mut self, velocity: Vec2) {
+        if !self.is_dashing() {
+            self.velocity = velocity;
         }
-        if keyboard_input.pressed(KeyCode::KeyD) {
-            pos.x += self.movement * time.delta_secs();
-            dir = 1;
+    }
+
+    /// Hıza göre; girdi yokken hızın kendisi sönümlenir
+    fn update(&mut self, input: &PlayerInput, max_speed: f32, can_move: bool, dt: f32) {
+        let direction = input.movement();
+        if direction != Vec2::ZERO {
+            self.facing = direction.normalize();
         }
-        if keyboard_input.pressed(KeyCode::KeyW) {
-            pos.y += self.movement * time.delta_secs();
-            dir = 2;
+        self.dash_cooldown = (self.dash_cooldown - dt).max(0.0);
+        self.invulnerable = (self.invulnerable - dt).max(0.0);
+
+        if input.dash && self.dash_ready() && can_move {
+            self.dash_remaining = DASH_DURATION;
+            self.dash_cooldown = DASH_COOLDOWN;
+            self.invulnerable = DASH_INVULNERABILITY;
+            self.velocity = self.facing * DASH_SPEED;
         }
-        if keyboard_input.pressed(KeyCode::KeyS) {
-            pos.y -= self.movement * time.delta_secs();
-            dir = 0
+        if self.is_dashing() {
+            self.dash_remaining = (self.dash_remaining - dt).max(0.0);
+            if !can_move {
+                self.velocity = Vec2::ZERO;
+            }
+            return;
         }
-        if let Some(ref mut atlas) = sprite.texture_atlas {
-            if move_timer.timer.just_finished() {
-                if dir == -1 {
-                    atlas.index = 9 + (atlas.index + 1) % 9;
-                } else if dir == 1 {
-                    atlas.index = 27 + (atlas.index + 1) % 9;
-                } else if dir == 2 {
-                    atlas.index = 0 + (atlas.index + 1) % 9;
-                } else if dir == 0 {
-                    atlas.index = 18 + (atlas.index + 1) % 9;
-                }
 
-            }
+        // Analogda hedef hız çubuğun büyüklüğüyle orantılı
+        let target = if can_move { direction * max_speed } else { Vec2::ZERO };
+        let rate = if target == Vec2::ZERO { FRICTION_TIME } else { ACCELERATION_TIME };
+        // Atılma bitince fazla hız da sürtünmeyle söner
+        let step = self.velocity.length().max(max_speed) / rate * dt;
+        self.velocity = self.velocity.move_towards(target, step);
+    }
+}
+
+/// Hareket eden oyuncu: hızı, konumu, sprite'ı ve hızını belirleyen statlar
+#[derive(QueryData)]
+#[query_data(mutable)]
+pub struct MovingPlayer {
+    player: &'static Player,
+    motion: &'static mut PlayerMotion,
+    transform: &'static mut Transform,
+    aabb: &'static mut AABB,
+    pub sprite: &'static mut Sprite,
+    stats: &'static PlayerStats,
+    effects: &'static StatusEffects,
+    animation: &'static CharacterAnimation,
+}
+
+impl MovingPlayerItem<'_, '_> {
+    pub fn move_around(&mut self, camera_transform: &mut Transform, input: &PlayerInput, time: &Time, move_timer: &MoveTimer) {
+        let max_speed = self.player.movement * self.stats.move_speed * self.effects.speed_multiplier();
+        self.motion.update(input, max_speed, !self.effects.is_frozen(), time.delta_secs());
+        let pos = self.transform.translation + (self.motion.velocity * time.delta_secs()).extend(0.0);
+
+        // Kare hızdan seçilir; karşılıklı tuşlar ya da kayma animasyonu bozmaz
+        let moving = self.motion.velocity.length() > ANIMATION_MIN_SPEED;
+        let direction = if moving { self.motion.velocity } else { self.motion.facing };
+        if let Some(ref mut atlas) = self.sprite.texture_atlas {
+            atlas.index = self.animation.next_frame(atlas.index, direction, moving, move_timer.timer.just_finished());
         }
-        transform.translation = pos;
-        aabb.change_point(pos);
+        self.sprite.flip_x = self.animation.flip_x(direction, self.sprite.flip_x);
+        self.transform.translation = pos;
+        self.aabb.change_point(pos);
         camera_transform.translation = pos;
     }
+}
 
-    pub fn take_damage(
-        &mut self,
-        entity: Entity,
-        commands: &mut Commands,
-        enemy_query: Query<(&AABB, &Enemy), (With<Enemy>, Without<Player>)>,
-        player_aabb: &AABB,
-    ) {
-        for (enemy_aabb, enemy) in enemy_query.iter() {
-            if self.health > 0 && enemy_aabb.self_aabb_intersects(player_aabb) {
-                if self.health > 0 {
-                    self.health = self.health.saturating_sub(enemy.damage as u32);
-                }
-                println!("{:?}", self.health);
-            }
-        }
-        if self.health == 0 {
-            commands.entity(entity).despawn();
+/// Oyuncu can kazandığında gönderilir (iyileşme sayıları için)
+#[derive(Message, Clone, Copy, Debug)]
+pub struct PlayerHealedEvent {
+    pub amount: u32,
+}
+
+impl Default for Player {
+    fn default() -> Self {
+        Self { health: 100, max_health: 100, score: 0, gold: 0, movement: 200., xp: 0., level: 1, xp_to_next_level: 100., revivals: 0, rerolls: 0 }
+    }
+}
+
+impl Player {
+    /// Canı en fazla `max_health`'e kadar artır, gerçekten kazanılan canı bildir
+    pub fn heal(&mut self, amount: u32, commands: &mut Commands) {
+        let healed = amount.min(self.max_health.saturating_sub(self.health));
+        if healed > 0 {
+            self.health += healed;
+            commands.write_message(PlayerHealedEvent { amount: healed });
         }
     }
 
@@ -117,49 +200,67 @@ impl Player {
 }
 
 pub fn collect_xp(
-    mut player_query: Query<(&mut Player, &AABB), With<Player>>,
+    // Ölürken seviye atlayıp yükseltme ekranı açılmasın
+    mut player_query: Query<(&mut Player, &AABB, &PlayerStats), Without<PlayerDeath>>,
     mut xp_query: Query<(&AABB, &Collectible, &XP, Entity)>,
+    grid: Res<SpatialGrid>,
     mut commands: Commands,
     mut level_up_events: MessageWriter<LevelUpEvent>,
     mut next_state: ResMut<NextState<GameState>>,
     audio: Res<GameAudio>,
 ){
-    for (mut player, player_aabb) in player_query.iter_mut(){
-        for (xp_aabb, _collectible, xp, entity) in xp_query.iter_mut(){
+    for (mut player, player_aabb, stats) in player_query.iter_mut(){
+        for candidate in grid.query_aabb(player_aabb.min(), player_aabb.max(), SpatialLayer::Collectible) {
+            let Ok((xp_aabb, _collectible, xp, entity)) = xp_query.get_mut(candidate.entity) else {
+                continue;
+            };
             if xp_aabb.self_aabb_intersects(player_aabb) {
-                player.gain_xp(xp.amount as f32, &mut level_up_events, &mut next_state, &mut commands, &audio);
+                player.gain_xp(xp.amount as f32 * stats.growth, &mut level_up_events, &mut next_state, &mut commands, &audio);
                 commands.entity(entity).despawn();
             }
         }
     }
 }
 
-#[derive(Component)]
-pub struct XPMagnetite;
+/// Oyuncuya çekilen XP - durarak başlar, yaklaştıkça hızlanır
+#[derive(Component, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct XPMagnetite {
+    pub speed: f32,
+}
 
-pub fn collect_xp_with_magnet(
+/// Toplama yarıçapına (Magnet pasifiyle büyür) giren XP'ler oyuncuya çekilir
+pub fn attract_xp_in_pickup_range(
     mut commands: Commands,
-    mut xp_query: Query<Entity, With<XP>>,
-    keyboard_input: Res<ButtonInput<KeyCode>>,
+    player_query: Query<(&Transform, &PlayerStats), With<Player>>,
+    xp_query: Query<(), (With<XP>, Without<XPMagnetite>)>,
+    grid: Res<SpatialGrid>,
 ){
-    if keyboard_input.just_pressed(KeyCode::KeyC){
-        for entity in xp_query {
-            commands.entity(entity).insert(XPMagnetite);
+    let Ok((player_transform, stats)) = player_query.single() else {
+        return;
+    };
+    for candidate in grid.query_radius(player_transform.translation.truncate(), stats.pickup_radius, SpatialLayer::Collectible) {
+        if xp_query.contains(candidate.entity) {
+            // Bu tick içinde toplanmış olabilir
+            commands.entity(candidate.entity).try_insert(XPMagnetite::default());
         }
     }
 }
 
+/// Çekilen XP'ler her saniye `XP_ATTRACT_ACCELERATION` kadar hızlanır; oyuncuyu geçmez
 pub fn magnetite_xp_to_player(
-    mut xp_query: Query<(&mut Transform, &mut AABB), (With<XPMagnetite>, Without<Player>)>,
+    time: Res<Time>,
+    mut xp_query: Query<(&mut Transform, &mut AABB, &mut XPMagnetite), Without<Player>>,
     player_query: Query<&Transform, (With<Player>, Without<XPMagnetite>)>,
 ){
     let Ok(player_position) = player_query.single() else{
         return;
     };
 
-    for (mut xp_transform, mut xp_aabb) in xp_query.iter_mut(){
-        let direction = (player_position.translation - xp_transform.translation).normalize();
-        xp_transform.translation += direction * 5.;
+    for (mut xp_transform, mut xp_aabb, mut magnetite) in xp_query.iter_mut(){
+        magnetite.speed = (magnetite.speed + XP_ATTRACT_ACCELERATION * time.delta_secs()).min(XP_ATTRACT_MAX_SPEED);
+        let offset = (player_position.translation - xp_transform.translation).with_z(0.0);
+        let step = (magnetite.speed * time.delta_secs()).min(offset.length());
+        xp_transform.translation += offset.normalize_or_zero() * step;
         xp_aabb.change_point(xp_transform.translation);
     }
 }
\ No newline at end of file
@@ -0,0 +1,157 @@
+use bevy::audio::{AudioPlayer, PlaybackSettings};
+use bevy::prelude::*;
+use crate::plugins::audio::GameAudio;
+use crate::plugins::game_state::GameState;
+use crate::plugins::passive_item::PlayerStats;
+use crate::plugins::player::{Player, PlayerMotion};
+use crate::plugins::screen_shake::ScreenShake;
+use crate::plugins::status_effect::StatusEffects;
+
+/// Doğrudan isabetten sonra hasar alınmayan süre
+pub const HIT_INVULNERABILITY: f32 = 0.6;
+/// Saldırandan uzağa itilme hızı - sürtünmeyle söner
+const KNOCKBACK_SPEED: f32 = 380.0;
+const PLAYER_HIT_TRAUMA: f32 = 0.35;
+/// Dokunulmazken saniyedeki yanıp sönme sayısı
+const INVULNERABLE_FLASH_HZ: f32 = 12.0;
+const INVULNERABLE_ALPHA: f32 = 0.3;
+pub const DEATH_SEQUENCE_SECS: f32 = 1.2;
+/// Ölürken dönüş hızı (radyan/sn)
+const DEATH_SPIN_SPEED: f32 = 8.0;
+/// Kalın ses dosyası olmadığı için isabet sesi yavaş çalınır
+const HURT_SOUND_SPEED: f32 = 0.6;
+/// Dirilince geri gelen can oranı
+const REVIVAL_HEALTH: f32 = 0.5;
+/// Dirilince etraftaki düşmanlardan kaçmak için dokunulmazlık
+const REVIVAL_INVULNERABILITY: f32 = 2.0;
+
+/// Oyuncuya gelen tüm hasar `PlayerDamageEvent` olarak yazılır, can sadece `apply_player_damage`'de düşer.
+/// Can bitince oyuncu ölüm animasyonunu oynatır, `GameOver` ondan sonra gelir.
+pub struct PlayerDamagePlugin;
+
+impl Plugin for PlayerDamagePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_message::<PlayerDamageEvent>()
+            .add_message::<PlayerHurtEvent>()
+            .add_systems(
+                Update,
+                (flash_invulnerable_player, shake_on_player_hurt, play_hurt_sound).run_if(in_state(GameState::Playing)),
+            );
+    }
+}
+
+/// Oyuncuya verilecek ham hasar - armor ve durum çarpanları `apply_player_damage`'de uygulanır
+#[derive(Message, Clone, Copy, Debug)]
+pub struct PlayerDamageEvent {
+    pub amount: u32,
+    /// Saldıranın konumu; oyuncu buradan uzağa itilir. `None` ise itme yok
+    pub source: Option<Vec2>,
+    /// Süreli hasar: armor'a ve dokunulmazlığa takılmaz, dokunulmazlık da vermez
+    pub over_time: bool,
+}
+
+/// Oyuncu gerçekten hasar aldı (geri bildirim için)
+#[derive(Message, Clone, Copy, Debug)]
+pub struct PlayerHurtEvent {
+    pub amount: u32,
+    pub position: Vec3,
+    pub over_time: bool,
+}
+
+/// Canı biten oyuncu; animasyon bitince `GameOver`
+#[derive(Component, Debug)]
+pub struct PlayerDeath {
+    pub timer: Timer,
+}
+
+/// Doğrudan isabetlerden tick başına en fazla biri işlenir: ilki dokunulmazlık başlatır
+pub fn apply_player_damage(
+    mut commands: Commands,
+    mut events: MessageReader<PlayerDamageEvent>,
+    mut hurt_events: MessageWriter<PlayerHurtEvent>,
+    mut player_query: Query<(Entity, &mut Player, &mut PlayerMotion, &Transform), Without<PlayerDeath>>,
+    modifiers: Query<(&PlayerStats, &StatusEffects)>,
+) {
+    let Ok((entity, mut player, mut motion, transform)) = player_query.single_mut() else {
+        events.clear();
+        return;
+    };
+    let Ok((stats, effects)) = modifiers.get(entity) else {
+        return;
+    };
+
+    for event in events.read() {
+        if player.health == 0 || (!event.over_time && motion.is_invulnerable()) {
+            continue;
+        }
+        let amount = if event.over_time {
+            effects.scale_damage(event.amount)
+        } else {
+            effects.scale_damage(stats.reduce_damage(event.amount))
+        };
+        player.health = player.health.saturating_sub(amount);
+        hurt_events.write(PlayerHurtEvent { amount, position: transform.translation, over_time: event.over_time });
+
+        if !event.over_time {
+            motion.make_invulnerable(HIT_INVULNERABILITY);
+            if let Some(source) = event.source {
+                let away = (transform.translation.truncate() - source).normalize_or_zero();
+                motion.knock_back(away * KNOCKBACK_SPEED);
+            }
+        }
+        if player.health == 0 && player.revivals > 0 {
+            player.revivals -= 1;
+            let health = (player.max_health as f32 * REVIVAL_HEALTH) as u32;
+            player.heal(health, &mut commands);
+            motion.make_invulnerable(REVIVAL_INVULNERABILITY);
+            println!("✨ Player revived ({} revival(s) left)", player.revivals);
+            break;
+        }
+        if player.health == 0 {
+            println!("💀 Player died");
+            commands.entity(entity).insert(PlayerDeath { timer: Timer::from_seconds(DEATH_SEQUENCE_SECS, TimerMode::Once) });
+            break;
+        }
+    }
+}
+
+/// Dönerek küçülüp solar, bitince oyun biter
+pub fn play_death_sequence(
+    time: Res<Time>,
+    mut player_query: Query<(&mut PlayerDeath, &mut Transform, &mut Sprite)>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    for (mut death, mut transform, mut sprite) in player_query.iter_mut() {
+        death.timer.tick(time.delta());
+        let remaining = 1.0 - death.timer.fraction();
+        transform.rotate_z(DEATH_SPIN_SPEED * time.delta_secs());
+        transform.scale = Vec3::splat(remaining.max(0.05));
+        sprite.color = sprite.color.with_alpha(remaining);
+        if death.timer.just_finished() {
+            next_state.set(GameState::GameOver);
+        }
+    }
+}
+
+/// Dokunulmazlık süresince yanıp söner - sadece saydamlık değişir, durum rengi kalır
+fn flash_invulnerable_player(time: Res<Time>, mut player_query: Query<(&PlayerMotion, &mut Sprite), Without<PlayerDeath>>) {
+    for (motion, mut sprite) in player_query.iter_mut() {
+        let visible = !motion.is_invulnerable() || ((time.elapsed_secs() * INVULNERABLE_FLASH_HZ) as u32).is_multiple_of(2);
+        let alpha = if visible { 1.0 } else { INVULNERABLE_ALPHA };
+        if sprite.color.alpha() != alpha {
+            sprite.color.set_alpha(alpha);
+        }
+    }
+}
+
+fn shake_on_player_hurt(mut hurt_events: MessageReader<PlayerHurtEvent>, mut shake: ResMut<ScreenShake>) {
+    if hurt_events.read().any(|hurt| !hurt.over_time) {
+        shake.add_trauma(PLAYER_HIT_TRAUMA);
+    }
+}
+
+fn play_hurt_sound(mut commands: Commands, mut hurt_events: MessageReader<PlayerHurtEvent>, audio: Res<GameAudio>) {
+    if hurt_events.read().any(|hurt| !hurt.over_time) {
+        commands.spawn((AudioPlayer(audio.player_hurt.clone()), PlaybackSettings::DESPAWN.with_speed(HURT_SOUND_SPEED)));
+    }
+}
@@ -0,0 +1,216 @@
+use bevy::prelude::*;
+use bevy::ui::FocusPolicy;
+use crate::plugins::game_state::GameState;
+use crate::plugins::input_action::{ActionState, InputAction};
+use crate::plugins::localization::{Localized, UiText};
+use crate::plugins::main_menu::{button_hover_effect, spawn_button};
+use crate::plugins::profile::{PowerUpKind, Profile, ProfileFile};
+use crate::plugins::settings::Settings;
+use crate::plugins::settings_menu::spawn_row;
+use crate::plugins::weapon_definition::{WeaponDefinition, WeaponDefinitions};
+use crate::plugins::weapon_evolution::EvolutionRules;
+
+/// Ana menüde açılan güçlendirme dükkanı: koşulardan biriken altınla kalıcı güçlendirme alınır.
+/// Satıra tıklamak bir seviye alır; başlangıç silahı satırı kilit açıldıysa sıradaki silaha geçer.
+/// Ekran kapanınca profil dosyaya yazılır.
+pub struct PowerUpShopPlugin;
+
+impl Plugin for PowerUpShopPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                handle_shop_buttons,
+                close_shop_on_escape,
+                button_hover_effect::<ShopButton>,
+                update_gold_text,
+                update_power_up_values,
+                update_starting_weapon_value,
+            )
+                .run_if(power_up_shop_open),
+        )
+        .add_systems(OnExit(GameState::MainMenu), close_power_up_shop);
+    }
+}
+
+#[derive(Component)]
+pub struct PowerUpShopUI;
+
+#[derive(Component, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ShopButton {
+    Buy(PowerUpKind),
+    StartingWeapon,
+    Back,
+}
+
+/// Güçlendirmenin seviyesini ve fiyatını gösteren yazı
+#[derive(Component)]
+struct PowerUpValue(PowerUpKind);
+
+/// Seçili başlangıç silahını gösteren yazı
+#[derive(Component)]
+struct StartingWeaponValue;
+
+#[derive(Component)]
+struct GoldText;
+
+/// Dükkan açıkken ana menü girdi almaz
+pub fn power_up_shop_open(shop: Query<(), With<PowerUpShopUI>>) -> bool {
+    !shop.is_empty()
+}
+
+pub fn open_power_up_shop(commands: &mut Commands, asset_server: &AssetServer) {
+    let font = asset_server.load("fonts/FiraMono-Medium.ttf");
+
+    commands.spawn((
+        PowerUpShopUI,
+        Node {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            position_type: PositionType::Absolute,
+            flex_direction: FlexDirection::Column,
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            ..default()
+        },
+        BackgroundColor(Color::srgb(0.1, 0.1, 0.1)),
+        // Alttaki menünün düğmeleri tıklanmasın
+        FocusPolicy::Block,
+        GlobalZIndex(20),
+    )).with_children(|parent| {
+        parent.spawn((
+            Localized(UiText::PowerUps),
+            TextFont {
+                font: font.clone(),
+                font_size: 50.0,
+                ..default()
+            },
+        ));
+        parent.spawn((
+            GoldText,
+            Text::default(),
+            TextFont {
+                font: font.clone(),
+                font_size: 28.0,
+                ..default()
+            },
+            TextColor(Color::srgb(1.0, 0.85, 0.2)),
+            Node {
+                margin: UiRect::vertical(Val::Px(20.0)),
+                ..default()
+            },
+        ));
+        parent.spawn(Node {
+            flex_direction: FlexDirection::Column,
+            margin: UiRect::bottom(Val::Px(20.0)),
+            ..default()
+        }).with_children(|column| {
+            for kind in PowerUpKind::ALL {
+                spawn_row(column, kind.label(), ShopButton::Buy(kind), PowerUpValue(kind), &font);
+            }
+            spawn_row(column, UiText::StartingWeapon, ShopButton::StartingWeapon, StartingWeaponValue, &font);
+        });
+        spawn_button(parent, UiText::Back, ShopButton::Back, font.clone());
+    });
+}
+
+fn handle_shop_buttons(
+    mut commands: Commands,
+    interactions_q: Query<(&Interaction, &ShopButton), Changed<Interaction>>,
+    mut profile: ResMut<Profile>,
+    shop: Query<Entity, With<PowerUpShopUI>>,
+    file: Res<ProfileFile>,
+    rules: EvolutionRules,
+) {
+    for (interaction, button) in &interactions_q {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        match button {
+            ShopButton::Buy(kind) => {
+                if profile.buy(*kind) {
+                    println!("🛒 {:?} bought, level {}", kind, profile.level(*kind));
+                    // Kilit açılınca ilk silah seçili gelir
+                    if *kind == PowerUpKind::StartingWeapon && profile.starting_weapon.is_none() {
+                        profile.cycle_starting_weapon(&rules.starting_choices());
+                    }
+                }
+            }
+            ShopButton::StartingWeapon => profile.cycle_starting_weapon(&rules.starting_choices()),
+            ShopButton::Back => save_and_close(&mut commands, &shop, &profile, &file),
+        }
+    }
+}
+
+fn close_shop_on_escape(
+    mut commands: Commands,
+    actions: Res<ActionState>,
+    profile: Res<Profile>,
+    shop: Query<Entity, With<PowerUpShopUI>>,
+    file: Res<ProfileFile>,
+) {
+    if actions.just_pressed(InputAction::Cancel) {
+        save_and_close(&mut commands, &shop, &profile, &file);
+    }
+}
+
+/// Dükkan açıkken state değişirse kapat ve kaydet
+fn close_power_up_shop(
+    mut commands: Commands,
+    profile: Res<Profile>,
+    shop: Query<Entity, With<PowerUpShopUI>>,
+    file: Res<ProfileFile>,
+) {
+    save_and_close(&mut commands, &shop, &profile, &file);
+}
+
+fn save_and_close(commands: &mut Commands, shop: &Query<Entity, With<PowerUpShopUI>>, profile: &Profile, file: &ProfileFile) {
+    if shop.is_empty() {
+        return;
+    }
+    for entity in shop {
+        commands.entity(entity).despawn();
+    }
+    profile.save_to(file);
+}
+
+fn update_gold_text(profile: Res<Profile>, settings: Res<Settings>, mut texts: Query<(Ref<GoldText>, &mut Text)>) {
+    for (marker, mut text) in texts.iter_mut() {
+        if profile.is_changed() || settings.is_changed() || marker.is_added() {
+            text.0 = format!("{}: {}", UiText::Gold.get(settings.language), profile.gold);
+        }
+    }
+}
+
+/// Seviye/maks ve sonraki seviyenin fiyatı
+fn update_power_up_values(profile: Res<Profile>, settings: Res<Settings>, mut values: Query<(Ref<PowerUpValue>, &mut Text)>) {
+    for (value, mut text) in values.iter_mut() {
+        if !(profile.is_changed() || settings.is_changed() || value.is_added()) {
+            continue;
+        }
+        let kind = value.0;
+        let level = format!("{}/{}", profile.level(kind), kind.max_level());
+        text.0 = match profile.next_cost(kind) {
+            Some(cost) => format!("{} - {} {}", level, cost, UiText::Gold.get(settings.language)),
+            None => format!("{} - MAX", level),
+        };
+    }
+}
+
+fn update_starting_weapon_value(
+    profile: Res<Profile>,
+    settings: Res<Settings>,
+    definitions: Res<WeaponDefinitions>,
+    weapon_assets: Res<Assets<WeaponDefinition>>,
+    mut values: Query<(Ref<StartingWeaponValue>, &mut Text)>,
+) {
+    for (value, mut text) in values.iter_mut() {
+        if !(profile.is_changed() || settings.is_changed() || value.is_added()) {
+            continue;
+        }
+        text.0 = match profile.loadout().starting_weapon {
+            Some(weapon) => definitions.get(&weapon, &weapon_assets).map_or_else(|| weapon.to_string(), |definition| definition.name.clone()),
+            None => UiText::Locked.get(settings.language).to_string(),
+        };
+    }
+}
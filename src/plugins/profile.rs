@@ -0,0 +1,424 @@
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use bevy::prelude::*;
+use serde::{Deserialize, Deserializer, Serialize};
+use thiserror::Error;
+use crate::plugins::character::{CharacterId, SelectedCharacter};
+use crate::plugins::game_state::GameState;
+use crate::plugins::localization::UiText;
+use crate::plugins::passive_item::PlayerStats;
+use crate::plugins::player::Player;
+use crate::plugins::replay::ReplaySession;
+use crate::plugins::run_save::PendingRun;
+use crate::plugins::settings::{config_dir, CONFIG_DIR_NAME};
+use crate::plugins::spawn_director::SpawnDirector;
+use crate::plugins::weapon_definition::WeaponId;
+
+const PROFILE_FILE_NAME: &str = "profile.ron";
+/// Format değişince artır ve eski sürümün dönüşümünü `migrated`'e ekle
+pub const PROFILE_VERSION: u32 = 2;
+const MAX_HEALTH_PER_LEVEL: u32 = 10;
+const MIGHT_PER_LEVEL: f32 = 0.05;
+const MOVE_SPEED_PER_LEVEL: f32 = 0.05;
+
+/// Koşular arasında kalan ilerleme: biriken altın, shop'tan alınan kalıcı güçlendirmeler ve karakter kilitlerini açan rekorlar.
+/// Koşu başında profilden bir `Loadout` çıkarılır, oyuncu onunla spawn olur
+pub struct ProfilePlugin;
+
+impl Plugin for ProfilePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ProfileFile>();
+        let profile = Profile::from_file(app.world().resource::<ProfileFile>());
+        app.insert_resource(profile)
+            .init_resource::<RunLoadout>()
+            .add_systems(OnEnter(GameState::Loading), choose_run_loadout);
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum ProfileError {
+    #[error("could not access profile file: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("could not parse profile: {0}")]
+    Parse(#[from] ron::error::SpannedError),
+    #[error("could not write profile: {0}")]
+    Write(#[from] ron::Error),
+    #[error("profile version {0} is newer than this game ({PROFILE_VERSION}), not overwriting it")]
+    Newer(u32),
+}
+
+/// Profilin okunup yazıldığı dosya; `None` ise profil sadece bellekte tutulur
+#[derive(Resource, Debug)]
+pub struct ProfileFile {
+    pub path: Option<PathBuf>,
+}
+
+impl Default for ProfileFile {
+    fn default() -> Self {
+        Self { path: config_dir().map(|dir| dir.join(CONFIG_DIR_NAME).join(PROFILE_FILE_NAME)) }
+    }
+}
+
+/// Shop'ta satılan kalıcı güçlendirmeler
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PowerUpKind {
+    MaxHealth,
+    Might,
+    MoveSpeed,
+    /// Ölünce bir kez yarım canla dirilir
+    Revival,
+    /// Seviye atlama seçeneklerini yeniden çekme hakkı
+    Reroll,
+    /// Başlangıç silahını seçme kilidi
+    StartingWeapon,
+}
+
+impl PowerUpKind {
+    pub const ALL: [PowerUpKind; 6] = [
+        PowerUpKind::MaxHealth,
+        PowerUpKind::Might,
+        PowerUpKind::MoveSpeed,
+        PowerUpKind::Revival,
+        PowerUpKind::Reroll,
+        PowerUpKind::StartingWeapon,
+    ];
+
+    pub fn max_level(self) -> u32 {
+        match self {
+            PowerUpKind::MaxHealth | PowerUpKind::Might => 5,
+            PowerUpKind::MoveSpeed | PowerUpKind::Reroll => 3,
+            PowerUpKind::Revival | PowerUpKind::StartingWeapon => 1,
+        }
+    }
+
+    fn base_cost(self) -> u32 {
+        match self {
+            PowerUpKind::MaxHealth => 20,
+            PowerUpKind::Might => 30,
+            PowerUpKind::MoveSpeed => 25,
+            PowerUpKind::Revival => 150,
+            PowerUpKind::Reroll => 40,
+            PowerUpKind::StartingWeapon => 60,
+        }
+    }
+
+    /// `level`'den bir sonrakine çıkmanın fiyatı - her seviye bir öncekinden pahalı
+    pub fn cost(self, level: u32) -> u32 {
+        self.base_cost() * (level + 1)
+    }
+
+    pub fn label(self) -> UiText {
+        match self {
+            PowerUpKind::MaxHealth => UiText::MaxHealth,
+            PowerUpKind::Might => UiText::Might,
+            PowerUpKind::MoveSpeed => UiText::MoveSpeed,
+            PowerUpKind::Revival => UiText::Revival,
+            PowerUpKind::Reroll => UiText::Reroll,
+            PowerUpKind::StartingWeapon => UiText::WeaponChoice,
+        }
+    }
+}
+
+/// Alınan seviyeler - isimli alanlar: yeni sürümün eklediği güçlendirmeler eski oyunda yok sayılır
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PowerUpLevels {
+    pub max_health: u32,
+    pub might: u32,
+    pub move_speed: u32,
+    pub revival: u32,
+    pub reroll: u32,
+    pub starting_weapon: u32,
+}
+
+impl PowerUpLevels {
+    pub fn get(&self, kind: PowerUpKind) -> u32 {
+        match kind {
+            PowerUpKind::MaxHealth => self.max_health,
+            PowerUpKind::Might => self.might,
+            PowerUpKind::MoveSpeed => self.move_speed,
+            PowerUpKind::Revival => self.revival,
+            PowerUpKind::Reroll => self.reroll,
+            PowerUpKind::StartingWeapon => self.starting_weapon,
+        }
+    }
+
+    fn get_mut(&mut self, kind: PowerUpKind) -> &mut u32 {
+        match kind {
+            PowerUpKind::MaxHealth => &mut self.max_health,
+            PowerUpKind::Might => &mut self.might,
+            PowerUpKind::MoveSpeed => &mut self.move_speed,
+            PowerUpKind::Revival => &mut self.revival,
+            PowerUpKind::Reroll => &mut self.reroll,
+            PowerUpKind::StartingWeapon => &mut self.starting_weapon,
+        }
+    }
+}
+
+#[derive(Resource, Clone, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Profile {
+    /// Sürümsüz (elle yazılmış ya da ilk) dosyalar 0 okunur
+    #[serde(default)]
+    pub version: u32,
+    pub gold: u32,
+    pub power_ups: PowerUpLevels,
+    /// Shop'ta seçilen başlangıç silahı - kilit açılmadıysa ya da tanımı yoksa kullanılmaz
+    #[serde(deserialize_with = "known_weapon")]
+    pub starting_weapon: Option<WeaponId>,
+    pub records: RunRecords,
+}
+
+impl Default for Profile {
+    fn default() -> Self {
+        Self { version: PROFILE_VERSION, gold: 0, power_ups: PowerUpLevels::default(), starting_weapon: None, records: RunRecords::default() }
+    }
+}
+
+/// Koşulardaki en iyi sonuçlar - karakterlerin kilidi bunlarla açılır
+#[derive(Clone, Copy, PartialEq, Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RunRecords {
+    /// Ulaşılan en yüksek seviye
+    pub level: i32,
+    /// En uzun hayatta kalma süresi (sn)
+    pub survived: f32,
+}
+
+impl RunRecords {
+    /// İki rekorun her alanda iyisi
+    pub fn best(self, other: RunRecords) -> Self {
+        Self { level: self.level.max(other.level), survived: self.survived.max(other.survived) }
+    }
+}
+
+impl Profile {
+    /// Başlangıçta: dosya yoksa ya da okunamazsa boş profil
+    pub fn from_file(file: &ProfileFile) -> Self {
+        let Some(path) = &file.path else {
+            return Self::default();
+        };
+        match Self::load(path) {
+            Ok(profile) => {
+                println!("🪙 Profile loaded: {} ({} gold)", path.display(), profile.gold);
+                if profile.version > PROFILE_VERSION {
+                    warn!("{}: {}", path.display(), ProfileError::Newer(profile.version));
+                }
+                profile
+            }
+            // İlk açılış
+            Err(ProfileError::Io(err)) if err.kind() == std::io::ErrorKind::NotFound => Self::default(),
+            // Boş profil kaydedilince altın kaybolmasın: bozuk dosya yedeklenir
+            Err(err) => {
+                let backup = path.with_extension("ron.bak");
+                match fs::rename(path, &backup) {
+                    Ok(()) => warn!("{}: {} - moved to {}, starting with an empty profile", path.display(), err, backup.display()),
+                    Err(rename_err) => warn!("{}: {} - starting with an empty profile ({})", path.display(), err, rename_err),
+                }
+                Self::default()
+            }
+        }
+    }
+
+    /// Daha yeni sürümün dosyası da okunur: bilinmeyen alanlar atlanır, sürüm korunur
+    pub fn load(path: &Path) -> Result<Self, ProfileError> {
+        let profile: Profile = ron::from_str(&fs::read_to_string(path)?)?;
+        Ok(profile.migrated().sanitized())
+    }
+
+    /// Daha yeni sürümün dosyası üzerine yazılmaz - o sürümün alanları kaybolurdu
+    pub fn save(&self, path: &Path) -> Result<(), ProfileError> {
+        if self.version > PROFILE_VERSION {
+            return Err(ProfileError::Newer(self.version));
+        }
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())?)?;
+        Ok(())
+    }
+
+    /// Eski sürümleri güncel sürüme taşı
+    fn migrated(mut self) -> Self {
+        // 0 -> 1: sürüm alanı eklendi, alanlar aynı
+        // 1 -> 2: silah enum adı yerine tanım id'siyle yazılıyor; eski seçim okunmaz, shop'ta yeniden seçilir
+        if self.version < PROFILE_VERSION {
+            self.version = PROFILE_VERSION;
+        }
+        self
+    }
+
+    /// Elle düzenlenmiş dosyadaki aralık dışı seviyeleri düzelt
+    fn sanitized(mut self) -> Self {
+        for kind in PowerUpKind::ALL {
+            let level = self.power_ups.get_mut(kind);
+            *level = (*level).min(kind.max_level());
+        }
+        self
+    }
+
+    pub fn level(&self, kind: PowerUpKind) -> u32 {
+        self.power_ups.get(kind)
+    }
+
+    /// Sonraki seviyenin fiyatı; maks seviyede `None`
+    pub fn next_cost(&self, kind: PowerUpKind) -> Option<u32> {
+        let level = self.level(kind);
+        (level < kind.max_level()).then(|| kind.cost(level))
+    }
+
+    /// Altın yetiyorsa bir seviye al
+    pub fn buy(&mut self, kind: PowerUpKind) -> bool {
+        let Some(cost) = self.next_cost(kind).filter(|cost| *cost <= self.gold) else {
+            return false;
+        };
+        self.gold -= cost;
+        *self.power_ups.get_mut(kind) += 1;
+        true
+    }
+
+    /// Kilit açıksa `choices` içinde sıradaki başlangıç silahına geç; seçim yoksa ilkine
+    pub fn cycle_starting_weapon(&mut self, choices: &[WeaponId]) {
+        if self.level(PowerUpKind::StartingWeapon) == 0 || choices.is_empty() {
+            return;
+        }
+        let index = self
+            .starting_weapon
+            .as_ref()
+            .and_then(|current| choices.iter().position(|weapon| weapon == current))
+            .map_or(0, |index| (index + 1) % choices.len());
+        self.starting_weapon = Some(choices[index].clone());
+    }
+
+    pub fn loadout(&self) -> Loadout {
+        let level = |kind| self.level(kind);
+        Loadout {
+            bonus_health: level(PowerUpKind::MaxHealth) * MAX_HEALTH_PER_LEVEL,
+            might: level(PowerUpKind::Might) as f32 * MIGHT_PER_LEVEL,
+            move_speed: level(PowerUpKind::MoveSpeed) as f32 * MOVE_SPEED_PER_LEVEL,
+            revivals: level(PowerUpKind::Revival),
+            rerolls: level(PowerUpKind::Reroll),
+            starting_weapon: self.starting_weapon.clone().filter(|_| level(PowerUpKind::StartingWeapon) > 0),
+            character: CharacterId::default(),
+        }
+    }
+
+    /// Profil dosyasına yaz; dosya yoksa bellekte kalır
+    pub fn save_to(&self, file: &ProfileFile) {
+        let Some(path) = &file.path else {
+            return;
+        };
+        match self.save(path) {
+            Ok(()) => println!("💾 Profile saved: {}", path.display()),
+            Err(err) => error!("{}: {}", path.display(), err),
+        }
+    }
+}
+
+/// Okunamayan silah (eski sürümün enum adı) seçilmemiş sayılır - dosyanın geri kalanı okunur
+fn known_weapon<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<WeaponId>, D::Error> {
+    Ok(Option::<StoredWeapon>::deserialize(deserializer)?.and_then(|weapon| weapon.0))
+}
+
+/// Profildeki silah id'si; id yerine ad (`Some(Laser)`) yazılmışsa `None`
+struct StoredWeapon(Option<WeaponId>);
+
+impl<'de> Deserialize<'de> for StoredWeapon {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct WeaponName;
+
+        impl serde::de::Visitor<'_> for WeaponName {
+            type Value = StoredWeapon;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a weapon id")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, id: &str) -> Result<StoredWeapon, E> {
+                Ok(StoredWeapon(Some(WeaponId::new(id))))
+            }
+
+            fn visit_unit<E: serde::de::Error>(self) -> Result<StoredWeapon, E> {
+                Ok(StoredWeapon(None))
+            }
+        }
+
+        deserializer.deserialize_any(WeaponName)
+    }
+}
+
+/// Bir koşunun güçlendirmeleri - replay'e yazılır, oynatma profil yerine bunu kullanır
+#[derive(Clone, PartialEq, Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Loadout {
+    /// `max_health`'e eklenen can
+    pub bonus_health: u32,
+    /// Hasar çarpanına eklenen
+    pub might: f32,
+    /// Hız çarpanına eklenen
+    pub move_speed: f32,
+    pub revivals: u32,
+    pub rerolls: u32,
+    /// `None` ise karakterin başlangıç silahı
+    pub starting_weapon: Option<WeaponId>,
+    /// Eski kayıtlar varsayılan karakterle oynar
+    pub character: CharacterId,
+}
+
+impl Loadout {
+    /// Pasifler bunun üstüne eklenir
+    pub fn base_stats(&self) -> PlayerStats {
+        let stats = PlayerStats::default();
+        PlayerStats { might: stats.might + self.might, move_speed: stats.move_speed + self.move_speed, ..stats }
+    }
+}
+
+/// Şu anki koşunun güçlendirmeleri
+#[derive(Resource, Default, Debug)]
+pub struct RunLoadout(pub Loadout);
+
+/// Oynatılan kayıt ve devam edilen koşu kendi güçlendirmeleri ve karakteriyle oynar -
+/// profil ve seçim o zamandan beri değişmiş olabilir
+fn choose_run_loadout(
+    profile: Res<Profile>,
+    selected: Res<SelectedCharacter>,
+    session: Res<ReplaySession>,
+    pending: Option<Res<PendingRun>>,
+    mut loadout: ResMut<RunLoadout>,
+) {
+    loadout.0 = session
+        .playback_loadout()
+        .or(pending.map(|pending| pending.0.loadout.clone()))
+        .unwrap_or_else(|| Loadout { character: selected.0.clone(), ..profile.loadout() });
+}
+
+/// Koşudan çıkarken (oyuncu henüz silinmeden) toplanan altını profile ekle, rekorları güncelle ve kaydet.
+/// Oynatılan koşunun altını ve rekorları zaten kaydedildiği koşuda kazanılmıştı
+pub fn bank_run(
+    player: Query<&Player>,
+    director: Res<SpawnDirector>,
+    session: Res<ReplaySession>,
+    mut profile: ResMut<Profile>,
+    file: Res<ProfileFile>,
+) {
+    let Ok(player) = player.single() else {
+        return;
+    };
+    if session.is_playback() {
+        return;
+    }
+    let records = profile.records.best(RunRecords { level: player.level, survived: director.elapsed });
+    if player.gold == 0 && records == profile.records {
+        return;
+    }
+    if player.gold > 0 {
+        profile.gold = profile.gold.saturating_add(player.gold);
+        println!("🪙 Banked {} gold ({} total)", player.gold, profile.gold);
+    }
+    if records != profile.records {
+        println!("🏆 New record: level {}, survived {:.0}s", records.level, records.survived);
+        profile.records = records;
+    }
+    profile.save_to(&file);
+}
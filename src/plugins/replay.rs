@@ -0,0 +1,508 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use bevy::app::RunFixedMainLoopSystems;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use crate::plugins::enemy::Enemy;
+use crate::plugins::game_state::{gameplay_tick, GameState};
+use crate::plugins::input_action::{ActionState, InputAction};
+use crate::plugins::player::Player;
+use crate::plugins::profile::{bank_run, Loadout, RunLoadout};
+use crate::plugins::rng::{GameRng, SeedSettings};
+use crate::plugins::weapon_upgrade::{apply_weapon_upgrade, reroll_upgrade_choices, show_upgrade_choices_on_level_up, RerollEvent, UpgradeKind, UpgradeSelectedEvent};
+
+/// Format değişince artır - eski kayıtlar reddedilir
+const REPLAY_VERSION: u32 = 6;
+/// Hareket ekseninin kayıttaki çözünürlüğü: -127..=127
+const AXIS_STEPS: f32 = 127.0;
+/// Kaç tick'te bir dünya checksum'ı alınır (64 Hz'de ~1 sn)
+const CHECKSUM_INTERVAL: u64 = 64;
+const DEFAULT_RECORD_PATH: &str = "replays/last.replay.ron";
+const DEFAULT_FAST_FORWARD: f32 = 4.0;
+
+/// Her koşunun girdilerini kaydeden ve `--replay` ile geri oynatan plugin
+pub struct ReplayPlugin;
+
+impl Plugin for ReplayPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ReplaySettings>()
+            .init_resource::<ReplaySession>()
+            .init_resource::<PlayerInput>()
+            .init_resource::<PendingPresses>()
+            .add_systems(Startup, start_playback)
+            .add_systems(RunFixedMainLoop, latch_action_presses.in_set(RunFixedMainLoopSystems::BeforeFixedMainLoop))
+            .add_systems(FixedPreUpdate, (checksum_world, read_player_input).chain().run_if(gameplay_tick))
+            // Biten koşunun altını oynatma mıydı bilgisi sıfırlanmadan profile eklenir
+            .add_systems(OnEnter(GameState::Loading), reset_session.after(bank_run))
+            .add_systems(OnEnter(GameState::GameOver), finish_replay)
+            .add_systems(
+                Update,
+                (
+                    feed_recorded_upgrade
+                        .after(show_upgrade_choices_on_level_up)
+                        .before(reroll_upgrade_choices)
+                        .before(apply_weapon_upgrade)
+                        .run_if(in_state(GameState::UpgradeSelection)),
+                    record_upgrade_choice.after(apply_weapon_upgrade),
+                    record_reroll.after(reroll_upgrade_choices),
+                    toggle_fast_forward.run_if(not(live_input)),
+                ),
+            );
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum ReplayError {
+    #[error("could not access replay file: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("could not parse replay: {0}")]
+    Parse(#[from] ron::error::SpannedError),
+    #[error("could not write replay: {0}")]
+    Write(#[from] ron::Error),
+    #[error("replay version {0} is not supported (expected {REPLAY_VERSION})")]
+    Version(u32),
+}
+
+/// Bir tick'in oyuncu girdisi - oyun mantığı klavyeyi değil bunu okur.
+/// Hareket tamsayı olarak tutulur; kayıttan oynatılan koşu aynı float'ları görür.
+#[derive(Resource, Default, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct PlayerInput {
+    /// Sağ pozitif, -127..=127
+    pub move_x: i8,
+    /// Yukarı pozitif, -127..=127
+    pub move_y: i8,
+    /// Bu tick'te atılma (Shift) tetiklendi
+    pub dash: bool,
+}
+
+impl PlayerInput {
+    /// Uzunluğu en fazla 1 olan `movement` ile
+    pub fn with_movement(movement: Vec2) -> Self {
+        let axis = |value: f32| (value.clamp(-1.0, 1.0) * AXIS_STEPS).round() as i8;
+        let movement = movement.clamp_length_max(1.0);
+        Self { move_x: axis(movement.x), move_y: axis(movement.y), ..default() }
+    }
+
+    /// Uzunluğu en fazla 1 olan hareket yönü; analogda ara büyüklükler
+    pub fn movement(&self) -> Vec2 {
+        (Vec2::new(self.move_x as f32, self.move_y as f32) / AXIS_STEPS).clamp_length_max(1.0)
+    }
+
+    fn to_bits(self) -> u32 {
+        self.move_x as u8 as u32
+            | (self.move_y as u8 as u32) << 8
+            | (self.dash as u32) << 16
+    }
+
+    fn from_bits(bits: u32) -> Self {
+        Self {
+            move_x: bits as u8 as i8,
+            move_y: (bits >> 8) as u8 as i8,
+            dash: bits & 1 << 16 != 0,
+        }
+    }
+}
+
+/// `just_pressed` bir frame sürer; tick o frame'de çalışmayabilir, bu yüzden sonraki tick'e sakla
+#[derive(Resource, Default)]
+struct PendingPresses {
+    dash: bool,
+}
+
+/// Bir koşunun kaydı - `ron` ile tek satır olarak yazılır
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
+pub struct Replay {
+    pub version: u32,
+    pub seed: u64,
+    /// Kaydın alındığı sabit tick frekansı (Hz)
+    pub tick_rate: f64,
+    /// (tick sayısı, girdi bitleri) - art arda aynı girdiler tek kayıt
+    pub inputs: Vec<(u32, u32)>,
+    /// (tick, seçim) - seçim ekranı açıldığında tick sayacı durur
+    pub upgrades: Vec<(u64, UpgradeKind)>,
+    /// (tick, önündeki seçimin `upgrades` sırası) - yeniden çekme, o seçimden önce oynatılır
+    pub rerolls: Vec<(u64, u32)>,
+    /// Koşunun profilden aldığı güçlendirmeler
+    pub loadout: Loadout,
+    /// (tick, dünya checksum'ı)
+    pub checksums: Vec<(u64, u64)>,
+}
+
+impl Replay {
+    pub fn load(path: &Path) -> Result<Self, ReplayError> {
+        let replay: Replay = ron::from_str(&fs::read_to_string(path)?)?;
+        if replay.version != REPLAY_VERSION {
+            return Err(ReplayError::Version(replay.version));
+        }
+        Ok(replay)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), ReplayError> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, ron::to_string(self)?)?;
+        Ok(())
+    }
+
+    fn push_input(&mut self, input: PlayerInput) {
+        let bits = input.to_bits();
+        match self.inputs.last_mut() {
+            Some((count, last)) if *last == bits && *count < u32::MAX => *count += 1,
+            _ => self.inputs.push((1, bits)),
+        }
+    }
+}
+
+/// `--record <dosya>`, `--replay <dosya>`, `--fast-forward <hız>`
+#[derive(Resource, Debug)]
+pub struct ReplaySettings {
+    /// Her koşu bitince kaydın yazıldığı dosya
+    pub record_path: PathBuf,
+    pub playback_path: Option<PathBuf>,
+    /// Oynatma bu hızda başlar; F tuşu 1x ile bu hız arasında geçiş yapar
+    pub fast_forward: Option<f32>,
+}
+
+impl Default for ReplaySettings {
+    fn default() -> Self {
+        Self {
+            record_path: PathBuf::from(DEFAULT_RECORD_PATH),
+            playback_path: None,
+            fast_forward: None,
+        }
+    }
+}
+
+impl ReplaySettings {
+    pub fn from_args(args: impl IntoIterator<Item = String>) -> Self {
+        let mut settings = Self::default();
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--record" => settings.record_path = args.next().map(PathBuf::from).unwrap_or(settings.record_path),
+                "--replay" => settings.playback_path = args.next().map(PathBuf::from),
+                "--fast-forward" => match args.next().map(|value| value.parse::<f32>()) {
+                    Some(Ok(speed)) if speed > 0.0 => settings.fast_forward = Some(speed),
+                    _ => warn!("--fast-forward expects a positive number"),
+                },
+                _ => {}
+            }
+        }
+        settings
+    }
+}
+
+/// Oynatılan kaydın okunma konumu
+struct Playback {
+    replay: Replay,
+    input_index: usize,
+    input_used: u32,
+    next_upgrade: usize,
+    next_reroll: usize,
+    next_checksum: usize,
+    desyncs: u32,
+}
+
+impl Playback {
+    fn new(replay: Replay) -> Self {
+        Self { replay, input_index: 0, input_used: 0, next_upgrade: 0, next_reroll: 0, next_checksum: 0, desyncs: 0 }
+    }
+
+    fn next_input(&mut self) -> Option<PlayerInput> {
+        let &(count, bits) = self.replay.inputs.get(self.input_index)?;
+        self.input_used += 1;
+        if self.input_used >= count {
+            self.input_index += 1;
+            self.input_used = 0;
+        }
+        Some(PlayerInput::from_bits(bits))
+    }
+}
+
+/// Koşunun tick sayacı, kaydı ve (varsa) oynatılan kayıt
+#[derive(Resource, Default)]
+pub struct ReplaySession {
+    /// Bu koşuda çalışan oyun tick'i sayısı
+    pub tick: u64,
+    recording: Replay,
+    playback: Option<Playback>,
+    /// Koşu bir kayıttan oynatılıyor - bitince kaydedilmez
+    from_playback: bool,
+    /// Koşu kayıtlı bir koşudan devam ediyor - girdiler baştan oynatılamaz, kaydedilmez
+    resumed: bool,
+    /// Debug tuşuyla düşman eklendi - kayıt aynı koşuyu vermez, kaydedilmez
+    tampered: bool,
+}
+
+impl ReplaySession {
+    /// Oynatılan kaydı yarıda bırak - sonraki koşular canlı girdiyle oynanır
+    pub fn stop_playback(&mut self) {
+        if self.playback.take().is_some() {
+            println!("⏹️ Replay stopped at tick {}", self.tick);
+        }
+    }
+
+    /// Oynatılan kaydın güçlendirmeleri
+    pub fn playback_loadout(&self) -> Option<Loadout> {
+        self.playback.as_ref().map(|playback| playback.replay.loadout.clone())
+    }
+
+    /// Oynatılan kayıtta şimdiye kadar tutmayan checksum sayısı
+    pub fn desyncs(&self) -> Option<u32> {
+        self.playback.as_ref().map(|playback| playback.desyncs)
+    }
+
+    /// Bu koşu bir kayıttan oynatılıyor (ya da oynatılırken yarıda bırakıldı)
+    pub fn is_playback(&self) -> bool {
+        self.from_playback
+    }
+
+    /// Yarıda bırakılan koşu `tick`'ten devam ediyor
+    pub fn resume_at(&mut self, tick: u64) {
+        self.tick = tick;
+        self.resumed = true;
+    }
+
+    /// Kayda girmeyen bir değişiklik yapıldı (debug boss) - bu koşunun kaydı yazılmaz
+    pub fn discard_recording(&mut self) {
+        if !self.tampered {
+            println!("🐞 Debug spawn used, this run's replay will not be saved");
+        }
+        self.tampered = true;
+    }
+}
+
+/// Girdiler canlı oyuncudan geliyor (oynatma yok)
+pub fn live_input(session: Res<ReplaySession>) -> bool {
+    session.playback.is_none()
+}
+
+fn start_playback(
+    settings: Res<ReplaySettings>,
+    mut session: ResMut<ReplaySession>,
+    mut seed: ResMut<SeedSettings>,
+    mut fixed_time: ResMut<Time<Fixed>>,
+    mut virtual_time: ResMut<Time<Virtual>>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    let Some(path) = &settings.playback_path else {
+        return;
+    };
+    let replay = match Replay::load(path) {
+        Ok(replay) => replay,
+        Err(err) => {
+            error!("{}: {}", path.display(), err);
+            return;
+        }
+    };
+    seed.fixed = Some(replay.seed);
+    fixed_time.set_timestep_hz(replay.tick_rate);
+    if let Some(speed) = settings.fast_forward {
+        virtual_time.set_relative_speed(speed);
+    }
+    println!("▶️ Replay: {} (seed {}, {} upgrades, {} rerolls)", path.display(), replay.seed, replay.upgrades.len(), replay.rerolls.len());
+    session.playback = Some(Playback::new(replay));
+    next_state.set(GameState::Loading);
+}
+
+fn reset_session(mut session: ResMut<ReplaySession>) {
+    session.tick = 0;
+    session.recording = Replay::default();
+    session.from_playback = session.playback.is_some();
+    session.resumed = false;
+    session.tampered = false;
+}
+
+fn latch_action_presses(actions: Res<ActionState>, mut pending: ResMut<PendingPresses>) {
+    pending.dash |= actions.just_pressed(InputAction::Dash);
+}
+
+/// Tick'in girdisini kayıttan ya da klavye/gamepad'den al; canlıysa kaydet
+fn read_player_input(
+    actions: Res<ActionState>,
+    mut pending: ResMut<PendingPresses>,
+    mut input: ResMut<PlayerInput>,
+    mut session: ResMut<ReplaySession>,
+) {
+    let live = PlayerInput {
+        dash: std::mem::take(&mut pending.dash),
+        ..PlayerInput::with_movement(actions.movement())
+    };
+    let session = &mut *session;
+    session.tick += 1;
+
+    if let Some(playback) = &mut session.playback {
+        if let Some(recorded) = playback.next_input() {
+            *input = recorded;
+            return;
+        }
+        println!("⏹️ Replay inputs ended at tick {}, switching to live input", session.tick);
+        session.playback = None;
+    }
+    *input = live;
+    if !session.from_playback {
+        session.recording.push_input(live);
+    }
+}
+
+/// Sırasız toplam: aynı dünya, entity sırası ne olursa olsun aynı değeri verir
+fn checksum_world(
+    mut session: ResMut<ReplaySession>,
+    players: Query<(&Transform, &Player)>,
+    enemies: Query<(&Transform, &Enemy)>,
+) {
+    if !session.tick.is_multiple_of(CHECKSUM_INTERVAL) {
+        return;
+    }
+    let mut checksum = enemies.iter().count() as u64;
+    for (transform, player) in &players {
+        checksum = mix(checksum, hash_position(transform));
+        checksum = mix(checksum, player.health as u64);
+        checksum = mix(checksum, player.xp.to_bits() as u64);
+        checksum = mix(checksum, player.level as u64);
+    }
+    let enemy_sum = enemies.iter().fold(0u64, |sum, (transform, enemy)| {
+        sum.wrapping_add(mix(hash_position(transform), enemy.health as u64))
+    });
+    checksum = mix(checksum, enemy_sum);
+
+    let tick = session.tick;
+    let session = &mut *session;
+    match &mut session.playback {
+        Some(playback) => {
+            let expected = playback.replay.checksums.iter().skip(playback.next_checksum).position(|(at, _)| *at >= tick);
+            let Some(offset) = expected else {
+                return;
+            };
+            playback.next_checksum += offset;
+            let (at, expected) = playback.replay.checksums[playback.next_checksum];
+            if at != tick {
+                return;
+            }
+            playback.next_checksum += 1;
+            if expected != checksum {
+                playback.desyncs += 1;
+                warn!("replay desync at tick {}: expected {:016x}, got {:016x}", tick, expected, checksum);
+            }
+        }
+        None if !session.from_playback => session.recording.checksums.push((tick, checksum)),
+        None => {}
+    }
+}
+
+fn hash_position(transform: &Transform) -> u64 {
+    mix(transform.translation.x.to_bits() as u64, transform.translation.y.to_bits() as u64)
+}
+
+/// FNV-1a tarzı karıştırma
+fn mix(hash: u64, value: u64) -> u64 {
+    (hash ^ value).wrapping_mul(0x0000_0100_0000_01B3).rotate_left(29)
+}
+
+/// Oynatmada sıradaki kayıtlı seçimi, seçenekler üretildikten sonra uygula.
+/// Seçimden önce yapılan yeniden çekmeler birer kare önce oynatılır - aynı RNG tüketilsin
+fn feed_recorded_upgrade(
+    mut session: ResMut<ReplaySession>,
+    mut upgrade_events: MessageWriter<UpgradeSelectedEvent>,
+    mut reroll_events: MessageWriter<RerollEvent>,
+) {
+    let tick = session.tick;
+    let Some(playback) = &mut session.playback else {
+        return;
+    };
+    if let Some(&(at, upgrade)) = playback.replay.rerolls.get(playback.next_reroll)
+        && upgrade as usize == playback.next_upgrade
+        && at <= tick
+    {
+        playback.next_reroll += 1;
+        reroll_events.write(RerollEvent);
+        return;
+    }
+    let Some((at, kind)) = playback.replay.upgrades.get(playback.next_upgrade).cloned() else {
+        return;
+    };
+    if at <= tick {
+        playback.next_upgrade += 1;
+        upgrade_events.write(UpgradeSelectedEvent { kind });
+    }
+}
+
+fn record_upgrade_choice(
+    mut session: ResMut<ReplaySession>,
+    mut upgrade_events: MessageReader<UpgradeSelectedEvent>,
+) {
+    for event in upgrade_events.read() {
+        if session.from_playback {
+            continue;
+        }
+        let tick = session.tick;
+        session.recording.upgrades.push((tick, event.kind.clone()));
+    }
+}
+
+fn record_reroll(
+    mut session: ResMut<ReplaySession>,
+    mut reroll_events: MessageReader<RerollEvent>,
+) {
+    for _ in reroll_events.read() {
+        if session.from_playback {
+            continue;
+        }
+        let (tick, upgrade) = (session.tick, session.recording.upgrades.len() as u32);
+        session.recording.rerolls.push((tick, upgrade));
+    }
+}
+
+fn toggle_fast_forward(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    settings: Res<ReplaySettings>,
+    mut virtual_time: ResMut<Time<Virtual>>,
+) {
+    if !keyboard.just_pressed(KeyCode::KeyF) {
+        return;
+    }
+    let speed = if virtual_time.relative_speed() > 1.0 {
+        1.0
+    } else {
+        settings.fast_forward.unwrap_or(DEFAULT_FAST_FORWARD)
+    };
+    virtual_time.set_relative_speed(speed);
+}
+
+/// Koşu bitti: canlıysa kaydı yaz, oynatmaysa sonucu raporla
+fn finish_replay(
+    settings: Res<ReplaySettings>,
+    mut session: ResMut<ReplaySession>,
+    rng: Res<GameRng>,
+    fixed_time: Res<Time<Fixed>>,
+    mut virtual_time: ResMut<Time<Virtual>>,
+    loadout: Res<RunLoadout>,
+) {
+    if session.from_playback {
+        if let Some(desyncs) = session.desyncs() {
+            println!("⏹️ Replay finished at tick {} with {} desync(s)", session.tick, desyncs);
+        }
+        session.playback = None;
+        virtual_time.set_relative_speed(1.0);
+        return;
+    }
+    if session.resumed {
+        println!("⏭️ Resumed run, replay not saved");
+        return;
+    }
+    if session.tampered {
+        println!("🐞 Debug spawn used, replay not saved");
+        return;
+    }
+    let session = &mut *session;
+    session.recording.version = REPLAY_VERSION;
+    session.recording.seed = rng.seed();
+    session.recording.tick_rate = 1.0 / fixed_time.timestep().as_secs_f64();
+    session.recording.loadout = loadout.0.clone();
+    match session.recording.save(&settings.record_path) {
+        Ok(()) => println!("💾 Replay saved: {}", settings.record_path.display()),
+        Err(err) => error!("{}: {}", settings.record_path.display(), err),
+    }
+}
@@ -0,0 +1,96 @@
+use bevy::prelude::*;
+use rand::SeedableRng;
+use rand_chacha::ChaCha12Rng;
+use serde::{Deserialize, Serialize};
+use crate::plugins::game_state::GameState;
+
+/// Koşu seed'inden türeyen `GameRng`'yi her koşu başında yeniden kuran plugin
+pub struct GameRngPlugin;
+
+impl Plugin for GameRngPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SeedSettings>()
+            .insert_resource(GameRng::new(0))
+            .add_systems(OnEnter(GameState::Loading), start_run_rng);
+    }
+}
+
+/// Oyunun rastgelelik kullanan alanları - her birinin kendi akışı var,
+/// böylece örn. fazladan bir yükseltme zarı spawn sırasını kaydırmaz
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum RngStream {
+    /// Düşman spawn konumları/hızları ve boss saldırı desenleri
+    Spawning,
+    /// Sandık ve düşman ödülleri
+    Loot,
+    /// Seviye atlama seçenekleri
+    Upgrades,
+    /// İsabet zarları (durum etkisi şansları)
+    Combat,
+}
+
+impl RngStream {
+    pub const ALL: [RngStream; 4] = [RngStream::Spawning, RngStream::Loot, RngStream::Upgrades, RngStream::Combat];
+}
+
+/// Seçilen seed - `None` ise her koşu rastgele bir seed alır
+#[derive(Resource, Default, Debug)]
+pub struct SeedSettings {
+    pub fixed: Option<u64>,
+}
+
+impl SeedSettings {
+    /// `--seed 1234` ya da `--seed=1234`
+    pub fn from_args(args: impl IntoIterator<Item = String>) -> Self {
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            let value = if arg == "--seed" {
+                args.next()
+            } else if let Some(value) = arg.strip_prefix("--seed=") {
+                Some(value.to_string())
+            } else {
+                continue;
+            };
+            match value.as_deref().map(str::parse::<u64>) {
+                Some(Ok(seed)) => return Self { fixed: Some(seed) },
+                _ => warn!("--seed expects an unsigned integer, using a random seed"),
+            }
+        }
+        Self::default()
+    }
+}
+
+/// Koşunun tüm rastgeleliği - aynı seed + aynı girdiler aynı koşuyu üretir.
+/// Akışlar `StdRng` ile aynı algoritma; durumları yarıda bırakılan koşuyla kaydedilebilsin diye doğrudan ChaCha12
+#[derive(Resource, Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct GameRng {
+    seed: u64,
+    streams: [ChaCha12Rng; RngStream::ALL.len()],
+}
+
+impl GameRng {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            // Akışları birbirinden ayırmak için seed'i akış sırasına göre kaydır
+            streams: RngStream::ALL.map(|stream| {
+                ChaCha12Rng::seed_from_u64(seed.wrapping_add((stream as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15)))
+            }),
+        }
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    pub fn stream(&mut self, stream: RngStream) -> &mut ChaCha12Rng {
+        &mut self.streams[stream as usize]
+    }
+}
+
+/// Yeni koşu: sabit seed varsa onu, yoksa rastgele bir seed kullan
+fn start_run_rng(settings: Res<SeedSettings>, mut rng: ResMut<GameRng>) {
+    let seed = settings.fixed.unwrap_or_else(rand::random);
+    *rng = GameRng::new(seed);
+    println!("🎲 Seed: {}", seed);
+}
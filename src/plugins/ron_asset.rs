@@ -0,0 +1,71 @@
+use std::marker::PhantomData;
+use bevy::asset::io::Reader;
+use bevy::asset::{AssetLoader, LoadContext};
+use bevy::prelude::*;
+use serde::de::DeserializeOwned;
+use thiserror::Error;
+
+/// `assets/data` altındaki RON dosyalarından yüklenen tasarım verileri
+pub trait RonAsset: Asset + DeserializeOwned {
+    /// Noktasız dosya uzantıları, ör. `"weapon.ron"`
+    const EXTENSIONS: &'static [&'static str];
+
+    /// Yüklendikten sonra verinin tutarlılığını kontrol et
+    fn validate(&self) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum RonAssetError {
+    #[error("could not read file: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("could not parse file: {0}")]
+    Ron(#[from] ron::error::SpannedError),
+    #[error("invalid data: {0}")]
+    Invalid(String),
+}
+
+/// Her `RonAsset` tipi için aynı yükleyici: oku, parse et, doğrula
+pub struct RonAssetLoader<A>(PhantomData<fn() -> A>);
+
+impl<A> Default for RonAssetLoader<A> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<A: RonAsset> AssetLoader for RonAssetLoader<A> {
+    type Asset = A;
+    type Settings = ();
+    type Error = RonAssetError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let asset = ron::de::from_bytes::<A>(&bytes)?;
+        asset.validate().map_err(RonAssetError::Invalid)?;
+        Ok(asset)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        A::EXTENSIONS
+    }
+}
+
+pub trait RonAssetAppExt {
+    /// Asset tipini ve RON yükleyicisini kaydet
+    fn init_ron_asset<A: RonAsset>(&mut self) -> &mut Self;
+}
+
+impl RonAssetAppExt for App {
+    fn init_ron_asset<A: RonAsset>(&mut self) -> &mut Self {
+        self.init_asset::<A>()
+            .register_asset_loader(RonAssetLoader::<A>::default())
+    }
+}
@@ -0,0 +1,440 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use bevy::ecs::query::QueryData;
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use crate::plugins::aabb::AABB;
+use crate::plugins::boss::Boss;
+use crate::plugins::damage::DamageMeter;
+use crate::plugins::enemy::{spawn_enemy, Enemy, EnemyDrops, EnemySpawner, XP};
+use crate::plugins::enemy_kind::{Charger, RangedAttacker};
+use crate::plugins::game_state::GameState;
+use crate::plugins::loot::{spawn_pickup, Pickup};
+use crate::plugins::passive_item::PassiveInventory;
+use crate::plugins::pause_menu::PauseButton;
+use crate::plugins::player::{Player, PlayerMotion, XPMagnetite};
+use crate::plugins::player_damage::PlayerDeath;
+use crate::plugins::profile::{Loadout, RunLoadout};
+use crate::plugins::replay::ReplaySession;
+use crate::plugins::rng::GameRng;
+use crate::plugins::score::GameScore;
+use crate::plugins::settings::{config_dir, CONFIG_DIR_NAME};
+use crate::plugins::settings_menu::settings_menu_open;
+use crate::plugins::spawn_director::SpawnDirector;
+use crate::plugins::status_effect::StatusEffects;
+use crate::plugins::weapon_definition::WeaponId;
+use crate::plugins::weapon_stats::WeaponSpawner;
+use crate::plugins::weapon_upgrade::WeaponLevel;
+use crate::plugins::weapons::{PlayerAddictedWeapon, RocketWeapon, Weapon};
+use crate::plugins::xp_orb::spawn_xp_orb;
+
+const RUN_SAVE_FILE_NAME: &str = "run.ron";
+/// Format değişince artır - yarım kalan koşu eski sürümden taşınmaz
+pub const RUN_SAVE_VERSION: u32 = 3;
+
+/// Duraklatma menüsünden koşuyu dosyaya yazıp ana menüye dönme ve ana menüden kaldığı yerden devam etme.
+/// Kayıt devam edilince silinir. Uçuştaki mermiler, patlamalar ve boss'un zemin alanları kaydedilmez
+pub struct RunSavePlugin;
+
+impl Plugin for RunSavePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<RunSaveFile>()
+            .add_systems(Update, suspend_run.run_if(in_state(GameState::Paused).and(not(settings_menu_open))))
+            // Oyuncu ve başlangıç silahları yükleme sırasında spawn oldu; üzerlerine kayıt yazılır
+            .add_systems(OnEnter(GameState::Playing), (restore_run, restore_drops).chain().run_if(resource_exists::<PendingRun>));
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum RunSaveError {
+    #[error("could not access run save: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("could not parse run save: {0}")]
+    Parse(#[from] ron::error::SpannedError),
+    #[error("could not write run save: {0}")]
+    Write(#[from] ron::Error),
+    #[error("run save version {0} does not match this game ({RUN_SAVE_VERSION})")]
+    Version(u32),
+}
+
+/// Yarım kalan koşunun dosyası; `None` ise koşu kaydedilemez
+#[derive(Resource, Debug)]
+pub struct RunSaveFile {
+    pub path: Option<PathBuf>,
+}
+
+impl Default for RunSaveFile {
+    fn default() -> Self {
+        Self { path: config_dir().map(|dir| dir.join(CONFIG_DIR_NAME).join(RUN_SAVE_FILE_NAME)) }
+    }
+}
+
+impl RunSaveFile {
+    /// Ana menüde "Devam Et" gösterilsin mi
+    pub fn exists(&self) -> bool {
+        self.path.as_deref().is_some_and(Path::exists)
+    }
+}
+
+/// Yarıda bırakılan koşunun tamamı
+#[derive(Serialize, Deserialize, Clone)]
+pub struct RunSave {
+    pub version: u32,
+    /// Replay tick sayacı
+    pub tick: u64,
+    pub rng: GameRng,
+    pub loadout: Loadout,
+    pub score: u32,
+    pub damage: BTreeMap<WeaponId, i64>,
+    pub director: DirectorSave,
+    pub player: PlayerSave,
+    pub weapons: Vec<WeaponSave>,
+    pub enemies: Vec<EnemySave>,
+    pub xp_orbs: Vec<XpOrbSave>,
+    pub pickups: Vec<(Vec3, Pickup)>,
+}
+
+/// Spawn director'ın zaman çizelgesindeki yeri - çizelgenin kendisi veri dosyasından gelir
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct DirectorSave {
+    pub elapsed: f32,
+    pub minute_index: Option<usize>,
+    pub spawn_timers: Vec<Timer>,
+    pub next_event: usize,
+    pub level: i32,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct PlayerSave {
+    pub position: Vec3,
+    pub player: Player,
+    pub motion: PlayerMotion,
+    pub passives: PassiveInventory,
+    pub effects: StatusEffects,
+}
+
+/// `WeaponStats` ve lazer rengi silah tanımından yeniden kurulur
+#[derive(Serialize, Deserialize, Clone)]
+pub struct WeaponSave {
+    pub level: WeaponLevel,
+    pub damage: f32,
+    pub speed: f32,
+    pub fire_timer: Timer,
+    pub rocket: Option<RocketWeapon>,
+    pub addicted: Option<PlayerAddictedWeapon>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct EnemySave {
+    pub position: Vec3,
+    pub enemy: Enemy,
+    pub effects: StatusEffects,
+    pub ranged: Option<RangedAttacker>,
+    pub charger: Option<Charger>,
+    pub boss: Option<Boss>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy)]
+pub struct XpOrbSave {
+    pub position: Vec3,
+    pub amount: i32,
+    /// Oyuncuya çekilmekteyse hızı
+    pub magnetite: Option<XPMagnetite>,
+}
+
+impl RunSave {
+    pub fn load(path: &Path) -> Result<Self, RunSaveError> {
+        let save: RunSave = ron::from_str(&fs::read_to_string(path)?)?;
+        if save.version != RUN_SAVE_VERSION {
+            return Err(RunSaveError::Version(save.version));
+        }
+        Ok(save)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), RunSaveError> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, ron::to_string(self)?)?;
+        Ok(())
+    }
+
+    /// Kaydı oku ve dosyayı sil - aynı koşuya ikinci kez devam edilemez.
+    /// Okunamayan kayıt profil gibi yedeklenir
+    pub fn take(file: &RunSaveFile) -> Option<Self> {
+        let path = file.path.as_ref()?;
+        match Self::load(path) {
+            Ok(save) => {
+                if let Err(err) = fs::remove_file(path) {
+                    warn!("{}: {}", path.display(), err);
+                }
+                Some(save)
+            }
+            Err(RunSaveError::Io(err)) if err.kind() == std::io::ErrorKind::NotFound => None,
+            Err(err) => {
+                let backup = path.with_extension("ron.bak");
+                match fs::rename(path, &backup) {
+                    Ok(()) => warn!("{}: {} - moved to {}", path.display(), err, backup.display()),
+                    Err(rename_err) => warn!("{}: {} ({})", path.display(), err, rename_err),
+                }
+                None
+            }
+        }
+    }
+}
+
+/// Ana menüden seçilen, yükleme bitince uygulanacak kayıt
+#[derive(Resource)]
+pub struct PendingRun(pub RunSave);
+
+/// "Devam Et": kayıt okunabildiyse yükleme başlatılabilir
+pub fn continue_run(commands: &mut Commands, file: &RunSaveFile) -> bool {
+    let Some(save) = RunSave::take(file) else {
+        return false;
+    };
+    println!("📂 Continuing run at {:.0}s (seed {})", save.director.elapsed, save.rng.seed());
+    commands.insert_resource(PendingRun(save));
+    true
+}
+
+#[derive(QueryData)]
+#[query_data(mutable)]
+struct PlayerState {
+    entity: Entity,
+    transform: &'static mut Transform,
+    aabb: &'static mut AABB,
+    player: &'static mut Player,
+    motion: &'static mut PlayerMotion,
+    passives: &'static mut PassiveInventory,
+    effects: &'static mut StatusEffects,
+}
+
+#[derive(QueryData)]
+struct WeaponState {
+    entity: Entity,
+    weapon: &'static Weapon,
+    level: &'static WeaponLevel,
+    rocket: Option<&'static RocketWeapon>,
+    addicted: Option<&'static PlayerAddictedWeapon>,
+}
+
+#[derive(QueryData)]
+struct EnemyState {
+    transform: &'static Transform,
+    enemy: &'static Enemy,
+    effects: &'static StatusEffects,
+    ranged: Option<&'static RangedAttacker>,
+    charger: Option<&'static Charger>,
+    boss: Option<&'static Boss>,
+}
+
+/// Koşunun kaydedilen entity'leri ve kaynakları
+#[derive(SystemParam)]
+struct RunWorld<'w, 's> {
+    player: Query<'w, 's, PlayerState, Without<PlayerDeath>>,
+    weapons: Query<'w, 's, WeaponState>,
+    enemies: Query<'w, 's, EnemyState, Without<Player>>,
+    director: ResMut<'w, SpawnDirector>,
+    score: ResMut<'w, GameScore>,
+    meter: ResMut<'w, DamageMeter>,
+    session: ResMut<'w, ReplaySession>,
+    loadout: ResMut<'w, RunLoadout>,
+}
+
+/// Yerdeki XP küreleri ve eşyalar
+#[derive(SystemParam)]
+struct FloorItems<'w, 's> {
+    xp_orbs: Query<'w, 's, (&'static Transform, &'static XP, Option<&'static XPMagnetite>), Without<Player>>,
+    pickups: Query<'w, 's, (&'static Transform, &'static Pickup), Without<Player>>,
+}
+
+impl RunWorld<'_, '_> {
+    /// Ölüm animasyonundaki oyuncu kaydedilmez
+    fn capture(&self, rng: &GameRng, floor: &FloorItems) -> Option<(Entity, RunSave)> {
+        let player = self.player.single().ok()?;
+        let director = &self.director;
+        let save = RunSave {
+            version: RUN_SAVE_VERSION,
+            tick: self.session.tick,
+            rng: rng.clone(),
+            loadout: self.loadout.0.clone(),
+            score: self.score.score,
+            damage: self.meter.per_weapon.clone(),
+            director: DirectorSave {
+                elapsed: director.elapsed,
+                minute_index: director.minute_index,
+                spawn_timers: director.spawn_timers.clone(),
+                next_event: director.next_event,
+                level: director.level,
+            },
+            player: PlayerSave {
+                position: player.transform.translation,
+                player: player.player.clone(),
+                motion: player.motion.clone(),
+                passives: player.passives.clone(),
+                effects: player.effects.clone(),
+            },
+            weapons: self
+                .weapons
+                .iter()
+                .filter(|weapon| weapon.weapon.owner == player.entity)
+                .map(|weapon| WeaponSave {
+                    level: weapon.level.clone(),
+                    damage: weapon.weapon.damage,
+                    speed: weapon.weapon.speed,
+                    fire_timer: weapon.weapon.fire_timer.clone(),
+                    rocket: weapon.rocket.copied(),
+                    addicted: weapon.addicted.copied(),
+                })
+                .collect(),
+            enemies: self
+                .enemies
+                .iter()
+                .map(|enemy| EnemySave {
+                    position: enemy.transform.translation,
+                    enemy: enemy.enemy.clone(),
+                    effects: enemy.effects.clone(),
+                    ranged: enemy.ranged.cloned(),
+                    charger: enemy.charger.cloned(),
+                    boss: enemy.boss.cloned(),
+                })
+                .collect(),
+            xp_orbs: floor
+                .xp_orbs
+                .iter()
+                .map(|(transform, xp, magnetite)| XpOrbSave { position: transform.translation, amount: xp.amount, magnetite: magnetite.copied() })
+                .collect(),
+            pickups: floor.pickups.iter().map(|(transform, pickup)| (transform.translation, *pickup)).collect(),
+        };
+        Some((player.entity, save))
+    }
+
+    /// Yeni spawn olmuş oyuncunun üzerine kaydı yaz, silahları ve düşmanları kayıttakilerle değiştir
+    fn restore(&mut self, save: &RunSave, commands: &mut Commands, spawner: &mut EnemySpawner, weapon_spawner: &mut WeaponSpawner) {
+        let Ok(mut player) = self.player.single_mut() else {
+            return;
+        };
+        let position = save.player.position;
+        player.transform.translation = position;
+        player.aabb.change_point(position);
+        *player.player = save.player.player.clone();
+        *player.motion = save.player.motion.clone();
+        *player.passives = save.player.passives.clone();
+        *player.effects = save.player.effects.clone();
+
+        for weapon in &self.weapons {
+            commands.entity(weapon.entity).despawn();
+        }
+        for saved in &save.weapons {
+            let Some(weapon) = weapon_spawner.spawn(commands, player.entity, position, &saved.level.weapon_type) else {
+                continue;
+            };
+            let mut weapon = commands.entity(weapon);
+            weapon.insert((
+                saved.level.clone(),
+                Weapon { owner: player.entity, damage: saved.damage, fire_timer: saved.fire_timer.clone(), speed: saved.speed },
+            ));
+            if let Some(rocket) = saved.rocket {
+                weapon.insert(rocket);
+            }
+            if let Some(addicted) = saved.addicted {
+                weapon.insert(addicted);
+            }
+        }
+
+        // Seviye 1 spawn sonra kayıttaki statlar; hız zarı aşağıda geri alınır
+        for saved in &save.enemies {
+            let Some(enemy) = spawn_enemy(commands, spawner, saved.enemy.kind, saved.position, 1) else {
+                continue;
+            };
+            let mut enemy = commands.entity(enemy);
+            enemy.insert((saved.enemy.clone(), saved.effects.clone()));
+            if let Some(ranged) = &saved.ranged {
+                enemy.insert(ranged.clone());
+            }
+            if let Some(charger) = &saved.charger {
+                enemy.insert(charger.clone());
+            }
+            if let Some(boss) = &saved.boss {
+                enemy.insert(boss.clone());
+            }
+        }
+
+        let director = &mut self.director;
+        director.elapsed = save.director.elapsed;
+        director.minute_index = save.director.minute_index;
+        director.spawn_timers = save.director.spawn_timers.clone();
+        director.next_event = save.director.next_event;
+        director.level = save.director.level;
+        self.score.score = save.score;
+        self.meter.per_weapon = save.damage.clone();
+        self.loadout.0 = save.loadout.clone();
+        self.session.resume_at(save.tick);
+        *spawner.rng = save.rng.clone();
+    }
+}
+
+/// "Kaydet ve Çık": koşu dosyaya yazılır, oyuncu silinir - altın profile değil, devam edilen koşuya kalır.
+/// Ana menüye dönüşü duraklatma menüsü yapar; kayıt yazılamazsa normal çıkış gibi biter
+fn suspend_run(
+    mut commands: Commands,
+    interactions_q: Query<(&Interaction, &PauseButton), Changed<Interaction>>,
+    run: RunWorld,
+    floor: FloorItems,
+    rng: Res<GameRng>,
+    file: Res<RunSaveFile>,
+) {
+    if !interactions_q.iter().any(|(interaction, button)| *interaction == Interaction::Pressed && *button == PauseButton::SaveAndQuit) {
+        return;
+    }
+    // Oynatılan kayıt zaten baştan izlenebilir
+    if run.session.is_playback() {
+        return;
+    }
+    let Some(path) = &file.path else {
+        return;
+    };
+    let Some((player, save)) = run.capture(&rng, &floor) else {
+        return;
+    };
+    match save.save(path) {
+        Ok(()) => {
+            println!("💾 Run saved: {} ({} enemies)", path.display(), save.enemies.len());
+            commands.entity(player).despawn();
+        }
+        Err(err) => error!("{}: {}", path.display(), err),
+    }
+}
+
+fn restore_run(
+    mut commands: Commands,
+    pending: Res<PendingRun>,
+    mut run: RunWorld,
+    mut spawner: EnemySpawner,
+    mut weapon_spawner: WeaponSpawner,
+) {
+    run.restore(&pending.0, &mut commands, &mut spawner, &mut weapon_spawner);
+}
+
+/// Silah spawner'ı ile aynı mesh/materyal kaynaklarını kullandığı için ayrı sistem
+fn restore_drops(
+    mut commands: Commands,
+    pending: Res<PendingRun>,
+    mut drops: EnemyDrops,
+    asset_server: Res<AssetServer>,
+) {
+    let save = &pending.0;
+    for orb in &save.xp_orbs {
+        let entity = spawn_xp_orb(&mut commands, &drops.xp_orbs, orb.amount, orb.position);
+        if let Some(magnetite) = orb.magnetite {
+            commands.entity(entity).insert(magnetite);
+        }
+    }
+    for (position, pickup) in &save.pickups {
+        spawn_pickup(&mut commands, *pickup, *position, &mut drops, &asset_server);
+    }
+    commands.remove_resource::<PendingRun>();
+}
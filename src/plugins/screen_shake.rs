@@ -0,0 +1,56 @@
+use bevy::prelude::*;
+use bevy::transform::TransformSystems;
+use crate::plugins::settings::Settings;
+
+/// Tam sarsıntıda kameranın en fazla kayması
+const MAX_SHAKE_OFFSET: f32 = 14.0;
+/// Saniyede azalan sarsıntı
+const TRAUMA_DECAY: f32 = 1.5;
+
+/// Kamera sarsıntısı. Sarsıntı sadece çizim için uygulanır: frame başında geri alınır,
+/// böylece oyun mantığı (kamera takibi, zemin parçaları) hep sarsılmamış kamerayı görür.
+pub struct ScreenShakePlugin;
+
+impl Plugin for ScreenShakePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ScreenShake>()
+            .add_systems(First, remove_camera_shake)
+            .add_systems(PostUpdate, shake_camera.before(TransformSystems::Propagate));
+    }
+}
+
+#[derive(Resource, Default, Debug)]
+pub struct ScreenShake {
+    /// 0-1 arası; kayma bunun karesiyle büyür
+    trauma: f32,
+    /// Bu frame kameraya eklenen kayma
+    applied: Vec3,
+}
+
+impl ScreenShake {
+    pub fn add_trauma(&mut self, amount: f32) {
+        self.trauma = (self.trauma + amount).clamp(0.0, 1.0);
+    }
+}
+
+fn remove_camera_shake(mut shake: ResMut<ScreenShake>, mut camera: Single<&mut Transform, With<Camera2d>>) {
+    camera.translation -= std::mem::take(&mut shake.applied);
+}
+
+/// Rastgelelik yerine zamanla değişen sinüsler - oyunun RNG akışlarına dokunmaz
+fn shake_camera(
+    time: Res<Time>,
+    settings: Res<Settings>,
+    mut shake: ResMut<ScreenShake>,
+    mut camera: Single<&mut Transform, With<Camera2d>>,
+) {
+    if shake.trauma <= 0.0 {
+        return;
+    }
+    shake.trauma = (shake.trauma - TRAUMA_DECAY * time.delta_secs()).max(0.0);
+    let t = time.elapsed_secs();
+    let strength = shake.trauma * shake.trauma * MAX_SHAKE_OFFSET * settings.screen_shake;
+    let offset = Vec3::new((t * 47.0).sin(), (t * 53.0 + 1.3).sin(), 0.0) * strength;
+    camera.translation += offset;
+    shake.applied = offset;
+}
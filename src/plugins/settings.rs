@@ -0,0 +1,309 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use bevy::audio::{AudioSinkPlayback, GlobalVolume, Volume};
+use bevy::prelude::*;
+use bevy::transform::TransformSystems;
+use bevy::window::{MonitorSelection, PresentMode, PrimaryWindow, VideoModeSelection, WindowMode};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use crate::plugins::audio::GameAudioEntity;
+use crate::plugins::input_action::InputBindings;
+use crate::plugins::localization::{Language, UiText};
+
+const SETTINGS_FILE_NAME: &str = "settings.ron";
+/// Platformun ayar klasörü altındaki oyun klasörü
+pub(crate) const CONFIG_DIR_NAME: &str = "vampire-survivals";
+pub const RESOLUTIONS: [(u32, u32); 4] = [(1280, 720), (1600, 900), (1920, 1080), (2560, 1440)];
+pub const SCREEN_SHAKE_STEPS: [f32; 5] = [0.0, 0.25, 0.5, 0.75, 1.0];
+/// Ses ayarları %10'luk adımlarla değişir
+const VOLUME_STEPS: i32 = 10;
+
+/// Oyuncu ayarları: başlangıçta dosyadan yüklenir, ses, pencere, hasar sayıları,
+/// ekran sarsıntısı, menü dili ve tuş atamaları buradan okunur
+pub struct SettingsPlugin;
+
+impl Plugin for SettingsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SettingsFile>();
+        let settings = Settings::from_file(app.world().resource::<SettingsFile>());
+        app.insert_resource(settings)
+            .add_systems(Update, (apply_window_settings, apply_master_volume, apply_playing_sound_volume).run_if(resource_changed::<Settings>))
+            // Ses sink'i PostUpdate'te, Transform yayılımından sonra oluşturulur
+            .add_systems(PostUpdate, apply_sound_volume.before(TransformSystems::Propagate));
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum SettingsError {
+    #[error("could not access settings file: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("could not parse settings: {0}")]
+    Parse(#[from] ron::error::SpannedError),
+    #[error("could not write settings: {0}")]
+    Write(#[from] ron::Error),
+}
+
+/// Ayarların okunup yazıldığı dosya; `None` ise ayarlar sadece bellekte tutulur
+#[derive(Resource, Debug)]
+pub struct SettingsFile {
+    pub path: Option<PathBuf>,
+}
+
+impl Default for SettingsFile {
+    fn default() -> Self {
+        Self { path: config_dir().map(|dir| dir.join(CONFIG_DIR_NAME).join(SETTINGS_FILE_NAME)) }
+    }
+}
+
+/// Windows'ta `%APPDATA%`, macOS'ta `~/Library/Application Support`, diğerlerinde `$XDG_CONFIG_HOME` ya da `~/.config`
+pub(crate) fn config_dir() -> Option<PathBuf> {
+    let home = || env::var_os("HOME").map(PathBuf::from);
+    if cfg!(target_os = "windows") {
+        env::var_os("APPDATA").map(PathBuf::from)
+    } else if cfg!(target_os = "macos") {
+        home().map(|home| home.join("Library").join("Application Support"))
+    } else {
+        env::var_os("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .filter(|dir| dir.is_absolute())
+            .or_else(|| home().map(|home| home.join(".config")))
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, Serialize, Deserialize)]
+pub enum WindowModeSetting {
+    #[default]
+    Windowed,
+    Borderless,
+    Fullscreen,
+}
+
+impl WindowModeSetting {
+    pub fn window_mode(self) -> WindowMode {
+        match self {
+            WindowModeSetting::Windowed => WindowMode::Windowed,
+            WindowModeSetting::Borderless => WindowMode::BorderlessFullscreen(MonitorSelection::Current),
+            WindowModeSetting::Fullscreen => WindowMode::Fullscreen(MonitorSelection::Current, VideoModeSelection::Current),
+        }
+    }
+
+    fn label(self) -> UiText {
+        match self {
+            WindowModeSetting::Windowed => UiText::Windowed,
+            WindowModeSetting::Borderless => UiText::Borderless,
+            WindowModeSetting::Fullscreen => UiText::Fullscreen,
+        }
+    }
+}
+
+/// Ayarlar menüsündeki satırlar
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SettingField {
+    MasterVolume,
+    MusicVolume,
+    SfxVolume,
+    WindowMode,
+    Resolution,
+    VSync,
+    DamageNumbers,
+    ScreenShake,
+    Language,
+}
+
+impl SettingField {
+    pub const ALL: [SettingField; 9] = [
+        SettingField::MasterVolume,
+        SettingField::MusicVolume,
+        SettingField::SfxVolume,
+        SettingField::WindowMode,
+        SettingField::Resolution,
+        SettingField::VSync,
+        SettingField::DamageNumbers,
+        SettingField::ScreenShake,
+        SettingField::Language,
+    ];
+
+    pub fn label(self) -> UiText {
+        match self {
+            SettingField::MasterVolume => UiText::MasterVolume,
+            SettingField::MusicVolume => UiText::MusicVolume,
+            SettingField::SfxVolume => UiText::SfxVolume,
+            SettingField::WindowMode => UiText::WindowMode,
+            SettingField::Resolution => UiText::Resolution,
+            SettingField::VSync => UiText::VSync,
+            SettingField::DamageNumbers => UiText::DamageNumbers,
+            SettingField::ScreenShake => UiText::ScreenShake,
+            SettingField::Language => UiText::Language,
+        }
+    }
+}
+
+/// Dosyada eksik alanlar varsayılanla doldurulur - eski ayar dosyaları da okunur
+#[derive(Resource, Clone, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// 0-1 arası
+    pub master_volume: f32,
+    /// Müzik parçaları (`GameAudioEntity`)
+    pub music_volume: f32,
+    /// Diğer tüm sesler
+    pub sfx_volume: f32,
+    pub window_mode: WindowModeSetting,
+    pub resolution: (u32, u32),
+    pub vsync: bool,
+    pub damage_numbers: bool,
+    /// Sarsıntı şiddeti çarpanı, 0 = kapalı
+    pub screen_shake: f32,
+    pub language: Language,
+    pub bindings: InputBindings,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            master_volume: 1.0,
+            music_volume: 0.7,
+            sfx_volume: 0.8,
+            window_mode: WindowModeSetting::Windowed,
+            resolution: RESOLUTIONS[0],
+            vsync: true,
+            damage_numbers: true,
+            screen_shake: 1.0,
+            language: Language::English,
+            bindings: InputBindings::default(),
+        }
+    }
+}
+
+impl Settings {
+    /// Müzik ya da efekt kanalının ses seviyesi
+    fn channel_volume(&self, music: bool) -> f32 {
+        if music { self.music_volume } else { self.sfx_volume }
+    }
+
+    /// Başlangıçta: dosya yoksa ya da okunamazsa varsayılanlar
+    pub fn from_file(file: &SettingsFile) -> Self {
+        let Some(path) = &file.path else {
+            return Self::default();
+        };
+        match Self::load(path) {
+            Ok(settings) => {
+                println!("⚙️ Settings loaded: {}", path.display());
+                settings
+            }
+            // İlk açılış
+            Err(SettingsError::Io(err)) if err.kind() == std::io::ErrorKind::NotFound => Self::default(),
+            Err(err) => {
+                warn!("{}: {} - using default settings", path.display(), err);
+                Self::default()
+            }
+        }
+    }
+
+    pub fn load(path: &Path) -> Result<Self, SettingsError> {
+        let settings: Settings = ron::from_str(&fs::read_to_string(path)?)?;
+        Ok(settings.sanitized())
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), SettingsError> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())?)?;
+        Ok(())
+    }
+
+    /// Elle düzenlenmiş dosyadaki aralık dışı değerleri düzelt
+    fn sanitized(mut self) -> Self {
+        for volume in [&mut self.master_volume, &mut self.music_volume, &mut self.sfx_volume, &mut self.screen_shake] {
+            *volume = if volume.is_finite() { volume.clamp(0.0, 1.0) } else { 1.0 };
+        }
+        self.bindings.fill_missing();
+        let deadzone = self.bindings.stick_deadzone;
+        self.bindings.stick_deadzone = if deadzone.is_finite() { deadzone.clamp(0.0, 0.9) } else { InputBindings::default().stick_deadzone };
+        if self.resolution.0 == 0 || self.resolution.1 == 0 {
+            self.resolution = RESOLUTIONS[0];
+        }
+        self
+    }
+
+    /// Menüde satıra tıklanınca sıradaki değere geç; sonuncudan sonra başa döner
+    pub fn cycle(&mut self, field: SettingField) {
+        fn next<T: PartialEq + Copy>(options: &[T], current: T) -> T {
+            let index = options.iter().position(|option| *option == current).map_or(0, |index| (index + 1) % options.len());
+            options[index]
+        }
+        let next_volume = |volume: f32| {
+            let step = ((volume * VOLUME_STEPS as f32).round() as i32 + 1) % (VOLUME_STEPS + 1);
+            step as f32 / VOLUME_STEPS as f32
+        };
+        match field {
+            SettingField::MasterVolume => self.master_volume = next_volume(self.master_volume),
+            SettingField::MusicVolume => self.music_volume = next_volume(self.music_volume),
+            SettingField::SfxVolume => self.sfx_volume = next_volume(self.sfx_volume),
+            SettingField::WindowMode => {
+                self.window_mode = next(&[WindowModeSetting::Windowed, WindowModeSetting::Borderless, WindowModeSetting::Fullscreen], self.window_mode)
+            }
+            SettingField::Resolution => self.resolution = next(&RESOLUTIONS, self.resolution),
+            SettingField::VSync => self.vsync = !self.vsync,
+            SettingField::DamageNumbers => self.damage_numbers = !self.damage_numbers,
+            SettingField::ScreenShake => self.screen_shake = next(&SCREEN_SHAKE_STEPS, self.screen_shake),
+            SettingField::Language => self.language = next(&Language::ALL, self.language),
+        }
+    }
+
+    /// Satırın o anki değeri, seçili dilde
+    pub fn value_label(&self, field: SettingField) -> String {
+        let percent = |value: f32| format!("{}%", (value * 100.0).round() as i32);
+        let on_off = |value: bool| if value { UiText::On } else { UiText::Off }.get(self.language).to_string();
+        match field {
+            SettingField::MasterVolume => percent(self.master_volume),
+            SettingField::MusicVolume => percent(self.music_volume),
+            SettingField::SfxVolume => percent(self.sfx_volume),
+            SettingField::WindowMode => self.window_mode.label().get(self.language).to_string(),
+            SettingField::Resolution => format!("{}x{}", self.resolution.0, self.resolution.1),
+            SettingField::VSync => on_off(self.vsync),
+            SettingField::DamageNumbers => on_off(self.damage_numbers),
+            SettingField::ScreenShake => percent(self.screen_shake),
+            SettingField::Language => self.language.name().to_string(),
+        }
+    }
+}
+
+/// Headless'ta pencere yok - sistem atlanır
+fn apply_window_settings(settings: Res<Settings>, mut window: Single<&mut Window, With<PrimaryWindow>>) {
+    let mode = settings.window_mode.window_mode();
+    if window.mode != mode {
+        window.mode = mode;
+    }
+    let (width, height) = settings.resolution;
+    if window.resolution.physical_width() != width || window.resolution.physical_height() != height {
+        window.resolution.set_physical_resolution(width, height);
+    }
+    let present_mode = if settings.vsync { PresentMode::AutoVsync } else { PresentMode::AutoNoVsync };
+    if window.present_mode != present_mode {
+        window.present_mode = present_mode;
+    }
+}
+
+fn apply_master_volume(settings: Res<Settings>, global_volume: Option<ResMut<GlobalVolume>>) {
+    if let Some(mut global_volume) = global_volume {
+        global_volume.volume = Volume::Linear(settings.master_volume);
+    }
+}
+
+/// Yeni çalınan seslere müzik ya da efekt ses seviyesini ver
+fn apply_sound_volume(settings: Res<Settings>, mut sounds: Query<(&mut PlaybackSettings, Has<GameAudioEntity>), Added<AudioPlayer>>) {
+    for (mut playback, music) in sounds.iter_mut() {
+        playback.volume = Volume::Linear(settings.channel_volume(music));
+    }
+}
+
+/// Çalmakta olan sesler (döngüdeki müzik dahil) ayar değişince güncellenir.
+/// Sink oluşurken ana ses seviyesiyle çarpıldığı için burada da çarpılır
+fn apply_playing_sound_volume(settings: Res<Settings>, mut sinks: Query<(&mut AudioSink, Has<GameAudioEntity>)>) {
+    for (mut sink, music) in sinks.iter_mut() {
+        sink.set_volume(Volume::Linear(settings.master_volume * settings.channel_volume(music)));
+    }
+}
@@ -0,0 +1,277 @@
+use bevy::prelude::*;
+use bevy::ui::FocusPolicy;
+use crate::plugins::game_state::GameState;
+use crate::plugins::input_action::{key_name, ActionState, InputAction, InputBindings};
+use crate::plugins::localization::{Localized, UiText};
+use crate::plugins::main_menu::{button_hover_effect, spawn_button};
+use crate::plugins::settings::{SettingField, Settings, SettingsFile};
+
+/// Ana menünün ve duraklatma menüsünün üstünde açılan ayarlar ekranı.
+/// Her satıra tıklamak sıradaki değere geçer; kontrol satırına tıklayınca basılan ilk tuş
+/// o eyleme atanır. Ekran kapanınca ayarlar dosyaya yazılır.
+pub struct SettingsMenuPlugin;
+
+impl Plugin for SettingsMenuPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Rebinding>()
+            .add_systems(
+                Update,
+                (
+                    handle_settings_buttons,
+                    // Tuş beklerken Escape atamayı iptal eder, ekranı kapatmaz
+                    close_settings_on_escape.run_if(not(waiting_for_key)),
+                    capture_rebind_key.after(close_settings_on_escape).run_if(waiting_for_key),
+                    button_hover_effect::<SettingsButton>,
+                    update_setting_values,
+                    update_binding_values,
+                )
+                    .run_if(settings_menu_open),
+            )
+            .add_systems(OnExit(GameState::MainMenu), close_settings_menu)
+            .add_systems(OnExit(GameState::Paused), close_settings_menu);
+    }
+}
+
+#[derive(Component)]
+pub struct SettingsMenuUI;
+
+#[derive(Component, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SettingsButton {
+    Field(SettingField),
+    Binding(InputAction),
+    ResetControls,
+    Back,
+}
+
+/// Satırın değerini gösteren yazı
+#[derive(Component)]
+struct SettingValue(SettingField);
+
+/// Eylemin klavye tuşunu gösteren yazı
+#[derive(Component)]
+struct BindingValue(InputAction);
+
+/// Yeni tuşu beklenen eylem
+#[derive(Resource, Default, Debug)]
+pub struct Rebinding(pub Option<InputAction>);
+
+fn waiting_for_key(rebinding: Res<Rebinding>) -> bool {
+    rebinding.0.is_some()
+}
+
+/// Ayarlar ekranı açıkken alttaki menüler girdi almaz
+pub fn settings_menu_open(menu: Query<(), With<SettingsMenuUI>>) -> bool {
+    !menu.is_empty()
+}
+
+pub fn open_settings_menu(commands: &mut Commands, asset_server: &AssetServer) {
+    let font = asset_server.load("fonts/FiraMono-Medium.ttf");
+    commands.insert_resource(Rebinding::default());
+
+    commands.spawn((
+        SettingsMenuUI,
+        Node {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            position_type: PositionType::Absolute,
+            flex_direction: FlexDirection::Column,
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            ..default()
+        },
+        BackgroundColor(Color::srgb(0.1, 0.1, 0.1)),
+        // Alttaki menünün düğmeleri tıklanmasın
+        FocusPolicy::Block,
+        GlobalZIndex(20),
+    )).with_children(|parent| {
+        parent.spawn((
+            Localized(UiText::Settings),
+            TextFont {
+                font: font.clone(),
+                font_size: 50.0,
+                ..default()
+            },
+            Node {
+                margin: UiRect::bottom(Val::Px(30.0)),
+                ..default()
+            },
+        ));
+        // Solda ayarlar, sağda kontroller
+        parent.spawn(Node {
+            column_gap: Val::Px(60.0),
+            margin: UiRect::bottom(Val::Px(20.0)),
+            ..default()
+        }).with_children(|columns| {
+            columns.spawn(Node {
+                flex_direction: FlexDirection::Column,
+                ..default()
+            }).with_children(|column| {
+                for field in SettingField::ALL {
+                    spawn_row(column, field.label(), SettingsButton::Field(field), SettingValue(field), &font);
+                }
+            });
+            columns.spawn(Node {
+                flex_direction: FlexDirection::Column,
+                ..default()
+            }).with_children(|column| {
+                column.spawn((
+                    Localized(UiText::Controls),
+                    TextFont {
+                        font: font.clone(),
+                        font_size: 28.0,
+                        ..default()
+                    },
+                ));
+                for action in InputAction::ALL {
+                    spawn_row(column, action.label(), SettingsButton::Binding(action), BindingValue(action), &font);
+                }
+            });
+        });
+        parent.spawn(Node {
+            column_gap: Val::Px(20.0),
+            ..default()
+        }).with_children(|buttons| {
+            spawn_button(buttons, UiText::ResetControls, SettingsButton::ResetControls, font.clone());
+            spawn_button(buttons, UiText::Back, SettingsButton::Back, font.clone());
+        });
+    });
+}
+
+/// Solda çevrilen isim, sağda değeri gösteren düğme
+pub fn spawn_row(parent: &mut ChildSpawnerCommands, label: UiText, button: impl Component, value: impl Component, font: &Handle<Font>) {
+    parent.spawn(Node {
+        align_items: AlignItems::Center,
+        margin: UiRect::vertical(Val::Px(4.0)),
+        ..default()
+    }).with_children(|row| {
+        row.spawn((
+            Localized(label),
+            TextFont {
+                font: font.clone(),
+                font_size: 24.0,
+                ..default()
+            },
+            Node {
+                width: Val::Px(240.0),
+                ..default()
+            },
+        ));
+        row.spawn((
+            Button,
+            button,
+            Node {
+                width: Val::Px(220.0),
+                height: Val::Px(40.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.3, 0.3, 0.3)),
+        )).with_children(|button| {
+            button.spawn((
+                value,
+                Text::default(),
+                TextFont {
+                    font: font.clone(),
+                    font_size: 24.0,
+                    ..default()
+                },
+            ));
+        });
+    });
+}
+
+fn handle_settings_buttons(
+    mut commands: Commands,
+    interactions_q: Query<(&Interaction, &SettingsButton), Changed<Interaction>>,
+    mut settings: ResMut<Settings>,
+    mut rebinding: ResMut<Rebinding>,
+    menu: Query<Entity, With<SettingsMenuUI>>,
+    file: Res<SettingsFile>,
+) {
+    for (interaction, button) in &interactions_q {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        match button {
+            SettingsButton::Field(field) => settings.cycle(*field),
+            SettingsButton::Binding(action) => rebinding.0 = Some(*action),
+            SettingsButton::ResetControls => {
+                rebinding.0 = None;
+                settings.bindings = InputBindings::default();
+            }
+            SettingsButton::Back => save_and_close(&mut commands, &menu, &settings, &file),
+        }
+    }
+}
+
+fn close_settings_on_escape(
+    mut commands: Commands,
+    actions: Res<ActionState>,
+    settings: Res<Settings>,
+    menu: Query<Entity, With<SettingsMenuUI>>,
+    file: Res<SettingsFile>,
+) {
+    if actions.just_pressed(InputAction::Cancel) {
+        save_and_close(&mut commands, &menu, &settings, &file);
+    }
+}
+
+/// Basılan ilk tuşu bekleyen eyleme ata; Escape iptal eder
+fn capture_rebind_key(keyboard: Res<ButtonInput<KeyCode>>, mut rebinding: ResMut<Rebinding>, mut settings: ResMut<Settings>) {
+    let (Some(action), Some(key)) = (rebinding.0, keyboard.get_just_pressed().next().copied()) else {
+        return;
+    };
+    rebinding.0 = None;
+    if key != KeyCode::Escape {
+        settings.bindings.rebind(action, key);
+        println!("🎮 {:?} bound to {}", action, key_name(key));
+    }
+}
+
+/// Menü açıkken state değişirse kapat ve kaydet
+fn close_settings_menu(
+    mut commands: Commands,
+    settings: Res<Settings>,
+    menu: Query<Entity, With<SettingsMenuUI>>,
+    file: Res<SettingsFile>,
+) {
+    save_and_close(&mut commands, &menu, &settings, &file);
+}
+
+fn save_and_close(commands: &mut Commands, menu: &Query<Entity, With<SettingsMenuUI>>, settings: &Settings, file: &SettingsFile) {
+    if menu.is_empty() {
+        return;
+    }
+    for entity in menu {
+        commands.entity(entity).despawn();
+    }
+    let Some(path) = &file.path else {
+        return;
+    };
+    match settings.save(path) {
+        Ok(()) => println!("💾 Settings saved: {}", path.display()),
+        Err(err) => error!("{}: {}", path.display(), err),
+    }
+}
+
+fn update_binding_values(settings: Res<Settings>, rebinding: Res<Rebinding>, mut values: Query<(Ref<BindingValue>, &mut Text)>) {
+    for (value, mut text) in values.iter_mut() {
+        if !(settings.is_changed() || rebinding.is_changed() || value.is_added()) {
+            continue;
+        }
+        text.0 = if rebinding.0 == Some(value.0) {
+            UiText::PressKey.get(settings.language).to_string()
+        } else {
+            settings.bindings.primary_key(value.0).map_or_else(|| "-".to_string(), key_name)
+        };
+    }
+}
+
+fn update_setting_values(settings: Res<Settings>, mut values: Query<(Ref<SettingValue>, &mut Text)>) {
+    for (value, mut text) in values.iter_mut() {
+        if settings.is_changed() || value.is_added() {
+            text.0 = settings.value_label(value.0);
+        }
+    }
+}
@@ -0,0 +1,234 @@
+use std::collections::BTreeMap;
+use bevy::audio::AudioPlayer;
+use bevy::input::InputSystems;
+use bevy::prelude::*;
+use crate::plugins::chest_reward::rewards_granted;
+use crate::plugins::enemy::EnemyDiedEvent;
+use crate::plugins::game_state::{gameplay_tick, GameState};
+use crate::plugins::input_action::InputActionSystems;
+use crate::plugins::player::Player;
+use crate::plugins::profile::ProfileFile;
+use crate::plugins::run_save::RunSaveFile;
+use crate::plugins::replay::{live_input, ReplaySession};
+use crate::plugins::rng::GameRng;
+use crate::plugins::settings::SettingsFile;
+use crate::plugins::spatial_grid::{SpatialGrid, SpatialLayer};
+use crate::plugins::weapon_definition::WeaponId;
+use crate::plugins::weapon_upgrade::{apply_weapon_upgrade, show_upgrade_choices_on_level_up, UpgradeChoices, UpgradeKind, UpgradeSelectedEvent};
+use crate::plugins::weapons::WeaponHitEvent;
+
+/// Bot bu yarıçaptaki düşmanlardan kaçar
+const THREAT_RADIUS: f32 = 220.0;
+
+/// `--simulate <dakika>`
+#[derive(Resource, Clone, Copy, Debug)]
+pub struct SimulationSettings {
+    pub minutes: f32,
+}
+
+impl SimulationSettings {
+    pub fn from_args(args: impl IntoIterator<Item = String>) -> Option<Self> {
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            if arg != "--simulate" {
+                continue;
+            }
+            return match args.next().map(|value| value.parse::<f32>()) {
+                Some(Ok(minutes)) if minutes > 0.0 => Some(Self { minutes }),
+                _ => {
+                    eprintln!("--simulate expects a positive number of minutes");
+                    None
+                }
+            };
+        }
+        None
+    }
+}
+
+/// Gerçek oyun sistemlerini botla `minutes` dakika koşturup rapor basan plugin.
+/// `HeadlessPlugin` ile birlikte kullanılır.
+pub struct SimulationPlugin(pub SimulationSettings);
+
+impl Plugin for SimulationPlugin {
+    fn build(&self, app: &mut App) {
+        // Oyuncunun kayıtlı ayarları (tuş atamaları dahil) ve güçlendirmeleri botu etkilemesin
+        app.insert_resource(self.0)
+            .insert_resource(SettingsFile { path: None })
+            .insert_resource(ProfileFile { path: None })
+            .insert_resource(RunSaveFile { path: None })
+            .init_resource::<SimulationReport>()
+            .add_systems(Startup, start_simulation)
+            .add_systems(PreUpdate, drive_bot.after(InputSystems).before(InputActionSystems).run_if(in_state(GameState::Playing)))
+            .add_systems(FixedPostUpdate, track_player.run_if(gameplay_tick))
+            .add_systems(
+                Update,
+                (
+                    choose_bot_upgrade
+                        .after(show_upgrade_choices_on_level_up)
+                        .before(apply_weapon_upgrade)
+                        .run_if(in_state(GameState::UpgradeSelection).and(live_input)),
+                    leave_chest_reward.run_if(in_state(GameState::ChestReward).and(live_input).and(rewards_granted)),
+                    tally_weapon_hits,
+                    tally_kills,
+                    drop_audio,
+                    end_on_time_limit.run_if(in_state(GameState::Playing)),
+                ),
+            )
+            .add_systems(OnEnter(GameState::GameOver), end_on_death);
+    }
+}
+
+#[derive(Default, Debug, Clone, Copy)]
+pub struct WeaponReport {
+    pub damage: i64,
+    pub kills: u32,
+    pub crits: u32,
+}
+
+/// Simülasyon boyunca toplanan istatistikler
+#[derive(Resource, Default, Debug)]
+pub struct SimulationReport {
+    pub weapons: BTreeMap<WeaponId, WeaponReport>,
+    pub damage_taken: u32,
+    pub level: i32,
+    last_health: Option<u32>,
+}
+
+fn start_simulation(settings: Res<SimulationSettings>, mut next_state: ResMut<NextState<GameState>>) {
+    println!("🤖 Simulating {} minute(s)", settings.minutes);
+    next_state.set(GameState::Loading);
+}
+
+/// Yakındaki düşmanlardan uzaklaş, tehlike yoksa en yakın XP'ye / sandığa yürü.
+/// Girdiler klavye üzerinden verilir; böylece oyun ve replay kaydı gerçek oyuncudaki gibi çalışır.
+fn drive_bot(
+    mut keyboard: ResMut<ButtonInput<KeyCode>>,
+    player: Single<&Transform, With<Player>>,
+    grid: Res<SpatialGrid>,
+) {
+    let position = player.translation.truncate();
+    let mut away = Vec2::ZERO;
+    for entry in grid.query_radius(position, THREAT_RADIUS, SpatialLayer::Enemy) {
+        let offset = position - entry.center();
+        away += offset / offset.length_squared().max(1.0);
+    }
+    let direction = if away != Vec2::ZERO {
+        away.normalize()
+    } else if let Some(pickup) = grid.nearest(position, SpatialLayer::Collectible) {
+        (pickup.center() - position).normalize_or_zero()
+    } else {
+        Vec2::ZERO
+    };
+
+    for (key, pressed) in [
+        (KeyCode::KeyW, direction.y > 0.38),
+        (KeyCode::KeyS, direction.y < -0.38),
+        (KeyCode::KeyA, direction.x < -0.38),
+        (KeyCode::KeyD, direction.x > 0.38),
+    ] {
+        if pressed {
+            keyboard.press(key);
+        } else {
+            keyboard.release(key);
+        }
+    }
+}
+
+/// Önce sahip olunan silahları yükselt, sonra yeni silah, en son pasif
+fn choose_bot_upgrade(
+    choices: Res<UpgradeChoices>,
+    mut upgrade_events: MessageWriter<UpgradeSelectedEvent>,
+) {
+    if !choices.waiting_for_choice {
+        return;
+    }
+    let choice = choices.options.iter().min_by_key(|option| match option.kind {
+        UpgradeKind::WeaponLevel(_) => 0,
+        UpgradeKind::NewWeapon(_) => 1,
+        UpgradeKind::Passive(_) => 2,
+    });
+    if let Some(option) = choice {
+        upgrade_events.write(UpgradeSelectedEvent { kind: option.kind.clone() });
+    }
+}
+
+/// Bot ödül animasyonunu beklemez
+fn leave_chest_reward(mut next_state: ResMut<NextState<GameState>>) {
+    next_state.set(GameState::Playing);
+}
+
+fn tally_weapon_hits(mut hits: MessageReader<WeaponHitEvent>, mut report: ResMut<SimulationReport>) {
+    for hit in hits.read() {
+        let weapon = report.weapons.entry(hit.weapon.clone()).or_default();
+        weapon.damage += hit.damage as i64;
+        weapon.crits += hit.crit as u32;
+    }
+}
+
+fn tally_kills(mut died_events: MessageReader<EnemyDiedEvent>, mut report: ResMut<SimulationReport>) {
+    for killer in died_events.read().filter_map(|event| event.killer.clone()) {
+        report.weapons.entry(killer).or_default().kills += 1;
+    }
+}
+
+/// Can düşüşlerini alınan hasar olarak say, seviyeyi takip et
+fn track_player(players: Query<&Player>, mut report: ResMut<SimulationReport>) {
+    let Ok(player) = players.single() else {
+        return;
+    };
+    if let Some(last) = report.last_health
+        && player.health < last
+    {
+        report.damage_taken += last - player.health;
+    }
+    report.last_health = Some(player.health);
+    report.level = player.level;
+}
+
+/// Ses çalınmadığı için `PlaybackSettings::DESPAWN` entity'leri kendiliğinden silinmez
+fn drop_audio(mut commands: Commands, sounds: Query<Entity, With<AudioPlayer>>) {
+    for entity in &sounds {
+        commands.entity(entity).despawn();
+    }
+}
+
+fn end_on_time_limit(
+    settings: Res<SimulationSettings>,
+    session: Res<ReplaySession>,
+    fixed_time: Res<Time<Fixed>>,
+    report: Res<SimulationReport>,
+    rng: Res<GameRng>,
+    mut exit: MessageWriter<AppExit>,
+) {
+    let survived = session.tick as f32 * fixed_time.timestep().as_secs_f32();
+    if survived >= settings.minutes * 60.0 {
+        print_report(&report, survived, false, rng.seed());
+        exit.write(AppExit::Success);
+    }
+}
+
+fn end_on_death(
+    session: Res<ReplaySession>,
+    fixed_time: Res<Time<Fixed>>,
+    report: Res<SimulationReport>,
+    rng: Res<GameRng>,
+    mut exit: MessageWriter<AppExit>,
+) {
+    let survived = session.tick as f32 * fixed_time.timestep().as_secs_f32();
+    print_report(&report, survived, true, rng.seed());
+    exit.write(AppExit::Success);
+}
+
+fn print_report(report: &SimulationReport, survived: f32, died: bool, seed: u64) {
+    let minutes = (survived / 60.0).floor();
+    let seconds = survived - minutes * 60.0;
+    println!("==== Simulation report (seed {}) ====", seed);
+    println!("Survived:     {:02}:{:04.1}{}", minutes, seconds, if died { " (died)" } else { "" });
+    println!("Level:        {}", report.level);
+    println!("Damage taken: {}", report.damage_taken);
+    println!("{:<18}{:>8}{:>12}{:>10}{:>8}", "Weapon", "Kills", "Damage", "DPS", "Crits");
+    for (weapon, stats) in &report.weapons {
+        let dps = stats.damage as f32 / survived.max(1.0);
+        println!("{:<18}{:>8}{:>12}{:>10.1}{:>8}", weapon.to_string(), stats.kills, stats.damage, dps, stats.crits);
+    }
+}
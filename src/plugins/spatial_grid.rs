@@ -0,0 +1,229 @@
+use std::collections::HashMap;
+use bevy::ecs::query::QueryData;
+use bevy::prelude::*;
+use crate::plugins::aabb::AABB;
+use crate::plugins::enemy::{Collectible, Enemy};
+use crate::plugins::player::Player;
+
+/// Grid'deki bir entity'nin ait olduğu katman - sorgular katmana göre filtrelenir
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SpatialLayer {
+    Player,
+    Enemy,
+    Collectible,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct GridEntry {
+    pub entity: Entity,
+    pub layer: SpatialLayer,
+    pub min: Vec2,
+    pub max: Vec2,
+}
+
+impl GridEntry {
+    pub fn center(&self) -> Vec2 {
+        (self.min + self.max) * 0.5
+    }
+
+    fn intersects(&self, min: Vec2, max: Vec2) -> bool {
+        !(self.min.x > max.x || self.max.x < min.x || self.min.y > max.y || self.max.y < min.y)
+    }
+}
+
+/// Tüm AABB'lerin her frame yeniden kurulan uniform hash grid'i.
+/// Sorgular aday entity'leri döner; kesin kontrol component verisiyle yapılır.
+#[derive(Resource)]
+pub struct SpatialGrid {
+    pub cell_size: f32,
+    cells: HashMap<IVec2, Vec<usize>>,
+    entries: Vec<GridEntry>,
+    min_cell: IVec2,
+    max_cell: IVec2,
+}
+
+impl Default for SpatialGrid {
+    fn default() -> Self {
+        Self::new(64.0)
+    }
+}
+
+impl SpatialGrid {
+    pub fn new(cell_size: f32) -> Self {
+        Self {
+            cell_size,
+            cells: HashMap::new(),
+            entries: Vec::new(),
+            min_cell: IVec2::ZERO,
+            max_cell: IVec2::ZERO,
+        }
+    }
+
+    pub fn clear(&mut self) {
+        // Son frame'de kullanılan hücreleri kapasiteleriyle tut, boş kalanları at
+        self.cells.retain(|_, cell| {
+            let used = !cell.is_empty();
+            cell.clear();
+            used
+        });
+        self.entries.clear();
+    }
+
+    fn cell_of(&self, point: Vec2) -> IVec2 {
+        (point / self.cell_size).floor().as_ivec2()
+    }
+
+    pub fn insert(&mut self, entity: Entity, layer: SpatialLayer, aabb: &AABB) {
+        let entry = GridEntry {
+            entity,
+            layer,
+            min: aabb.min(),
+            max: aabb.max(),
+        };
+        let index = self.entries.len();
+        let min_cell = self.cell_of(entry.min);
+        let max_cell = self.cell_of(entry.max);
+        if self.entries.is_empty() {
+            self.min_cell = min_cell;
+            self.max_cell = max_cell;
+        } else {
+            self.min_cell = self.min_cell.min(min_cell);
+            self.max_cell = self.max_cell.max(max_cell);
+        }
+        self.entries.push(entry);
+        for x in min_cell.x..=max_cell.x {
+            for y in min_cell.y..=max_cell.y {
+                self.cells.entry(IVec2::new(x, y)).or_default().push(index);
+            }
+        }
+    }
+
+    /// Verilen kutuyla kesişen, istenen katmandaki entity'ler
+    pub fn query_aabb(&self, min: Vec2, max: Vec2, layer: SpatialLayer) -> Vec<GridEntry> {
+        let mut found: Vec<usize> = Vec::new();
+        let min_cell = self.cell_of(min);
+        let max_cell = self.cell_of(max);
+        for x in min_cell.x..=max_cell.x {
+            for y in min_cell.y..=max_cell.y {
+                let Some(cell) = self.cells.get(&IVec2::new(x, y)) else {
+                    continue;
+                };
+                for &index in cell {
+                    let entry = &self.entries[index];
+                    if entry.layer == layer && entry.intersects(min, max) {
+                        found.push(index);
+                    }
+                }
+            }
+        }
+        // Birden fazla hücreye yayılan entity'ler bir kere dönsün
+        found.sort_unstable();
+        found.dedup();
+        found.into_iter().map(|index| self.entries[index]).collect()
+    }
+
+    /// Merkezi `radius` içinde kalan entity'ler
+    pub fn query_radius(&self, center: Vec2, radius: f32, layer: SpatialLayer) -> Vec<GridEntry> {
+        let mut found = self.query_aabb(center - Vec2::splat(radius), center + Vec2::splat(radius), layer);
+        found.retain(|entry| entry.center().distance_squared(center) <= radius * radius);
+        found
+    }
+
+    /// Merkezi `point`'e en yakın entity - hücre halkaları dışa doğru taranır
+    pub fn nearest(&self, point: Vec2, layer: SpatialLayer) -> Option<GridEntry> {
+        if self.entries.is_empty() {
+            return None;
+        }
+        let origin = self.cell_of(point);
+        let max_ring = (origin - self.min_cell).abs().max((self.max_cell - origin).abs()).max_element();
+
+        let mut best: Option<(f32, usize)> = None;
+        for ring in 0..=max_ring {
+            // Bu halkadaki en yakın nokta bile mevcut en iyiden uzaksa dur
+            if let Some((best_dist_sq, _)) = best {
+                let ring_dist = (ring - 1).max(0) as f32 * self.cell_size;
+                if ring_dist * ring_dist > best_dist_sq {
+                    break;
+                }
+            }
+            for x in -ring..=ring {
+                for y in -ring..=ring {
+                    if x.abs() != ring && y.abs() != ring {
+                        continue;
+                    }
+                    let Some(cell) = self.cells.get(&(origin + IVec2::new(x, y))) else {
+                        continue;
+                    };
+                    for &index in cell {
+                        let entry = &self.entries[index];
+                        if entry.layer != layer {
+                            continue;
+                        }
+                        let dist_sq = entry.center().distance_squared(point);
+                        if best.is_none_or(|(best_dist_sq, _)| dist_sq < best_dist_sq) {
+                            best = Some((dist_sq, index));
+                        }
+                    }
+                }
+            }
+        }
+        best.map(|(_, index)| self.entries[index])
+    }
+
+    /// Kutuları kesişen, aynı katmandaki her entity çifti bir kere
+    pub fn overlapping_pairs(&self, layer: SpatialLayer) -> Vec<(GridEntry, GridEntry)> {
+        let mut pairs = Vec::new();
+        for (index, entry) in self.entries.iter().enumerate() {
+            if entry.layer != layer {
+                continue;
+            }
+            let min_cell = self.cell_of(entry.min);
+            let max_cell = self.cell_of(entry.max);
+            let mut others: Vec<usize> = Vec::new();
+            for x in min_cell.x..=max_cell.x {
+                for y in min_cell.y..=max_cell.y {
+                    let Some(cell) = self.cells.get(&IVec2::new(x, y)) else {
+                        continue;
+                    };
+                    others.extend(cell.iter().copied().filter(|&other| other > index));
+                }
+            }
+            others.sort_unstable();
+            others.dedup();
+            for other in others {
+                let other_entry = &self.entries[other];
+                if other_entry.layer == layer && other_entry.intersects(entry.min, entry.max) {
+                    pairs.push((*entry, *other_entry));
+                }
+            }
+        }
+        pairs
+    }
+}
+
+/// Grid'e girebilecek bir entity; katmanı marker'larından seçilir
+#[derive(QueryData)]
+pub struct GridMember {
+    entity: Entity,
+    aabb: &'static AABB,
+    player: Has<Player>,
+    enemy: Has<Enemy>,
+    collectible: Has<Collectible>,
+}
+
+/// Her frame başında grid'i AABB component'lerinden yeniden kur
+pub fn rebuild_spatial_grid(mut grid: ResMut<SpatialGrid>, query: Query<GridMember>) {
+    grid.clear();
+    for member in query.iter() {
+        let layer = if member.enemy {
+            SpatialLayer::Enemy
+        } else if member.collectible {
+            SpatialLayer::Collectible
+        } else if member.player {
+            SpatialLayer::Player
+        } else {
+            continue;
+        };
+        grid.insert(member.entity, layer, member.aabb);
+    }
+}
@@ -0,0 +1,242 @@
+use std::f32::consts::{PI, TAU};
+use bevy::prelude::*;
+use rand::Rng;
+use serde::Deserialize;
+use crate::plugins::enemy::{spawn_enemy, Enemy, EnemySpawner};
+use crate::plugins::enemy_kind::EnemyKind;
+use crate::plugins::player::Player;
+use crate::plugins::rng::RngStream;
+use crate::plugins::ron_asset::{RonAsset, RonAssetAppExt};
+
+/// Düşmanların oyuncudan ne kadar uzakta belireceği
+const SPAWN_DISTANCE_MIN: f32 = 500.0;
+const SPAWN_DISTANCE_MAX: f32 = 800.0;
+
+/// `assets/data/*.waves.ron` zaman çizelgesini yükleyen ve spawn'ları yöneten plugin
+pub struct SpawnDirectorPlugin;
+
+impl Plugin for SpawnDirectorPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_ron_asset::<WaveTimeline>()
+            .init_resource::<SpawnDirector>();
+    }
+}
+
+/// Koşunun dakika dakika spawn planı
+#[derive(Asset, TypePath, Debug, Clone, Deserialize)]
+pub struct WaveTimeline {
+    pub minutes: Vec<WaveMinute>,
+    #[serde(default)]
+    pub events: Vec<ScheduledSpawn>,
+}
+
+/// `minute`'ten bir sonraki girişe kadar geçerli kurallar
+#[derive(Debug, Clone, Deserialize)]
+pub struct WaveMinute {
+    pub minute: u32,
+    /// Düşman statlarının çarpanı
+    pub level: i32,
+    /// Aynı anda yaşayabilecek en fazla düşman
+    pub max_enemies: usize,
+    pub spawns: Vec<SpawnRule>,
+}
+
+/// Her `interval` saniyede `formation` düzeninde `count` tane `kind`
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct SpawnRule {
+    pub kind: EnemyKind,
+    pub interval: f32,
+    pub count: u32,
+    pub formation: Formation,
+}
+
+/// Belirli bir saniyede bir kere tetiklenen spawn - üst sınırı yok sayar
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct ScheduledSpawn {
+    pub time: f32,
+    pub kind: EnemyKind,
+    pub count: u32,
+    pub formation: Formation,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+pub enum Formation {
+    /// Her düşman oyuncunun etrafında rastgele bir noktada
+    Random,
+    /// Ekran dışındaki bir noktanın etrafında halka şeklinde bir grup
+    Ring { radius: f32 },
+    /// Oyuncuya doğru gelen bir sıra
+    Line { spacing: f32 },
+    /// Ekran dışındaki bir noktada küme
+    Cluster { radius: f32 },
+    /// Oyuncuyu her yönden saran çember
+    Surround { radius: f32 },
+}
+
+impl RonAsset for WaveTimeline {
+    const EXTENSIONS: &'static [&'static str] = &["waves.ron"];
+
+    fn validate(&self) -> Result<(), String> {
+        let Some(first) = self.minutes.first() else {
+            return Err("timeline needs at least one minute".to_string());
+        };
+        if first.minute != 0 {
+            return Err(format!("first minute must be 0, got {}", first.minute));
+        }
+        for pair in self.minutes.windows(2) {
+            if pair[1].minute <= pair[0].minute {
+                return Err(format!("minutes must be ascending: {} after {}", pair[1].minute, pair[0].minute));
+            }
+        }
+        for minute in &self.minutes {
+            if minute.level < 1 {
+                return Err(format!("minute {}: level must be at least 1", minute.minute));
+            }
+            if minute.max_enemies == 0 {
+                return Err(format!("minute {}: max_enemies must be positive", minute.minute));
+            }
+            for rule in &minute.spawns {
+                if rule.interval <= 0.0 {
+                    return Err(format!("minute {}: {:?} interval must be positive", minute.minute, rule.kind));
+                }
+                if rule.count == 0 {
+                    return Err(format!("minute {}: {:?} count must be positive", minute.minute, rule.kind));
+                }
+            }
+        }
+        for pair in self.events.windows(2) {
+            if pair[1].time < pair[0].time {
+                return Err(format!("events must be sorted by time: {} after {}", pair[1].time, pair[0].time));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Koşu boyunca zaman çizelgesini takip eden durum
+#[derive(Resource)]
+pub struct SpawnDirector {
+    pub timeline: Handle<WaveTimeline>,
+    pub elapsed: f32,
+    pub minute_index: Option<usize>,
+    pub spawn_timers: Vec<Timer>,
+    pub next_event: usize,
+    pub level: i32,
+}
+
+impl FromWorld for SpawnDirector {
+    fn from_world(world: &mut World) -> Self {
+        let asset_server = world.get_resource::<AssetServer>()
+            .expect("AssetServer resource not found.");
+        Self {
+            timeline: asset_server.load("data/default.waves.ron"),
+            elapsed: 0.0,
+            minute_index: None,
+            spawn_timers: Vec::new(),
+            next_event: 0,
+            level: 1,
+        }
+    }
+}
+
+impl SpawnDirector {
+    /// Yeni koşu için baştan başla, yüklü zaman çizelgesini koru
+    pub fn reset(&mut self) {
+        self.elapsed = 0.0;
+        self.minute_index = None;
+        self.spawn_timers.clear();
+        self.next_event = 0;
+        self.level = 1;
+    }
+}
+
+/// Formasyondaki her düşmanın dünya konumu
+pub fn formation_positions(formation: Formation, count: u32, center: Vec3, rng: &mut impl Rng) -> Vec<Vec3> {
+    let offset = |angle: f32, distance: f32| Vec3::new(angle.cos(), angle.sin(), 0.0) * distance;
+    let group_angle = rng.random_range(0.0..TAU);
+    let group_center = center + offset(group_angle, (SPAWN_DISTANCE_MIN + SPAWN_DISTANCE_MAX) / 2.0);
+
+    (0..count)
+        .map(|i| match formation {
+            Formation::Random => {
+                let distance = rng.random_range(SPAWN_DISTANCE_MIN..SPAWN_DISTANCE_MAX);
+                center + offset(rng.random_range(0.0..TAU), distance)
+            }
+            Formation::Ring { radius } => group_center + offset(TAU * i as f32 / count as f32, radius),
+            Formation::Line { spacing } => {
+                // Oyuncuya bakan yöne dik bir sıra
+                let along = (i as f32 - (count - 1) as f32 / 2.0) * spacing;
+                group_center + offset(group_angle + PI / 2.0, along)
+            }
+            Formation::Cluster { radius } => {
+                let distance = rng.random_range(0.0..radius.max(f32::EPSILON));
+                group_center + offset(rng.random_range(0.0..TAU), distance)
+            }
+            Formation::Surround { radius } => center + offset(group_angle + TAU * i as f32 / count as f32, radius),
+        })
+        .collect()
+}
+
+/// Zaman çizelgesine göre düşman spawn et
+pub fn run_spawn_director(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut director: ResMut<SpawnDirector>,
+    timelines: Res<Assets<WaveTimeline>>,
+    player_query: Query<&Transform, With<Player>>,
+    enemies: Query<(), With<Enemy>>,
+    mut spawner: EnemySpawner,
+) {
+    let Some(timeline) = timelines.get(&director.timeline) else {
+        return;
+    };
+    if !spawner.ready() {
+        return;
+    }
+    let Ok(player_transform) = player_query.single() else {
+        return;
+    };
+    let player_position = player_transform.translation.with_z(0.0);
+
+    director.elapsed += time.delta_secs();
+    let elapsed = director.elapsed;
+
+    // Yeni dakikaya girildiyse kuralları ve zamanlayıcıları değiştir
+    let current = timeline.minutes.iter().rposition(|minute| minute.minute as f32 * 60.0 <= elapsed);
+    if current != director.minute_index {
+        director.minute_index = current;
+        if let Some(minute) = current.map(|index| &timeline.minutes[index]) {
+            director.level = minute.level;
+            director.spawn_timers = minute
+                .spawns
+                .iter()
+                .map(|rule| Timer::from_seconds(rule.interval, TimerMode::Repeating))
+                .collect();
+        }
+    }
+    let Some(minute) = director.minute_index.map(|index| &timeline.minutes[index]) else {
+        return;
+    };
+
+    let level = director.level;
+    let mut alive = enemies.iter().count();
+    for (rule, timer) in minute.spawns.iter().zip(director.spawn_timers.iter_mut()) {
+        timer.tick(time.delta());
+        let waves = timer.times_finished_this_tick();
+        for _ in 0..waves {
+            let count = (rule.count as usize).min(minute.max_enemies.saturating_sub(alive)) as u32;
+            for position in formation_positions(rule.formation, count, player_position, spawner.rng.stream(RngStream::Spawning)) {
+                spawn_enemy(&mut commands, &mut spawner, rule.kind, position, level);
+            }
+            alive += count as usize;
+        }
+    }
+
+    // Zamanı gelen özel spawn'lar
+    while let Some(event) = timeline.events.get(director.next_event).filter(|event| event.time <= elapsed) {
+        for position in formation_positions(event.formation, event.count, player_position, spawner.rng.stream(RngStream::Spawning)) {
+            spawn_enemy(&mut commands, &mut spawner, event.kind, position, level);
+        }
+        director.next_event += 1;
+    }
+}
@@ -0,0 +1,305 @@
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use crate::plugins::damage::{apply_damage, DamageEvent, DamageType};
+use crate::plugins::enemy::{resting_tint, tint_enemy_sprites, Enemy, EnemySprit};
+use crate::plugins::enemy_kind::Charger;
+use crate::plugins::game_state::GameplaySet;
+use crate::plugins::player::Player;
+use crate::plugins::player_damage::{apply_player_damage, PlayerDamageEvent, PlayerDeath};
+use crate::plugins::rng::{GameRng, RngStream};
+use crate::plugins::weapon_definition::WeaponId;
+
+/// Süreli hasarlar bu aralıkla işler
+pub const DOT_INTERVAL: f32 = 0.5;
+
+/// Düşman ve oyuncu üzerindeki süreli etkiler
+pub struct StatusEffectPlugin;
+
+impl Plugin for StatusEffectPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            FixedUpdate,
+            (
+                tick_enemy_status_effects.before(apply_damage),
+                tick_player_status_effects.before(apply_player_damage),
+                tint_enemy_status_effects,
+                tint_player_status_effects,
+            ).chain().in_set(GameplaySet),
+        );
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub enum StatusKind {
+    /// Saniyede `magnitude` hasar, yenilenir
+    Burn,
+    /// Hareket ve animasyon durur
+    Freeze,
+    /// Saniyede `magnitude` hasar, 5 kata kadar birikir
+    Poison,
+    /// Hız `magnitude` oranında düşer (0.4 = %40 yavaş)
+    Slow,
+    /// Saniyede `magnitude` hasar, 10 kata kadar birikir
+    Bleed,
+    /// Alınan hasar `magnitude` oranında artar (0.25 = +%25)
+    Vulnerable,
+}
+
+impl StatusKind {
+    /// Aynı etki tekrar gelince: `None` süreyi yeniler, `Some(n)` n kata kadar biriktirir
+    pub fn max_stacks(self) -> Option<u32> {
+        match self {
+            StatusKind::Poison => Some(5),
+            StatusKind::Bleed => Some(10),
+            _ => None,
+        }
+    }
+
+    pub fn is_damage_over_time(self) -> bool {
+        matches!(self, StatusKind::Burn | StatusKind::Poison | StatusKind::Bleed)
+    }
+
+    /// Süreli hasarın tipi - direnç hesabı için
+    pub fn damage_type(self) -> DamageType {
+        match self {
+            StatusKind::Burn => DamageType::Fire,
+            StatusKind::Poison => DamageType::Poison,
+            _ => DamageType::Physical,
+        }
+    }
+
+    pub fn tint(self) -> Color {
+        match self {
+            StatusKind::Burn => Color::srgb(1.0, 0.55, 0.2),
+            StatusKind::Freeze => Color::srgb(0.55, 0.85, 1.0),
+            StatusKind::Poison => Color::srgb(0.5, 1.0, 0.4),
+            StatusKind::Slow => Color::srgb(0.6, 0.6, 1.0),
+            StatusKind::Bleed => Color::srgb(0.9, 0.2, 0.25),
+            StatusKind::Vulnerable => Color::srgb(0.85, 0.5, 1.0),
+        }
+    }
+}
+
+/// Birden fazla etki varken gösterilen renk bu sıraya göre seçilir
+const TINT_PRIORITY: [StatusKind; 6] = [
+    StatusKind::Freeze,
+    StatusKind::Burn,
+    StatusKind::Poison,
+    StatusKind::Bleed,
+    StatusKind::Slow,
+    StatusKind::Vulnerable,
+];
+
+/// Silah tanımındaki `on_hit` girdisi: isabette `chance` olasılıkla uygulanır
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+pub struct StatusApplication {
+    pub kind: StatusKind,
+    pub duration: f32,
+    #[serde(default)]
+    pub magnitude: f32,
+    #[serde(default = "always")]
+    pub chance: f32,
+}
+
+fn always() -> f32 {
+    1.0
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ActiveEffect {
+    pub kind: StatusKind,
+    pub magnitude: f32,
+    pub remaining: f32,
+    pub stacks: u32,
+    /// Süreli hasarın yazılacağı silah
+    pub source: Option<WeaponId>,
+    /// Tam sayıya yuvarlanmamış süreli hasar
+    carry: f32,
+}
+
+#[derive(Component, Debug, Clone, Serialize, Deserialize)]
+pub struct StatusEffects {
+    pub effects: Vec<ActiveEffect>,
+    dot_timer: Timer,
+    /// Sprite'lara en son uygulanan renk - sadece değişince boyanır; kayıttan dönen düşman yeniden boyansın
+    #[serde(skip)]
+    shown_tint: Option<StatusKind>,
+}
+
+impl Default for StatusEffects {
+    fn default() -> Self {
+        Self {
+            effects: Vec::new(),
+            dot_timer: Timer::from_seconds(DOT_INTERVAL, TimerMode::Repeating),
+            shown_tint: None,
+        }
+    }
+}
+
+impl StatusEffects {
+    pub fn apply(&mut self, application: &StatusApplication, source: Option<WeaponId>) {
+        if let Some(effect) = self.effects.iter_mut().find(|effect| effect.kind == application.kind) {
+            effect.remaining = effect.remaining.max(application.duration);
+            effect.magnitude = effect.magnitude.max(application.magnitude);
+            effect.source = source.or(effect.source.take());
+            if let Some(max) = application.kind.max_stacks() {
+                effect.stacks = (effect.stacks + 1).min(max);
+            }
+            return;
+        }
+        self.effects.push(ActiveEffect {
+            kind: application.kind,
+            magnitude: application.magnitude,
+            remaining: application.duration,
+            stacks: 1,
+            source,
+            carry: 0.0,
+        });
+    }
+
+    pub fn get(&self, kind: StatusKind) -> Option<&ActiveEffect> {
+        self.effects.iter().find(|effect| effect.kind == kind)
+    }
+
+    pub fn is_frozen(&self) -> bool {
+        self.get(StatusKind::Freeze).is_some()
+    }
+
+    /// Hareket hızı çarpanı - donmuşsa 0
+    pub fn speed_multiplier(&self) -> f32 {
+        if self.is_frozen() {
+            return 0.0;
+        }
+        self.get(StatusKind::Slow).map_or(1.0, |slow| (1.0 - slow.magnitude).clamp(0.0, 1.0))
+    }
+
+    /// Alınan hasar çarpanı
+    pub fn damage_taken_multiplier(&self) -> f32 {
+        1.0 + self.get(StatusKind::Vulnerable).map_or(0.0, |vulnerable| vulnerable.magnitude.max(0.0))
+    }
+
+    /// Vulnerable çarpanı uygulanmış hasar
+    pub fn scale_damage(&self, damage: u32) -> u32 {
+        (damage as f32 * self.damage_taken_multiplier()).round() as u32
+    }
+
+    pub fn tint(&self) -> Option<StatusKind> {
+        TINT_PRIORITY.into_iter().find(|kind| self.get(*kind).is_some())
+    }
+
+    /// Süreleri ilerlet, biten etkileri kaldır; süreli hasar tick'inde etki başına hasarı döndür
+    pub fn tick(&mut self, delta: std::time::Duration) -> Vec<(StatusKind, Option<WeaponId>, f32)> {
+        let mut damage = Vec::new();
+        if self.dot_timer.tick(delta).just_finished() {
+            for effect in self.effects.iter_mut().filter(|effect| effect.kind.is_damage_over_time()) {
+                effect.carry += effect.magnitude * effect.stacks as f32 * DOT_INTERVAL;
+                let whole = effect.carry.floor();
+                if whole >= 1.0 {
+                    effect.carry -= whole;
+                    damage.push((effect.kind, effect.source.clone(), whole));
+                }
+            }
+        }
+        let seconds = delta.as_secs_f32();
+        self.effects.retain_mut(|effect| {
+            effect.remaining -= seconds;
+            effect.remaining > 0.0
+        });
+        damage
+    }
+}
+
+/// Silahın isabette uyguladığı etkiler - tanımdaki `on_hit`
+#[derive(Component, Debug, Clone, Default)]
+pub struct OnHitEffects(pub Vec<StatusApplication>);
+
+/// İsabet eden silahın etkilerini hedefe uygular; şans zarları `Combat` akışından
+#[derive(SystemParam)]
+pub struct StatusApplier<'w, 's> {
+    on_hit: Query<'w, 's, &'static OnHitEffects>,
+    rng: ResMut<'w, GameRng>,
+}
+
+impl StatusApplier<'_, '_> {
+    pub fn apply(&mut self, weapon: Entity, source: &WeaponId, target: &mut StatusEffects) {
+        let Ok(on_hit) = self.on_hit.get(weapon) else {
+            return;
+        };
+        for application in &on_hit.0 {
+            if application.chance >= 1.0 || self.rng.stream(RngStream::Combat).random::<f32>() < application.chance {
+                target.apply(application, Some(source.clone()));
+            }
+        }
+    }
+}
+
+/// Düşman etkilerinin sürelerini işlet, süreli hasarları `DamageEvent` olarak yaz
+fn tick_enemy_status_effects(
+    time: Res<Time>,
+    mut enemies: Query<(Entity, &mut StatusEffects), With<Enemy>>,
+    mut damage_events: MessageWriter<DamageEvent>,
+) {
+    for (entity, mut effects) in enemies.iter_mut() {
+        if effects.effects.is_empty() {
+            continue;
+        }
+        for (kind, source, amount) in effects.tick(time.delta()) {
+            // Düşmanlara etkiler sadece silahlardan gelir
+            if source.is_none() {
+                continue;
+            }
+            damage_events.write(DamageEvent {
+                source,
+                weapon: None,
+                target: entity,
+                amount,
+                damage_type: kind.damage_type(),
+                crit: false,
+                over_time: true,
+                knockback: Vec3::ZERO,
+            });
+        }
+    }
+}
+
+/// Oyuncu etkilerinin sürelerini işlet, süreli hasarları uygula
+fn tick_player_status_effects(
+    time: Res<Time>,
+    mut player_query: Query<&mut StatusEffects, (With<Player>, Without<PlayerDeath>)>,
+    mut damage_events: MessageWriter<PlayerDamageEvent>,
+) {
+    let Ok(mut effects) = player_query.single_mut() else {
+        return;
+    };
+    for (_, _, damage) in effects.tick(time.delta()) {
+        damage_events.write(PlayerDamageEvent { amount: damage as u32, source: None, over_time: true });
+    }
+}
+
+/// En öncelikli etkinin rengini düşman sprite'larına uygula, etki kalmayınca beyaza döndür.
+/// Atılmaya hazırlanan charger uyarı renginde kalır
+fn tint_enemy_status_effects(
+    mut enemies: Query<(&mut StatusEffects, Option<&Charger>, &Children), With<Enemy>>,
+    mut sprites: Query<&mut Sprite, With<EnemySprit>>,
+) {
+    for (mut effects, charger, children) in enemies.iter_mut() {
+        let tint = effects.tint();
+        if tint == effects.shown_tint {
+            continue;
+        }
+        effects.shown_tint = tint;
+        tint_enemy_sprites(children, &mut sprites, resting_tint(&effects, charger));
+    }
+}
+
+fn tint_player_status_effects(mut player_query: Query<(&mut StatusEffects, &mut Sprite), With<Player>>) {
+    for (mut effects, mut sprite) in player_query.iter_mut() {
+        let tint = effects.tint();
+        if tint != effects.shown_tint {
+            effects.shown_tint = tint;
+            sprite.color = tint.map_or(Color::WHITE, StatusKind::tint);
+        }
+    }
+}
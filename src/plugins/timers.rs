@@ -1,17 +1,5 @@
 use bevy::prelude::*;
 
-#[derive(Resource)]
-pub struct EnemySpawnTimer {
-    pub timer: Timer,
-}
-impl Default for EnemySpawnTimer {
-    fn default() -> Self {
-        Self {
-            timer: Timer::from_seconds(0.1, TimerMode::Repeating),
-        }
-    }
-}
-
 #[derive(Resource)]
 pub struct MoveTimer {
     pub timer: Timer,
@@ -24,15 +12,3 @@ impl Default for MoveTimer {
     }
 
 }
-#[derive(Resource)]
-pub struct PlayerHealthReduceTimer {
-    pub timer: Timer,
-}
-impl Default for PlayerHealthReduceTimer {
-    fn default() -> Self {
-        Self {
-            timer: Timer::from_seconds(0.2, TimerMode::Repeating),
-        }
-    }
-
-}
\ No newline at end of file
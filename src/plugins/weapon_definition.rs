@@ -0,0 +1,285 @@
+use std::collections::BTreeMap;
+use bevy::asset::LoadedFolder;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use crate::plugins::asset_folder::{index_folder, FolderAsset};
+use crate::plugins::damage::DamageType;
+use crate::plugins::ron_asset::{RonAsset, RonAssetAppExt};
+use crate::plugins::status_effect::StatusApplication;
+
+/// `assets/data/weapons/*.weapon.ron` dosyalarını yükleyen plugin
+pub struct WeaponDefinitionPlugin;
+
+impl Plugin for WeaponDefinitionPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_ron_asset::<WeaponDefinition>()
+            .init_resource::<WeaponDefinitions>()
+            .add_systems(Update, index_weapon_definitions);
+    }
+}
+
+/// Silah kimliği - tanım dosyasındaki `id`; yeni silah eklemek için kod değişmez
+#[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Debug, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct WeaponId(pub String);
+
+impl WeaponId {
+    pub fn new(id: &str) -> Self {
+        Self(id.to_string())
+    }
+}
+
+impl std::fmt::Display for WeaponId {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// Silahın nasıl ateşlediği - spawn ve ateşleme sistemleri buna göre ayrılır
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum WeaponArchetype {
+    /// En yakın düşmana düz giden mermi
+    Beam,
+    /// Çarpınca `range` yarıçapında patlayan mermi
+    Rocket,
+    /// Oyuncunun etrafında `range` yarıçapında sürekli hasar veren alan
+    Aura,
+}
+
+/// Bir silahın tüm tasarım verisi - kod derlemeden dengelenebilir
+#[derive(Asset, TypePath, Debug, Clone, Deserialize)]
+pub struct WeaponDefinition {
+    pub id: WeaponId,
+    pub name: String,
+    pub archetype: WeaponArchetype,
+    pub base: BaseStats,
+    pub scaling: StatScaling,
+    /// Bu seviyeden sonra yükseltme çıkmaz; evrim için gereken seviye
+    #[serde(default = "default_max_level")]
+    pub max_level: i32,
+    #[serde(default)]
+    pub projectile: Option<ProjectileDefinition>,
+    /// Evrimleşmiş silahların ekstra davranışı
+    #[serde(default)]
+    pub behaviour: WeaponBehaviour,
+    /// İsabet ettiği düşmana uyguladığı durum etkileri
+    #[serde(default)]
+    pub on_hit: Vec<StatusApplication>,
+    /// Dirençler bu tipe göre uygulanır
+    #[serde(default)]
+    pub damage_type: DamageType,
+    /// İsabetin `CRIT_MULTIPLIER` kat hasar verme olasılığı
+    #[serde(default)]
+    pub crit_chance: f32,
+    pub color: (f32, f32, f32, f32),
+    pub upgrade: UpgradeText,
+}
+
+fn default_max_level() -> i32 {
+    8
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct BaseStats {
+    pub damage: f32,
+    pub fire_rate: f32,
+    pub speed: f32,
+    pub range: f32,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct StatScaling {
+    pub damage: ScalingCurve,
+    pub fire_rate: ScalingCurve,
+    pub speed: ScalingCurve,
+    pub range: ScalingCurve,
+}
+
+/// Seviye başına bir değerin nasıl değişeceği
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+pub enum ScalingCurve {
+    /// Seviyeden bağımsız
+    Flat,
+    /// base + x * (level - 1)
+    Linear(f32),
+    /// base * (1 + x * (level - 1))
+    Percent(f32),
+    /// base * (1 - x * (level - 1)), en az `min`
+    Reduction { per_level: f32, min: f32 },
+}
+
+impl ScalingCurve {
+    pub fn apply(&self, base: f32, level: i32) -> f32 {
+        let steps = (level - 1).max(0) as f32;
+        match *self {
+            ScalingCurve::Flat => base,
+            ScalingCurve::Linear(per_level) => base + per_level * steps,
+            ScalingCurve::Percent(per_level) => base * (1.0 + per_level * steps),
+            ScalingCurve::Reduction { per_level, min } => (base * (1.0 - per_level * steps)).max(min),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+pub enum ProjectileShape {
+    Circle { radius: f32 },
+    Square { size: f32 },
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct ProjectileDefinition {
+    pub shape: ProjectileShape,
+    pub lifetime: f32,
+}
+
+/// Sayılardan öte yeni bir mekanik - silah entity'sine ve mermilerine eklenir
+#[derive(Component, Debug, Clone, Copy, PartialEq, Default, Deserialize)]
+pub enum WeaponBehaviour {
+    #[default]
+    Standard,
+    /// Lazer mermisi `count` düşmanı deldikten sonra yok olur
+    Piercing { count: u32 },
+    /// Roket patlaması etrafına `bomblets` tane küçük patlama saçar
+    Cluster { bomblets: u32, radius: f32, damage_scale: f32 },
+    /// Alanın öldürdüğü her düşman oyuncuya `heal_per_kill` can verir
+    LifeDrain { heal_per_kill: u32 },
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct UpgradeText {
+    pub name: String,
+    pub description: String,
+}
+
+impl WeaponDefinition {
+    pub fn color(&self) -> Color {
+        let (r, g, b, a) = self.color;
+        Color::srgba(r, g, b, a)
+    }
+}
+
+impl RonAsset for WeaponDefinition {
+    const EXTENSIONS: &'static [&'static str] = &["weapon.ron"];
+
+    fn validate(&self) -> Result<(), String> {
+        let invalid = |reason: String| Err(format!("weapon '{}': {reason}", self.name));
+
+        if self.id.0.trim().is_empty() {
+            return invalid("id must not be empty".to_string());
+        }
+        if self.name.trim().is_empty() {
+            return invalid("name must not be empty".to_string());
+        }
+        if self.base.damage < 0.0 {
+            return invalid(format!("base.damage must not be negative: {}", self.base.damage));
+        }
+        if self.base.fire_rate <= 0.0 {
+            return invalid(format!("base.fire_rate must be positive: {}", self.base.fire_rate));
+        }
+        if self.max_level < 1 {
+            return invalid(format!("max_level must be at least 1: {}", self.max_level));
+        }
+        if let ScalingCurve::Reduction { min, .. } = self.scaling.fire_rate
+            && min <= 0.0
+        {
+            return invalid(format!("scaling.fire_rate min must be positive: {min}"));
+        }
+        match self.archetype {
+            WeaponArchetype::Beam | WeaponArchetype::Rocket => {
+                let Some(projectile) = self.projectile else {
+                    return invalid("projectile weapons need a projectile section".to_string());
+                };
+                if projectile.lifetime <= 0.0 {
+                    return invalid(format!("projectile.lifetime must be positive: {}", projectile.lifetime));
+                }
+                if self.base.speed <= 0.0 {
+                    return invalid(format!("base.speed must be positive: {}", self.base.speed));
+                }
+            }
+            WeaponArchetype::Aura => {}
+        }
+        if matches!(self.archetype, WeaponArchetype::Rocket | WeaponArchetype::Aura) && self.base.range <= 0.0 {
+            return invalid(format!("base.range must be positive: {}", self.base.range));
+        }
+        match self.behaviour {
+            WeaponBehaviour::Piercing { count: 0 } => {
+                return invalid("behaviour Piercing count must be positive".to_string());
+            }
+            WeaponBehaviour::Cluster { bomblets, radius, .. } if bomblets == 0 || radius <= 0.0 => {
+                return invalid("behaviour Cluster needs bomblets and a positive radius".to_string());
+            }
+            _ => {}
+        }
+        if !(0.0..=1.0).contains(&self.crit_chance) {
+            return invalid(format!("crit_chance must be between 0 and 1: {}", self.crit_chance));
+        }
+        for effect in &self.on_hit {
+            if effect.duration <= 0.0 {
+                return invalid(format!("on_hit {:?} duration must be positive: {}", effect.kind, effect.duration));
+            }
+            if !(0.0..=1.0).contains(&effect.chance) {
+                return invalid(format!("on_hit {:?} chance must be between 0 and 1: {}", effect.kind, effect.chance));
+            }
+            if effect.magnitude < 0.0 {
+                return invalid(format!("on_hit {:?} magnitude must not be negative: {}", effect.kind, effect.magnitude));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl FolderAsset for WeaponDefinition {
+    type Id = WeaponId;
+    const LABEL: &'static str = "Weapon definition";
+
+    fn id(&self) -> &WeaponId {
+        &self.id
+    }
+}
+
+/// Yüklenen tanımların id'ye göre indeksi
+#[derive(Resource)]
+pub struct WeaponDefinitions {
+    pub folder: Handle<LoadedFolder>,
+    pub by_id: BTreeMap<WeaponId, Handle<WeaponDefinition>>,
+    pub ready: bool,
+}
+
+impl FromWorld for WeaponDefinitions {
+    fn from_world(world: &mut World) -> Self {
+        let asset_server = world.get_resource::<AssetServer>()
+            .expect("AssetServer resource not found.");
+        Self {
+            folder: asset_server.load_folder("data/weapons"),
+            by_id: BTreeMap::new(),
+            ready: false,
+        }
+    }
+}
+
+impl WeaponDefinitions {
+    pub fn get<'a>(&self, id: &WeaponId, assets: &'a Assets<WeaponDefinition>) -> Option<&'a WeaponDefinition> {
+        self.by_id.get(id).and_then(|handle| assets.get(handle))
+    }
+
+    pub fn iter<'a>(&'a self, assets: &'a Assets<WeaponDefinition>) -> impl Iterator<Item = &'a WeaponDefinition> + 'a {
+        self.by_id.values().filter_map(|handle| assets.get(handle))
+    }
+}
+
+/// Klasör yüklendiğinde tanımları id'ye göre indeksle
+pub fn index_weapon_definitions(
+    asset_server: Res<AssetServer>,
+    folders: Res<Assets<LoadedFolder>>,
+    weapon_assets: Res<Assets<WeaponDefinition>>,
+    mut definitions: ResMut<WeaponDefinitions>,
+) {
+    if definitions.ready {
+        return;
+    }
+    let Some(by_id) = folders.get(&definitions.folder).and_then(|folder| index_folder(folder, &asset_server, &weapon_assets)) else {
+        return;
+    };
+    definitions.by_id = by_id;
+    definitions.ready = true;
+}
@@ -0,0 +1,148 @@
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use serde::Deserialize;
+use crate::plugins::localization::{Language, UiText};
+use crate::plugins::passive_item::{PassiveInventory, PassiveKind};
+use crate::plugins::ron_asset::{RonAsset, RonAssetAppExt};
+use crate::plugins::weapon_definition::{WeaponDefinition, WeaponDefinitions, WeaponId};
+use crate::plugins::weapon_upgrade::WeaponLevel;
+
+/// `assets/data/*.evolutions.ron` tariflerini yükleyen plugin - evrimi sandık ödülleri uygular (`chest_reward.rs`)
+pub struct WeaponEvolutionPlugin;
+
+impl Plugin for WeaponEvolutionPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_ron_asset::<EvolutionTable>()
+            .init_resource::<WeaponEvolutions>();
+    }
+}
+
+/// Hangi silahın hangi pasifle neye dönüştüğü
+#[derive(Asset, TypePath, Debug, Clone, Deserialize)]
+pub struct EvolutionTable {
+    pub recipes: Vec<EvolutionRecipe>,
+}
+
+/// Maks seviyedeki `weapon` + sahip olunan `passive` => `evolves_into`
+#[derive(Debug, Clone, Deserialize)]
+pub struct EvolutionRecipe {
+    pub weapon: WeaponId,
+    pub passive: PassiveKind,
+    pub evolves_into: WeaponId,
+}
+
+impl RonAsset for EvolutionTable {
+    const EXTENSIONS: &'static [&'static str] = &["evolutions.ron"];
+
+    fn validate(&self) -> Result<(), String> {
+        for (i, recipe) in self.recipes.iter().enumerate() {
+            if recipe.weapon == recipe.evolves_into {
+                return Err(format!("{} cannot evolve into itself", recipe.weapon));
+            }
+            if self.recipes[..i].iter().any(|other| other.weapon == recipe.weapon) {
+                return Err(format!("{} has more than one recipe", recipe.weapon));
+            }
+            if self.recipes.iter().any(|other| other.weapon == recipe.evolves_into) {
+                return Err(format!("evolved weapon {} cannot evolve again", recipe.evolves_into));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Resource)]
+pub struct WeaponEvolutions {
+    pub table: Handle<EvolutionTable>,
+}
+
+impl FromWorld for WeaponEvolutions {
+    fn from_world(world: &mut World) -> Self {
+        let asset_server = world.get_resource::<AssetServer>()
+            .expect("AssetServer resource not found.");
+        Self {
+            table: asset_server.load("data/default.evolutions.ron"),
+        }
+    }
+}
+
+/// Evrim tarifleri + oyuncunun silahları: hangi silah evrimleşebilir, ipucu ne
+#[derive(SystemParam)]
+pub struct EvolutionRules<'w, 's> {
+    evolutions: Res<'w, WeaponEvolutions>,
+    tables: Res<'w, Assets<EvolutionTable>>,
+    definitions: Res<'w, WeaponDefinitions>,
+    weapon_assets: Res<'w, Assets<WeaponDefinition>>,
+    weapons: Query<'w, 's, (Entity, &'static WeaponLevel)>,
+}
+
+impl EvolutionRules<'_, '_> {
+    pub fn recipes(&self) -> &[EvolutionRecipe] {
+        self.tables
+            .get(&self.evolutions.table)
+            .map(|table| table.recipes.as_slice())
+            .unwrap_or_default()
+    }
+
+    pub fn recipe_for(&self, weapon: &WeaponId) -> Option<&EvolutionRecipe> {
+        self.recipes().iter().find(|recipe| recipe.weapon == *weapon)
+    }
+
+    pub fn definition(&self, weapon: &WeaponId) -> Option<&WeaponDefinition> {
+        self.definitions.get(weapon, &self.weapon_assets)
+    }
+
+    /// Yüklü tüm silah tanımları (evrimler dahil)
+    pub fn definitions(&self) -> impl Iterator<Item = &WeaponDefinition> {
+        self.definitions.iter(&self.weapon_assets)
+    }
+
+    /// Sadece evrimle elde edilebilen silah mı
+    pub fn is_evolution(&self, weapon: &WeaponId) -> bool {
+        self.recipes().iter().any(|recipe| recipe.evolves_into == *weapon)
+    }
+
+    /// Evrim olmayan silahlar - shop'ta başlangıç silahı olarak seçilebilir
+    pub fn starting_choices(&self) -> Vec<WeaponId> {
+        self.definitions()
+            .map(|definition| &definition.id)
+            .filter(|id| !self.is_evolution(id))
+            .cloned()
+            .collect()
+    }
+
+    /// Oyuncunun sahip olduğu silahlar ve seviyeleri
+    pub fn owned(&self) -> impl Iterator<Item = (Entity, &WeaponLevel)> {
+        self.weapons.iter()
+    }
+
+    /// Şu an evrimleşebilecek silahlar (tablo sırasıyla)
+    pub fn ready_evolutions(&self, inventory: &PassiveInventory) -> Vec<(Entity, EvolutionRecipe)> {
+        self.recipes().iter().filter_map(|recipe| {
+            if !inventory.has(recipe.passive) || self.definition(&recipe.evolves_into).is_none() {
+                return None;
+            }
+            self.weapons
+                .iter()
+                .find(|(_, level)| level.weapon_type == recipe.weapon && level.level >= level.max_level)
+                .map(|(entity, _)| (entity, recipe.clone()))
+        }).collect()
+    }
+
+    /// Yükseltme menüsünde gösterilecek evrim ipucu
+    pub fn hint(&self, weapon: &WeaponId, inventory: &PassiveInventory, language: Language) -> Option<String> {
+        let recipe = self.recipe_for(weapon)?;
+        let evolved = self.definition(&recipe.evolves_into)?;
+        let max_level = self.definition(weapon).map(|definition| definition.max_level)?;
+        let text = |text: UiText| text.get(language);
+        let owned = if inventory.has(recipe.passive) { format!(" ({})", text(UiText::Owned)) } else { String::new() };
+        Some(format!(
+            "{}: {}.{} + {}{} -> {}",
+            text(UiText::Evolution),
+            text(UiText::LevelShort),
+            max_level,
+            recipe.passive.name(),
+            owned,
+            evolved.name,
+        ))
+    }
+}
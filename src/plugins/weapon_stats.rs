@@ -1,6 +1,12 @@
+use bevy::ecs::query::QueryData;
+use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
-use crate::plugins::weapon_upgrade::{WeaponLevel, WeaponType};
-use crate::plugins::weapons::{GameEntity, LaserWeapon, PlayerAddictedWeapon, RocketWeapon, Weapon};
+use crate::plugins::damage::DamageProfile;
+use crate::plugins::passive_item::PlayerStats;
+use crate::plugins::status_effect::OnHitEffects;
+use crate::plugins::weapon_definition::{StatScaling, WeaponArchetype, WeaponDefinition, WeaponDefinitions, WeaponId};
+use crate::plugins::weapon_upgrade::WeaponLevel;
+use crate::plugins::weapons::{GameEntity, LaserWeapon, PlayerAddictedWeapon, ProjectileTemplate, RocketWeapon, Weapon};
 
 #[derive(Component)]
 pub struct WeaponStats{
@@ -8,108 +14,166 @@ pub struct WeaponStats{
     pub base_fire_rate: f32,
     pub base_speed: f32,
     pub base_range: f32,
+    pub scaling: StatScaling,
 }
 
 impl WeaponStats {
-    pub fn calculate_damage(&self, level:i32) -> f32{
-        match level {
-            1 => self.base_damage,
-            _ => self.base_damage + ((level -1) as f32 * 10.0)
+    pub fn from_definition(definition: &WeaponDefinition) -> Self {
+        Self {
+            base_damage: definition.base.damage,
+            base_fire_rate: definition.base.fire_rate,
+            base_speed: definition.base.speed,
+            base_range: definition.base.range,
+            scaling: definition.scaling,
         }
     }
-    pub fn calculate_fire_rate(&self, level:i32) -> f32{
-        let bonus = (level-1) as f32 * 0.1;
-        (self.base_fire_rate * (1.0 - bonus)).max(0.05)
+    pub fn calculate_damage(&self, level:i32, player_stats: &PlayerStats) -> f32{
+        self.scaling.damage.apply(self.base_damage, level) * player_stats.might
+    }
+    pub fn calculate_fire_rate(&self, level:i32, player_stats: &PlayerStats) -> f32{
+        self.scaling.fire_rate.apply(self.base_fire_rate, level) * player_stats.cooldown
     }
     pub fn calculate_speed(&self, level:i32) -> f32{
-        self.base_speed + ((level - 1) as f32 * 25.0)
+        self.scaling.speed.apply(self.base_speed, level)
+    }
+    pub fn calculate_range(&self, level:i32, player_stats: &PlayerStats) -> f32{
+        self.scaling.range.apply(self.base_range, level) * player_stats.area
     }
-    pub fn calculate_range(&self, level:i32) -> f32{
-        self.base_range * (1.0 + (level -1) as f32 * 0.15)
+}
+
+/// Statları seviyeden hesaplanan silah; menzil arketipe göre roket ya da alanda
+#[derive(QueryData)]
+#[query_data(mutable)]
+pub struct ScaledWeapon {
+    weapon: &'static mut Weapon,
+    level: Ref<'static, WeaponLevel>,
+    stats: &'static WeaponStats,
+    rocket: Option<&'static mut RocketWeapon>,
+    addicted: Option<&'static mut PlayerAddictedWeapon>,
+}
+
+/// Seviye ya da oyuncu statları değişen silahların değerlerini yeniden hesapla
+pub fn sync_weapon_stats(player_stats: Single<Ref<PlayerStats>>, mut weapons: Query<ScaledWeapon>) {
+    let stats_changed = player_stats.is_changed();
+    for mut scaled in weapons.iter_mut() {
+        if !stats_changed && !scaled.level.is_changed() {
+            continue;
+        }
+        let level = scaled.level.level;
+        let stats = scaled.stats;
+        scaled.weapon.damage = stats.calculate_damage(level, &player_stats);
+        scaled.weapon.speed = stats.calculate_speed(level);
+        let fire_rate = stats.calculate_fire_rate(level, &player_stats);
+        scaled.weapon.fire_timer.set_duration(std::time::Duration::from_secs_f32(fire_rate));
+
+        if let Some(rocket) = scaled.rocket.as_mut() {
+            rocket.explosion_radius = stats.calculate_range(level, &player_stats);
+        }
+        if let Some(addicted) = scaled.addicted.as_mut() {
+            addicted.radius = stats.calculate_range(level, &player_stats);
+        }
     }
 }
 
-pub fn spawn_weapons_for_player(
+/// `starting_weapon` karakterin ya da shop'ta seçilen başlangıç silahı - diğerleri seviye atlarken gelir.
+/// Oyuncu orijinde spawn olur
+pub fn spawn_starting_weapon(
     commands: &mut Commands,
     player_entity: Entity,
-    _player_pos: Vec3,
+    starting_weapon: &WeaponId,
     meshes: &mut Assets<Mesh>,
     materials: &mut Assets<ColorMaterial>,
+    definitions: &WeaponDefinitions,
+    weapon_assets: &Assets<WeaponDefinition>,
 ){
-    println!("Spawning weapons for player!");
+    println!("Spawning {} for player!", starting_weapon);
 
-    // Lazer silahı
-    commands.spawn((
-        GameEntity,
-        Weapon {
-            owner: player_entity,
-            damage: 50.0,
-            fire_timer: Timer::from_seconds(0.3, TimerMode::Repeating),
-            speed: 200.0
-        },
-        LaserWeapon { color: Color::srgb(0.0, 0.5, 0.0) },
-        WeaponLevel {
-            level: 1,
-            weapon_type: WeaponType::Laser,
-        },
-        WeaponStats {
-            base_damage: 50.0,
-            base_fire_rate: 0.3,
-            base_speed: 200.0,
-            base_range: 0.0,
-        },
-    ));
+    let Some(definition) = definitions.get(starting_weapon, weapon_assets) else {
+        warn!("No weapon definition for {}, skipping", starting_weapon);
+        return;
+    };
+    spawn_weapon(commands, player_entity, Vec3::ZERO, meshes, materials, definition);
+}
 
-    // Roket silahı
-    let rocket_base_range = 100.0;
-    commands.spawn((
-        GameEntity,
-        Weapon {
-            owner: player_entity,
-            damage: 100.0,
-            fire_timer: Timer::from_seconds(0.1, TimerMode::Repeating),
-            speed: 200.0,
-        },
-        RocketWeapon { explosion_radius: rocket_base_range },
-        WeaponLevel {
-            level: 1,
-            weapon_type: WeaponType::Rocket,
-        },
-        WeaponStats {
-            base_damage: 50.0,
-            base_fire_rate: 0.2,
-            base_speed: 200.0,
-            base_range: rocket_base_range,
-        },
-    ));
+/// Oyun sırasında id'siyle silah spawn etmek için gereken kaynaklar (evrim, yeni silah)
+#[derive(SystemParam)]
+pub struct WeaponSpawner<'w> {
+    meshes: ResMut<'w, Assets<Mesh>>,
+    materials: ResMut<'w, Assets<ColorMaterial>>,
+    definitions: Res<'w, WeaponDefinitions>,
+    weapon_assets: Res<'w, Assets<WeaponDefinition>>,
+}
+
+impl WeaponSpawner<'_> {
+    pub fn spawn(&mut self, commands: &mut Commands, player_entity: Entity, player_pos: Vec3, weapon: &WeaponId) -> Option<Entity> {
+        let Some(definition) = self.definitions.get(weapon, &self.weapon_assets) else {
+            warn!("No weapon definition for {}, skipping", weapon);
+            return None;
+        };
+        Some(spawn_weapon(commands, player_entity, player_pos, &mut self.meshes, &mut self.materials, definition))
+    }
+}
 
-    // Alev silahı
-    let base_range = 75.0;
-    commands.spawn((
+/// Tek bir silahı tanımından spawn et - oyuncu statları `sync_weapon_stats` ile uygulanır
+pub fn spawn_weapon(
+    commands: &mut Commands,
+    player_entity: Entity,
+    player_pos: Vec3,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<ColorMaterial>,
+    definition: &WeaponDefinition,
+) -> Entity {
+    let stats = WeaponStats::from_definition(definition);
+    let player_stats = PlayerStats::default();
+    let mut weapon = commands.spawn((
         GameEntity,
-        Mesh2d(meshes.add(Circle::new(1.0))),
-        MeshMaterial2d(materials.add(ColorMaterial::from(Color::srgba(1.0, 0.5, 0.0, 0.3)))),
-        PlayerAddictedWeapon{ radius: base_range },
         Weapon {
-            fire_timer: Timer::from_seconds(0.1, TimerMode::Repeating),
-            damage: 5.0,
             owner: player_entity,
-            speed: 0.0,
+            damage: stats.calculate_damage(1, &player_stats),
+            fire_timer: Timer::from_seconds(stats.calculate_fire_rate(1, &player_stats), TimerMode::Repeating),
+            speed: stats.calculate_speed(1),
         },
         WeaponLevel {
             level: 1,
-            weapon_type: WeaponType::Addicted,
-        },
-        WeaponStats {
-            base_damage: 5.0,
-            base_fire_rate: 0.1,
-            base_speed: 0.0,
-            base_range: base_range,
-        },
-        Transform {
-            translation: _player_pos,
-            scale: Vec3::splat(base_range),
-            ..Default::default()
+            max_level: definition.max_level,
+            weapon_type: definition.id.clone(),
         },
+        definition.behaviour,
+        OnHitEffects(definition.on_hit.clone()),
+        DamageProfile { damage_type: definition.damage_type, crit_chance: definition.crit_chance },
     ));
-}
\ No newline at end of file
+    if let Some(projectile) = definition.projectile {
+        weapon.insert(ProjectileTemplate {
+            shape: projectile.shape,
+            lifetime: projectile.lifetime,
+            color: definition.color(),
+        });
+    }
+
+    match definition.archetype {
+        // Lazer gibi düz mermi
+        WeaponArchetype::Beam => {
+            weapon.insert(LaserWeapon { color: definition.color() });
+        }
+        // Patlayan mermi
+        WeaponArchetype::Rocket => {
+            weapon.insert(RocketWeapon { explosion_radius: stats.calculate_range(1, &player_stats) });
+        }
+        // Alev gibi oyuncuyu saran alan
+        WeaponArchetype::Aura => {
+            let radius = stats.calculate_range(1, &player_stats);
+            weapon.insert((
+                Mesh2d(meshes.add(Circle::new(1.0))),
+                MeshMaterial2d(materials.add(ColorMaterial::from(definition.color()))),
+                PlayerAddictedWeapon{ radius },
+                Transform {
+                    translation: player_pos,
+                    scale: Vec3::splat(radius),
+                    ..Default::default()
+                },
+            ));
+        }
+    }
+    weapon.insert(stats);
+    weapon.id()
+}
@@ -1,34 +1,58 @@
+use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
 use bevy::ui::Val::Auto;
-use rand::{rng};
+use rand::Rng;
 use rand::prelude::IndexedRandom;
 use crate::plugins::audio::GameAudioEntity;
+use serde::{Deserialize, Serialize};
 use crate::plugins::game_state::GameState;
-use crate::plugins::weapon_stats::WeaponStats;
-use crate::plugins::weapons::{LaserWeapon, PlayerAddictedWeapon, RocketWeapon, Weapon};
+use crate::plugins::input_action::{ActionState, InputAction};
+use crate::plugins::localization::UiText;
+use crate::plugins::passive_item::{PassiveCatalogue, PassiveInventory, PassiveItems, PassiveKind, PlayerStats};
+use crate::plugins::player::Player;
+use crate::plugins::rng::{GameRng, RngStream};
+use crate::plugins::settings::Settings;
+use crate::plugins::weapon_definition::WeaponId;
+use crate::plugins::weapon_evolution::{EvolutionRecipe, EvolutionRules};
+use crate::plugins::weapon_stats::WeaponSpawner;
 
-/// Silah tipi - sadece tip belirteci, veri içermez
-#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
-pub enum WeaponType {
-    Laser,
-    Rocket,
-    Addicted,
+/// Aynı anda taşınabilecek silah sayısı
+pub const MAX_WEAPON_SLOTS: usize = 6;
+const UPGRADE_OUTLINE_COLOR: Color = Color::srgba(0.0, 0.1, 0.2, 0.8);
+/// Klavye / gamepad ile seçili kartın çerçevesi
+const UPGRADE_FOCUS_COLOR: Color = Color::srgb(1.0, 0.85, 0.2);
+
+/// Seviye atlarken seçilebilecek ödül
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub enum UpgradeKind {
+    /// Sahip olunan silahın seviyesini artır
+    WeaponLevel(WeaponId),
+    /// Boş silah slotuna yeni silah
+    NewWeapon(WeaponId),
+    /// Yeni pasif eşya ya da sahip olunanın seviyesi
+    Passive(PassiveKind),
 }
 
 #[derive(Component,Clone)]
 pub struct UpgradeOption{
-    pub weapon_type: WeaponType,
+    pub kind: UpgradeKind,
     pub name: String,
     pub description: String,
     pub icon: Option<Handle<Image>>,
+    /// Bu silahın evrim tarifi varsa menüde gösterilecek ipucu
+    pub evolution_hint: Option<String>,
 }
 
-#[derive(Message)]
+#[derive(Message, Clone)]
 pub struct UpgradeSelectedEvent{
-    pub weapon_type: WeaponType
+    pub kind: UpgradeKind,
 }
 
-#[derive(Message)]
+/// Seçenekleri yeniden çek - oyuncunun `rerolls` hakkından düşer
+#[derive(Message, Clone, Copy, Debug)]
+pub struct RerollEvent;
+
+#[derive(Message, Clone, Copy, Debug)]
 pub struct LevelUpEvent{
     pub level: i32,
 }
@@ -39,36 +63,100 @@ pub struct UpgradeChoices {
     pub waiting_for_choice: bool,
 }
 
-#[derive(Component)]
+#[derive(Component, Clone, Serialize, Deserialize)]
 pub struct WeaponLevel {
     pub level: i32,
-    pub weapon_type: WeaponType,
+    pub max_level: i32,
+    pub weapon_type: WeaponId,
 }
-impl UpgradeChoices {
-    pub fn generate_random_options(&mut self) -> Vec<UpgradeOption>{
-
-        let all_options = vec![
-            UpgradeOption{
-                weapon_type: WeaponType::Laser,
-                name : "Laser Silahı Güçlendir".to_string(),
-                description: "Hasar +10, Hız +%5".to_string(),
-                icon: None
-            },
-            UpgradeOption {
-                weapon_type: WeaponType::Rocket,
-                name: "Roket Silahı Güçlendir".to_string(),
-                description: "Hasar +15, Patlama +10".to_string(),
-                icon: None,
-            },
-            UpgradeOption {
-                weapon_type: WeaponType::Addicted,
-                name: "Alev Silahı Güçlendir".to_string(),
-                description: "Hasar +3, Alan +15%".to_string(),
+/// Seviye atlama seçeneklerinin havuzu: silah yükseltmeleri, yeni silahlar, pasifler
+#[derive(SystemParam)]
+pub struct UpgradePool<'w, 's> {
+    pub rules: EvolutionRules<'w, 's>,
+    passive_items: Res<'w, PassiveItems>,
+    catalogues: Res<'w, Assets<PassiveCatalogue>>,
+    inventory: Single<'w, 's, &'static PassiveInventory>,
+    player_stats: Single<'w, 's, &'static PlayerStats>,
+    rng: ResMut<'w, GameRng>,
+    settings: Res<'w, Settings>,
+}
+
+impl UpgradePool<'_, '_> {
+    /// Şu an seçilebilecek tüm seçenekler
+    pub fn candidates(&self) -> Vec<UpgradeOption> {
+        let inventory: &PassiveInventory = &self.inventory;
+        let text = |text: UiText| text.get(self.settings.language);
+        let mut options = Vec::new();
+
+        // Sahip olunan ve maks seviyeye ulaşmamış silahlar
+        for (_, level) in self.rules.owned().filter(|(_, level)| level.level < level.max_level) {
+            let Some(definition) = self.rules.definition(&level.weapon_type) else {
+                continue;
+            };
+            options.push(UpgradeOption {
+                kind: UpgradeKind::WeaponLevel(level.weapon_type.clone()),
+                name: definition.upgrade.name.clone(),
+                description: format!("{} ({}.{} -> {}/{})", definition.upgrade.description, text(UiText::LevelShort), level.level, level.level + 1, level.max_level),
                 icon: None,
-            },
-        ];
-        let mut rng = rng();
-        let selected: Vec<_> = all_options.choose_multiple(&mut rng, 3).cloned().collect();
+                evolution_hint: self.rules.hint(&level.weapon_type, inventory, self.settings.language),
+            });
+        }
+
+        // Boş slot varsa henüz alınmamış, evrim olmayan silahlar
+        if self.rules.owned().count() < MAX_WEAPON_SLOTS {
+            for definition in self.rules.definitions() {
+                let weapon = &definition.id;
+                if self.rules.is_evolution(weapon) || self.rules.owned().any(|(_, level)| level.weapon_type == *weapon) {
+                    continue;
+                }
+                options.push(UpgradeOption {
+                    kind: UpgradeKind::NewWeapon(weapon.clone()),
+                    name: format!("{}: {}", text(UiText::NewWeapon), definition.name),
+                    description: format!("{}.1", text(UiText::LevelShort)),
+                    icon: None,
+                    evolution_hint: self.rules.hint(weapon, inventory, self.settings.language),
+                });
+            }
+        }
+
+        // Pasifler: sahip olunanların bir sonraki seviyesi ya da boş slota yenisi
+        if let Some(catalogue) = self.catalogues.get(&self.passive_items.catalogue) {
+            for item in &catalogue.items {
+                let level = inventory.level(item.kind);
+                if level >= item.max_level || (level == 0 && !inventory.has_free_slot()) {
+                    continue;
+                }
+                options.push(UpgradeOption {
+                    kind: UpgradeKind::Passive(item.kind),
+                    name: item.kind.name().to_string(),
+                    description: format!("{} ({}.{} -> {}/{})", item.description, text(UiText::LevelShort), level, level + 1, item.max_level),
+                    icon: None,
+                    evolution_hint: None,
+                });
+            }
+        }
+        options
+    }
+
+    pub fn inventory(&self) -> &PassiveInventory {
+        &self.inventory
+    }
+
+    pub fn rng(&mut self) -> &mut GameRng {
+        &mut self.rng
+    }
+
+    /// Luck ile bir seçenek daha çıkabilir
+    pub fn choice_count(&mut self) -> usize {
+        if self.rng.stream(RngStream::Upgrades).random_bool(self.player_stats.luck.clamp(0.0, 1.0) as f64) { 4 } else { 3 }
+    }
+}
+
+impl UpgradeChoices {
+    pub fn generate_random_options(&mut self, pool: &mut UpgradePool) -> Vec<UpgradeOption>{
+        let all_options = pool.candidates();
+        let count = pool.choice_count();
+        let selected: Vec<_> = all_options.choose_multiple(pool.rng.stream(RngStream::Upgrades), count).cloned().collect();
         self.options = selected.clone();
         self.waiting_for_choice = true;
         selected
@@ -80,31 +168,47 @@ pub struct WeaponTable;
 
 
 #[derive(Component)]
-pub struct UpgradeButton(pub WeaponType);
-pub fn show_upgrade_choices_on_level_up(
-    mut level_up_events: MessageReader<LevelUpEvent>,
-    mut upgrade_choices: ResMut<UpgradeChoices>,
-    mut next_state: ResMut<NextState<GameState>>,
-    mut commands: Commands,
-    table: Query<Entity, With<WeaponTable>>,
-    asset_server: Res<AssetServer>,
-){
-    let font = asset_server.load("fonts/FiraMono-Medium.ttf");
-    for _ in level_up_events.read() {
-        let options = upgrade_choices.generate_random_options();
+pub struct UpgradeButton(pub UpgradeKind);
 
-        next_state.set(GameState::UpgradeSelection);
+#[derive(Component, PartialEq, Eq, Debug)]
+pub struct RerollButton;
 
-        let Ok(table_entity) = table.single() else {
-            commands.spawn((WeaponTable, Node::default()));
-            continue;
+/// Klavye / gamepad ile seçili kartın `WeaponTable` içindeki sırası
+#[derive(Resource, Default, Debug)]
+pub struct UpgradeFocus(pub usize);
+
+/// Seçim ekranı: kartlar `WeaponTable` altında, yeniden çekme düğmesi ayrı
+#[derive(SystemParam)]
+pub struct UpgradeScreen<'w, 's> {
+    commands: Commands<'w, 's>,
+    table: Query<'w, 's, (Entity, Option<&'static Children>), With<WeaponTable>>,
+    reroll_buttons: Query<'w, 's, Entity, With<RerollButton>>,
+    asset_server: Res<'w, AssetServer>,
+    settings: Res<'w, Settings>,
+}
+
+impl UpgradeScreen<'_, '_> {
+    /// Masadaki kartları `options` ile değiştir; hak kaldıysa altına yeniden çekme düğmesi
+    fn show(&mut self, options: &[UpgradeOption], rerolls: u32) {
+        let font = self.asset_server.load("fonts/FiraMono-Medium.ttf");
+        let Ok((table_entity, cards)) = self.table.single() else {
+            self.commands.spawn((WeaponTable, Node::default()));
+            return;
         };
+        for card in cards.into_iter().flatten() {
+            self.commands.entity(*card).despawn();
+        }
         let options_len = options.len() as f32;
         for (i ,option) in options.iter().enumerate() {
-            commands.entity(table_entity).with_children(|parent| {
+            let mut label = format!("{} {} {} - {}", UiText::UpgradeOption.get(self.settings.language), i, option.name, option.description);
+            if let Some(hint) = &option.evolution_hint {
+                label.push('\n');
+                label.push_str(hint);
+            }
+            self.commands.entity(table_entity).with_children(|parent| {
                 parent.spawn((
-                    Button::default(), UpgradeButton(option.weapon_type),
-                    Text::new(format!("Seçenek {} {} - {}", i, option.name, option.description)),
+                    Button::default(), UpgradeButton(option.kind.clone()),
+                    Text::new(label),
                     TextFont{
                         font: font.clone(),
                         font_size: 20.0,
@@ -114,11 +218,82 @@ pub fn show_upgrade_choices_on_level_up(
                     Outline{
                         width: Val::Px(2.0),
                         offset: Val::Px(0.0),
-                        color: Color::srgba(0.0, 0.1, 0.2, 0.8),
+                        color: UPGRADE_OUTLINE_COLOR,
                     }
                 ));
             });
         }
+
+        for button in self.reroll_buttons.iter() {
+            self.commands.entity(button).despawn();
+        }
+        if rerolls == 0 {
+            return;
+        }
+        self.commands.spawn((
+            Button,
+            RerollButton,
+            Node{
+                position_type: PositionType::Absolute,
+                bottom: Val::Percent(12.0),
+                left: Val::Percent(50.0),
+                width: Val::Px(240.0),
+                height: Val::Px(50.0),
+                margin: UiRect::left(Val::Px(-120.0)),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.3, 0.3, 0.3)),
+        )).with_child((
+            Text::new(format!("{} ({})", UiText::Reroll.get(self.settings.language), rerolls)),
+            TextFont{
+                font,
+                font_size: 24.0,
+                ..default()
+            },
+        ));
+    }
+}
+
+pub fn show_upgrade_choices_on_level_up(
+    mut level_up_events: MessageReader<LevelUpEvent>,
+    mut upgrade_choices: ResMut<UpgradeChoices>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut screen: UpgradeScreen,
+    mut pool: UpgradePool,
+    player: Single<&Player>,
+){
+    for _ in level_up_events.read() {
+        let options = upgrade_choices.generate_random_options(&mut pool);
+
+        // Yükseltilecek bir şey kalmadıysa oyuna dön
+        if options.is_empty() {
+            upgrade_choices.waiting_for_choice = false;
+            next_state.set(GameState::Playing);
+            continue;
+        }
+        next_state.set(GameState::UpgradeSelection);
+        screen.show(&options, player.rerolls);
+    }
+}
+
+/// Hak varsa seçenekleri yeniden üret - seçim yapılana kadar ekran açık kalır
+pub fn reroll_upgrade_choices(
+    mut reroll_events: MessageReader<RerollEvent>,
+    mut upgrade_choices: ResMut<UpgradeChoices>,
+    mut screen: UpgradeScreen,
+    mut pool: UpgradePool,
+    mut player: Single<&mut Player>,
+){
+    for _ in reroll_events.read() {
+        if player.rerolls == 0 || !upgrade_choices.waiting_for_choice {
+            continue;
+        }
+        player.rerolls -= 1;
+        let options = upgrade_choices.generate_random_options(&mut pool);
+        screen.show(&options, player.rerolls);
+        println!("🎲 Seçenekler yeniden çekildi ({} hak kaldı)", player.rerolls);
     }
 }
 
@@ -141,64 +316,58 @@ pub fn create_table_ui(
     ));
 }
 
+/// Yükseltmeyi oyuncuya uygular - seviye atlama seçimi ve sandık ödülleri aynı yoldan geçer
+#[derive(SystemParam)]
+pub struct UpgradeApplier<'w, 's> {
+    commands: Commands<'w, 's>,
+    weapons: Query<'w, 's, &'static mut WeaponLevel>,
+    player: Single<'w, 's, (Entity, &'static Transform, &'static mut PassiveInventory), With<Player>>,
+    spawner: WeaponSpawner<'w>,
+}
+
+impl UpgradeApplier<'_, '_> {
+    pub fn apply(&mut self, kind: UpgradeKind) {
+        let (player_entity, player_transform, inventory) = &mut *self.player;
+        match kind {
+            // Statlar `sync_weapon_stats` ile yeniden hesaplanır
+            UpgradeKind::WeaponLevel(weapon) => {
+                if let Some(mut level) = self.weapons.iter_mut().find(|level| level.weapon_type == weapon) {
+                    // Seviye artır - maks seviyeden sonra evrim bekler
+                    level.level = (level.level + 1).min(level.max_level);
+                    println!("{} yükseltildi! Yeni seviye: {}", weapon, level.level);
+                }
+            }
+            UpgradeKind::NewWeapon(weapon) => {
+                self.spawner.spawn(&mut self.commands, *player_entity, player_transform.translation, &weapon);
+                println!("Yeni silah: {}", weapon);
+            }
+            UpgradeKind::Passive(kind) => {
+                let level = inventory.items.entry(kind).or_insert(0);
+                *level += 1;
+                println!("{} pasifi seviye {}", kind.name(), level);
+            }
+        }
+    }
+
+    /// Maks seviyedeki `weapon`'ı evrimiyle değiştir
+    pub fn evolve(&mut self, weapon: Entity, recipe: EvolutionRecipe) {
+        let (player_entity, player_transform, _) = &*self.player;
+        self.commands.entity(weapon).try_despawn();
+        self.spawner.spawn(&mut self.commands, *player_entity, player_transform.translation, &recipe.evolves_into);
+        println!("✨ {} evrimleşti: {}", recipe.weapon, recipe.evolves_into);
+    }
+}
+
 pub fn apply_weapon_upgrade(
     mut upgrade_events: MessageReader<UpgradeSelectedEvent>,
-    mut weapons: Query<(
-        &mut Weapon,
-        &mut WeaponLevel,
-        &WeaponStats,
-        Option<&mut LaserWeapon>,
-        Option<&mut RocketWeapon>,
-        Option<&mut PlayerAddictedWeapon>,
-    )>,
+    mut applier: UpgradeApplier,
     mut next_state: ResMut<NextState<GameState>>,
     mut upgrade_choices: ResMut<UpgradeChoices>,
 ){
     for event in upgrade_events.read() {
         upgrade_choices.waiting_for_choice = false;
-        
-        for (mut weapon, mut level, stats, laser, rocket, addicted) in weapons.iter_mut() {
-            // Silah tipini kontrol et
-            if level.weapon_type != event.weapon_type {
-                continue;
-            }
-            
-            // Seviye artır
-            level.level += 1;
-            let new_level = level.level;
-            
-            // Ortak güncellemeler
-            weapon.damage = stats.calculate_damage(new_level);
-            weapon.speed = stats.calculate_speed(new_level);
-            let new_fire_rate = stats.calculate_fire_rate(new_level);
-            weapon.fire_timer.set_duration(std::time::Duration::from_secs_f32(new_fire_rate));
-
-            // Silah tipine göre özel güncellemeler
-            match event.weapon_type {
-                WeaponType::Laser => {
-                    if let Some(_laser_weapon) = laser {
-                        // Laser'a özel güncellemeler (örn: renk değişimi)
-                        println!("Laser yükseltildi! Yeni seviye: {}", new_level);
-                    }
-                },
-                WeaponType::Rocket => {
-                    if let Some(mut rocket_weapon) = rocket {
-                        // Roket patlama yarıçapını artır
-                        rocket_weapon.explosion_radius = stats.calculate_range(new_level);
-                        println!("Roket yükseltildi! Yeni patlama yarıçapı: {}", rocket_weapon.explosion_radius);
-                    }
-                },
-                WeaponType::Addicted => {
-                    if let Some(mut addicted_weapon) = addicted {
-                        addicted_weapon.radius = stats.calculate_range(new_level);
-                        println!("Alev silahı yükseltildi! Yeni yarıçap: {}", addicted_weapon.radius);
-                    }
-                },
-            }
-            
-            next_state.set(GameState::Playing);
-            break;
-        }
+        applier.apply(event.kind.clone());
+        next_state.set(GameState::Playing);
     }
 }
 
@@ -210,18 +379,73 @@ pub fn handle_upgrade_input(
         if *interaction == Interaction::Pressed {
             upgrade_events.write(
                 UpgradeSelectedEvent{
-                    weapon_type: upgrade_button.0,
+                    kind: upgrade_button.0.clone(),
             });
         }
     }
 }
 
+pub fn handle_reroll_button(
+    interaction_q: Query<&Interaction, (Changed<Interaction>, With<RerollButton>)>,
+    mut reroll_events: MessageWriter<RerollEvent>,
+){
+    if interaction_q.iter().any(|interaction| *interaction == Interaction::Pressed) {
+        reroll_events.write(RerollEvent);
+    }
+}
+
+pub fn reset_upgrade_focus(mut focus: ResMut<UpgradeFocus>) {
+    focus.0 = 0;
+}
+
+/// Yukarı/sol önceki, aşağı/sağ sonraki karta geçer; Confirm seçili kartı alır
+pub fn navigate_upgrade_choices(
+    actions: Res<ActionState>,
+    mut focus: ResMut<UpgradeFocus>,
+    table: Query<&Children, With<WeaponTable>>,
+    mut buttons: Query<(&UpgradeButton, &mut Outline)>,
+    mut upgrade_events: MessageWriter<UpgradeSelectedEvent>,
+){
+    let Ok(children) = table.single() else {
+        return;
+    };
+    let cards: Vec<Entity> = children.iter().filter(|child| buttons.contains(*child)).collect();
+    if cards.is_empty() {
+        return;
+    }
+    let count = cards.len();
+    if actions.just_pressed(InputAction::MoveUp) || actions.just_pressed(InputAction::MoveLeft) {
+        focus.0 = (focus.0 + count - 1) % count;
+    }
+    if actions.just_pressed(InputAction::MoveDown) || actions.just_pressed(InputAction::MoveRight) {
+        focus.0 = (focus.0 + 1) % count;
+    }
+    focus.0 = focus.0.min(count - 1);
+
+    for (index, card) in cards.iter().enumerate() {
+        let Ok((_, mut outline)) = buttons.get_mut(*card) else {
+            continue;
+        };
+        let color = if index == focus.0 { UPGRADE_FOCUS_COLOR } else { UPGRADE_OUTLINE_COLOR };
+        if outline.color != color {
+            outline.color = color;
+        }
+    }
+
+    if actions.just_pressed(InputAction::Confirm)
+        && let Ok((upgrade_button, _)) = buttons.get(cards[focus.0])
+    {
+        upgrade_events.write(UpgradeSelectedEvent{ kind: upgrade_button.0.clone() });
+    }
+}
+
 pub fn cleanup_upgrade_ui_on_choice(
     table: Query<Entity, With<WeaponTable>>,
+    reroll_buttons: Query<Entity, With<RerollButton>>,
     audio_entity: Query<Entity, With<GameAudioEntity>>,
     mut commands: Commands,
 ){
-    for table_entity in table.iter() {
+    for table_entity in table.iter().chain(reroll_buttons.iter()) {
         commands.entity(table_entity).try_despawn();
     }
     for audio_entity in audio_entity.iter() {
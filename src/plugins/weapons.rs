@@ -1,9 +1,13 @@
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 use crate::plugins::aabb::AABB;
-use crate::plugins::audio::GameAudio;
+use crate::plugins::damage::DamageWriter;
 use crate::plugins::enemy::Enemy;
+use crate::plugins::passive_item::PlayerStats;
 use crate::plugins::player::Player;
-use crate::plugins::weapon_stats::WeaponStats;
+use crate::plugins::spatial_grid::{SpatialGrid, SpatialLayer};
+use crate::plugins::weapon_definition::{ProjectileShape, WeaponBehaviour, WeaponId};
+use crate::plugins::weapon_upgrade::WeaponLevel;
 
 // GameEntity marker
 #[derive(Component)]
@@ -18,13 +22,13 @@ pub struct Weapon {
     pub speed: f32,
 }
 
-// Farklı silah tipleri - sadece özellikler
+// Silah arketipleri - `WeaponArchetype`e göre eklenir, ateşleme sistemleri bunlarla ayrılır
 #[derive(Component, Clone, Copy, PartialEq)]
 pub struct LaserWeapon {
     pub color: Color,
 }
 
-#[derive(Component, Clone, Copy, PartialEq)]
+#[derive(Component, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct RocketWeapon {
     pub explosion_radius: f32,
 }
@@ -39,11 +43,36 @@ pub enum ProjectileKind {
 // Mermi component'i
 #[derive(Component)]
 pub struct Projectile {
+    /// Ateşleyen silah - isabet istatistikleri için
+    pub source: WeaponId,
+    /// Ateşleyen silah entity'si - isabette uygulanacak durum etkileri buradan okunur
+    pub weapon: Entity,
     pub direction: Vec3,
     pub speed: f32,
     pub damage: f32,
     pub lifetime: Timer,
     pub kind: ProjectileKind,
+    /// Ateşleyen silahın evrim davranışı
+    pub behaviour: WeaponBehaviour,
+    /// Delip geçtiği düşmanlar - aynı düşmana iki kez vurmasın
+    pub pierced: Vec<Entity>,
+}
+
+/// Silahın ateşlediği merminin görünümü ve ömrü - silah tanımından gelir
+#[derive(Component, Clone, Copy)]
+pub struct ProjectileTemplate {
+    pub shape: ProjectileShape,
+    pub lifetime: f32,
+    pub color: Color,
+}
+
+impl ProjectileTemplate {
+    pub fn mesh(&self) -> Mesh {
+        match self.shape {
+            ProjectileShape::Circle { radius } => Circle::new(radius).into(),
+            ProjectileShape::Square { size } => Rectangle::new(size, size).into(),
+        }
+    }
 }
 
 #[derive(Component)]
@@ -51,27 +80,36 @@ pub struct Explosion{
     pub lifetime: Timer,
 }
 
-#[derive(Component)]
+#[derive(Component, Clone, Copy, Serialize, Deserialize)]
 pub struct PlayerAddictedWeapon{
     pub radius: f32,
 }
 
-
-
-// Player için silahları bir kere spawn et
-
+/// Bir silahın düşmana her isabetinde gönderilir (silah başına hasar istatistikleri).
+/// Öldürmeler `EnemyDiedEvent::killer` ile sayılır.
+#[derive(Message, Clone, Debug)]
+pub struct WeaponHitEvent {
+    pub weapon: WeaponId,
+    pub target: Entity,
+    /// İsabet anında düşmanın konumu (geri itmeden sonra)
+    pub position: Vec3,
+    /// Düşmandan gerçekten eksilen can (artan hasar sayılmaz)
+    pub damage: i32,
+    pub crit: bool,
+    pub over_time: bool,
+}
 
 // Lazer silahlarını ateşle
 pub fn fire_laser_weapons(
     mut commands: Commands,
     time: Res<Time>,
-    mut weapons: Query<(&mut Weapon, &LaserWeapon), With<LaserWeapon>>,
-    players: Query<&Transform, With<Player>>,
-    enemies: Query<&Transform, With<Enemy>>,
+    mut weapons: Query<(Entity, &mut Weapon, &LaserWeapon, &ProjectileTemplate, &WeaponBehaviour, &WeaponLevel)>,
+    players: Query<(&Transform, &PlayerStats), With<Player>>,
+    grid: Res<SpatialGrid>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<ColorMaterial>>,
 ) {
-    for (mut weapon, laser) in weapons.iter_mut() {
+    for (weapon_entity, mut weapon, laser, template, behaviour, level) in weapons.iter_mut() {
         weapon.fire_timer.tick(time.delta());
         
         if !weapon.fire_timer.just_finished() {
@@ -79,32 +117,38 @@ pub fn fire_laser_weapons(
         }
 
         // Owner player'ı bul
-        let Ok(player_transform) = players.get(weapon.owner) else {
+        let Ok((player_transform, stats)) = players.get(weapon.owner) else {
             continue;
         };
 
         // En yakın düşmanı bul
-        let Some(target_pos) = find_nearest_enemy(player_transform.translation, &enemies) else {
+        let Some(target_pos) = find_nearest_enemy(player_transform.translation, &grid) else {
             continue;
         };
 
         let direction = (target_pos - player_transform.translation).normalize();
         
-        // Mermi spawn et
-        commands.spawn((
-            GameEntity,
-            Projectile {
-                direction,
-                speed: weapon.speed,
-                damage: weapon.damage,
-                lifetime: Timer::from_seconds(3.0, TimerMode::Once),
-                kind: ProjectileKind::Laser { color: laser.color },
-            },
-            Mesh2d(meshes.add(Circle::new(8.0))),
-            MeshMaterial2d(materials.add(ColorMaterial::from(laser.color))),
-            Transform::from_translation(player_transform.translation + Vec3::new(0.0, 0.0, 10.0)),
-            GlobalTransform::default(),
-        ));
+        // Mermi spawn et - Amount pasifi kadar ekstra mermi yelpaze şeklinde
+        for direction in spread_directions(direction, 1 + stats.amount) {
+            commands.spawn((
+                GameEntity,
+                Projectile {
+                    source: level.weapon_type.clone(),
+                    weapon: weapon_entity,
+                    direction,
+                    speed: weapon.speed,
+                    damage: weapon.damage,
+                    lifetime: Timer::from_seconds(template.lifetime, TimerMode::Once),
+                    kind: ProjectileKind::Laser { color: laser.color },
+                    behaviour: *behaviour,
+                    pierced: Vec::new(),
+                },
+                Mesh2d(meshes.add(template.mesh())),
+                MeshMaterial2d(materials.add(ColorMaterial::from(template.color))),
+                Transform::from_translation(player_transform.translation + Vec3::new(0.0, 0.0, 10.0)),
+                GlobalTransform::default(),
+            ));
+        }
     }
 }
 
@@ -112,65 +156,66 @@ pub fn fire_laser_weapons(
 pub fn fire_rocket_weapons(
     mut commands: Commands,
     time: Res<Time>,
-    mut weapons: Query<(&mut Weapon, &RocketWeapon), With<RocketWeapon>>,
-    players: Query<&Transform, With<Player>>,
-    enemies: Query<&Transform, With<Enemy>>,
+    mut weapons: Query<(Entity, &mut Weapon, &RocketWeapon, &ProjectileTemplate, &WeaponBehaviour, &WeaponLevel)>,
+    players: Query<(&Transform, &PlayerStats), With<Player>>,
+    grid: Res<SpatialGrid>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<ColorMaterial>>,
 ) {
-    for (mut weapon, rocket) in weapons.iter_mut() {
+    for (weapon_entity, mut weapon, rocket, template, behaviour, level) in weapons.iter_mut() {
         weapon.fire_timer.tick(time.delta());
 
         if !weapon.fire_timer.just_finished() {
             continue;
         }
 
-        let Ok(player_transform) = players.get(weapon.owner) else {
+        let Ok((player_transform, stats)) = players.get(weapon.owner) else {
             continue;
         };
 
-        let Some(target_pos) = find_nearest_enemy(player_transform.translation, &enemies) else {
+        let Some(target_pos) = find_nearest_enemy(player_transform.translation, &grid) else {
             continue;
         };
 
         let direction = (target_pos - player_transform.translation).normalize();
 
         // Roket mermisi spawn et - silah entity'sindeki explosion_radius kullan
-        commands.spawn((
-            GameEntity,
-            Projectile {
-                direction,
-                speed: weapon.speed,
-                damage: weapon.damage,
-                lifetime: Timer::from_seconds(5.0, TimerMode::Once),
-                kind: ProjectileKind::Rocket { explosion_radius: rocket.explosion_radius },
-            },
-            Mesh2d(meshes.add(Rectangle::new(12.0, 12.0))),
-            MeshMaterial2d(materials.add(ColorMaterial::from(Color::srgb(1.0, 0.5, 0.0)))),
-            Transform::from_translation(player_transform.translation + Vec3::new(0.0, 0.0, 10.0)),
-            GlobalTransform::default(),
-        ));
+        for direction in spread_directions(direction, 1 + stats.amount) {
+            commands.spawn((
+                GameEntity,
+                Projectile {
+                    source: level.weapon_type.clone(),
+                    weapon: weapon_entity,
+                    direction,
+                    speed: weapon.speed,
+                    damage: weapon.damage,
+                    lifetime: Timer::from_seconds(template.lifetime, TimerMode::Once),
+                    kind: ProjectileKind::Rocket { explosion_radius: rocket.explosion_radius },
+                    behaviour: *behaviour,
+                    pierced: Vec::new(),
+                },
+                Mesh2d(meshes.add(template.mesh())),
+                MeshMaterial2d(materials.add(ColorMaterial::from(template.color))),
+                Transform::from_translation(player_transform.translation + Vec3::new(0.0, 0.0, 10.0)),
+                GlobalTransform::default(),
+            ));
+        }
     }
 }
 
-
-
 pub fn move_player_addicted_weapons(
-    mut commands: Commands,
     time: Res<Time>,
-    mut player_query: Query<(&Transform, &mut Player), (With<Player>, Without<Enemy>, Without<Projectile>, Without<PlayerAddictedWeapon>)>,
+    player_query: Query<&Transform, (With<Player>, Without<PlayerAddictedWeapon>)>,
     // PlayerAddictedWeapon referansını da alıyoruz ki radius'ı okuyup görseli güncelleyebilelim
-    mut player_addicted_weapon: Query<(&mut Transform, &WeaponStats, &mut Weapon, &PlayerAddictedWeapon), With<PlayerAddictedWeapon>>,
-    mut enemies: Query<(&Transform, Entity, &mut Enemy), Without<PlayerAddictedWeapon>>,
-    mut meshes: ResMut<Assets<Mesh>>,
-    mut materials: ResMut<Assets<ColorMaterial>>,
-    audio: Res<GameAudio>
-
+    mut player_addicted_weapon: Query<(Entity, &mut Transform, &mut Weapon, &PlayerAddictedWeapon, &WeaponLevel)>,
+    enemies: Query<(&Transform, &Enemy), Without<PlayerAddictedWeapon>>,
+    grid: Res<SpatialGrid>,
+    mut damage: DamageWriter,
 ){
-    let Ok(mut player_transform) = player_query.single_mut() else { return; };
-    for (mut addicted_transform, _weapon_stats, mut weapon, addicted_comp) in player_addicted_weapon.iter_mut() {
+    let Ok(player_transform) = player_query.single() else { return; };
+    for (weapon_entity, mut addicted_transform, mut weapon, addicted_comp, level) in player_addicted_weapon.iter_mut() {
         // Pozisyonu takip et
-        addicted_transform.translation = player_transform.0.translation;
+        addicted_transform.translation = player_transform.translation;
         // Görsel ölçeği radius'a göre güncelle
         let visual_scale = addicted_comp.radius;
         addicted_transform.scale = Vec3::splat(visual_scale);
@@ -180,16 +225,19 @@ pub fn move_player_addicted_weapons(
         if !weapon.fire_timer.just_finished() { continue; }
 
         let weapon_radius = addicted_comp.radius;
-        for (enemy_transform, enemy_entity, mut enemy) in enemies.iter_mut() {
-            let dist = enemy_transform.translation.distance(player_transform.0.translation);
-
-            if dist <= weapon_radius {
-                enemy.health = enemy.health.saturating_sub(weapon.damage as i32);
-                if enemy.health <= 0 {
-                    player_transform.1.score += 1;
-                    enemy.despawn(enemy_entity, &enemy_transform.translation, &mut *meshes, &mut *materials, &mut commands, &audio);
-                }
+        for candidate in grid.query_radius(player_transform.translation.truncate(), weapon_radius, SpatialLayer::Enemy) {
+            let Ok((enemy_transform, enemy)) = enemies.get(candidate.entity) else {
+                continue;
+            };
+            if enemy.health <= 0 {
+                continue;
             }
+            let dist = enemy_transform.translation.distance(player_transform.translation);
+            if dist > weapon_radius {
+                continue;
+            }
+
+            damage.weapon_hit(weapon_entity, level.weapon_type.clone(), candidate.entity, weapon.damage, Vec3::ZERO);
         }
     }
 }
@@ -212,11 +260,11 @@ pub fn move_projectiles(
     mut commands: Commands,
     time: Res<Time>,
     mut projectiles: Query<(Entity, &mut Transform, &mut Projectile), With<Projectile>>,
-    mut enemies: Query<(Entity, &mut Transform, &mut Enemy, &mut AABB), Without<Projectile>>,
-    mut player: Single<&mut Player>,
+    enemies: Query<(Entity, &Transform, &Enemy, &AABB), Without<Projectile>>,
+    grid: Res<SpatialGrid>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<ColorMaterial>>,
-    audio: Res<GameAudio>
+    mut damage: DamageWriter,
 ) {
     for (proj_entity, mut proj_transform, mut projectile) in projectiles.iter_mut() {
         // Hareketi uygula
@@ -229,24 +277,31 @@ pub fn move_projectiles(
             continue;
         }
 
-        // Düşman çarpışma kontrolü
+        // Düşman çarpışma kontrolü - sadece merminin bulunduğu hücrelerdeki adaylar
+        let hit_point = proj_transform.translation.truncate();
+        let candidates = grid.query_aabb(hit_point - Vec2::splat(5.), hit_point + Vec2::splat(5.), SpatialLayer::Enemy);
 
-
-            match &projectile.kind {
+            match projectile.kind {
                 ProjectileKind::Laser { .. } => {
-                    for (enemy_entity, mut enemy_transform, mut enemy, mut enemy_aabb) in enemies.iter_mut() {
-                        if enemy_aabb.contains_point(proj_transform.translation) {
-                            // Knockback
-                            enemy_transform.translation += projectile.direction * 10.;
-                            enemy_aabb.change_point(enemy_transform.translation);
-                            // Hasar
-                            enemy.health = enemy.health.saturating_sub(projectile.damage as i32);
-                            // Mermi yok et
-                            commands.entity(proj_entity).try_despawn();
-                            // Düşman öldüyse
-                            if enemy.health <= 0 {
-                                enemy.despawn(enemy_entity, &enemy_transform.translation, &mut *meshes, &mut *materials, &mut commands, &audio);
-                                player.score += 1;
+                    for candidate in candidates {
+                        let Ok((enemy_entity, _enemy_transform, enemy, enemy_aabb)) = enemies.get(candidate.entity) else {
+                            continue;
+                        };
+                        if projectile.pierced.contains(&enemy_entity) {
+                            continue;
+                        }
+                        if enemy.health > 0 && enemy_aabb.contains_point(proj_transform.translation) {
+                            // Hasar ve knockback `apply_damage`'de uygulanır
+                            damage.weapon_hit(projectile.weapon, projectile.source.clone(), enemy_entity, projectile.damage, projectile.direction * 10.);
+                            // Delici mermiler hakkı bitene kadar devam eder, diğerleri yok olur
+                            let can_pierce = match projectile.behaviour {
+                                WeaponBehaviour::Piercing { count } => projectile.pierced.len() < count as usize,
+                                _ => false,
+                            };
+                            if can_pierce {
+                                projectile.pierced.push(enemy_entity);
+                            } else {
+                                commands.entity(proj_entity).try_despawn();
                             }
                             break;
                         }
@@ -256,8 +311,11 @@ pub fn move_projectiles(
                     // Önce roketin herhangi bir düşmana çarpıp çarpmadığını kontrol et
                     let mut explosion_pos: Option<Vec3> = None;
 
-                    for (_enemy_entity, _enemy_transform, _enemy, enemy_aabb) in enemies.iter() {
-                        if enemy_aabb.contains_point(proj_transform.translation) {
+                    for candidate in candidates {
+                        let Ok((_enemy_entity, _enemy_transform, enemy, enemy_aabb)) = enemies.get(candidate.entity) else {
+                            continue;
+                        };
+                        if enemy.health > 0 && enemy_aabb.contains_point(proj_transform.translation) {
                             // Roket bir düşmana çarptı, patlama konumunu kaydet
                             explosion_pos = Some(proj_transform.translation);
                             break;
@@ -266,31 +324,41 @@ pub fn move_projectiles(
 
                     // Eğer patlama olduysa, patlama yarıçapındaki TÜM düşmanlara hasar ver
                     if let Some(explosion_center) = explosion_pos {
-                        // Patlama görselini oluştur
-                        commands.spawn((
-                            GameEntity,
-                            Mesh2d(meshes.add(Circle::new(*explosion_radius))),
-                            MeshMaterial2d(materials.add(ColorMaterial::from(Color::srgba(1.0, 0.1, 0.0, 0.3)))),
-                            Transform::from_translation(explosion_center),
-                            Explosion {
-                                lifetime: Timer::from_seconds(0.2, TimerMode::Once),
-                            },
-                        ));
-
-                        // Tüm düşmanları tekrar tara ve patlama yarıçapındakilere hasar ver
-                        for (enemy_entity, mut enemy_transform, mut enemy, mut enemy_aabb) in enemies.iter_mut() {
-                            let dist = enemy_transform.translation.distance(explosion_center);
-                            if dist <= *explosion_radius {
-                                // Knockback - patlamadan uzağa it
-                                let knockback_dir = (enemy_transform.translation - explosion_center).normalize_or_zero();
-                                enemy_transform.translation += knockback_dir * 20.;
-                                enemy_aabb.change_point(enemy_transform.translation);
-
-                                // Hasar
-                                enemy.health = enemy.health.saturating_sub(projectile.damage as i32);
+                        // Ana patlama + Cluster Launcher'ın etrafa saçtığı küçük patlamalar
+                        let mut blasts = vec![(explosion_center, explosion_radius, projectile.damage)];
+                        if let WeaponBehaviour::Cluster { bomblets, radius, damage_scale } = projectile.behaviour {
+                            for i in 0..bomblets {
+                                let angle = std::f32::consts::TAU * i as f32 / bomblets as f32;
+                                let offset = Vec3::new(angle.cos(), angle.sin(), 0.0) * explosion_radius;
+                                blasts.push((explosion_center + offset, radius, projectile.damage * damage_scale));
+                            }
+                        }
+
+                        for (blast_center, blast_radius, blast_damage) in blasts {
+                            // Patlama görselini oluştur
+                            commands.spawn((
+                                GameEntity,
+                                Mesh2d(meshes.add(Circle::new(blast_radius))),
+                                MeshMaterial2d(materials.add(ColorMaterial::from(Color::srgba(1.0, 0.1, 0.0, 0.3)))),
+                                Transform::from_translation(blast_center),
+                                Explosion {
+                                    lifetime: Timer::from_seconds(0.2, TimerMode::Once),
+                                },
+                            ));
+
+                            // Patlama yarıçapındaki düşmanlara hasar ver
+                            for candidate in grid.query_radius(blast_center.truncate(), blast_radius, SpatialLayer::Enemy) {
+                                let Ok((enemy_entity, enemy_transform, enemy, _enemy_aabb)) = enemies.get(candidate.entity) else {
+                                    continue;
+                                };
                                 if enemy.health <= 0 {
-                                    enemy.despawn(enemy_entity, &enemy_transform.translation, &mut *meshes, &mut *materials, &mut commands, &audio);
-                                    player.score += 1;
+                                    continue;
+                                }
+                                let dist = enemy_transform.translation.distance(blast_center);
+                                if dist <= blast_radius {
+                                    // Knockback - patlamadan uzağa it
+                                    let knockback_dir = (enemy_transform.translation - blast_center).normalize_or_zero();
+                                    damage.weapon_hit(projectile.weapon, projectile.source.clone(), enemy_entity, blast_damage, knockback_dir * 20.);
                                 }
                             }
                         }
@@ -300,19 +368,26 @@ pub fn move_projectiles(
                     }
                 }
             }
-
-
     }
 }
 
+/// Hedef yönü etrafında `count` mermilik yelpaze
+fn spread_directions(direction: Vec3, count: u32) -> Vec<Vec3> {
+    const SPREAD: f32 = 0.15;
+    let center = (count as f32 - 1.0) / 2.0;
+    (0..count)
+        .map(|i| {
+            let angle = (i as f32 - center) * SPREAD;
+            Quat::from_rotation_z(angle) * direction
+        })
+        .collect()
+}
+
 // Yardımcı fonksiyon - en yakın düşmanı bul
 fn find_nearest_enemy(
     position: Vec3,
-    enemies: &Query<&Transform, With<Enemy>>
+    grid: &SpatialGrid,
 ) -> Option<Vec3> {
-    enemies
-        .iter()
-        .map(|t| (t.translation, position.distance(t.translation)))
-        .min_by(|a, b| a.1.partial_cmp(&b.1).unwrap())
-        .map(|(pos, _)| pos)
+    grid.nearest(position.truncate(), SpatialLayer::Enemy)
+        .map(|entry| entry.center().extend(position.z))
 }
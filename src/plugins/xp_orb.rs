@@ -0,0 +1,143 @@
+use bevy::prelude::*;
+use crate::plugins::aabb::AABB;
+use crate::plugins::enemy::{Collectible, XP};
+use crate::plugins::game_state::GameplaySet;
+use crate::plugins::player::{collect_xp, Player, XPMagnetite};
+use crate::plugins::weapons::GameEntity;
+
+/// Yerde bundan fazla XP küresi olursa fazlası birleştirilir - uzun koşularda entity sayısı sınırlı kalır
+pub const MAX_XP_ORBS: usize = 300;
+/// Bu değerden itibaren yeşil küre
+const GREEN_XP: i32 = 20;
+/// Bu değerden itibaren kırmızı küre
+const RED_XP: i32 = 60;
+
+/// XP kürelerinin değerine göre görünümü ve sayı sınırı
+pub struct XpOrbPlugin;
+
+impl Plugin for XpOrbPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<XpOrbAssets>()
+            .add_systems(FixedUpdate, merge_xp_orbs.after(collect_xp).in_set(GameplaySet));
+    }
+}
+
+/// Kürenin değer kademesi: mavi < yeşil < kırmızı
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum XpTier {
+    Blue,
+    Green,
+    Red,
+}
+
+impl XpTier {
+    pub const ALL: [XpTier; 3] = [XpTier::Blue, XpTier::Green, XpTier::Red];
+
+    pub fn for_amount(amount: i32) -> Self {
+        if amount >= RED_XP {
+            XpTier::Red
+        } else if amount >= GREEN_XP {
+            XpTier::Green
+        } else {
+            XpTier::Blue
+        }
+    }
+
+    fn color(self) -> Color {
+        match self {
+            XpTier::Blue => Color::srgb(0.25, 0.45, 1.0),
+            XpTier::Green => Color::srgb(0.2, 0.9, 0.3),
+            XpTier::Red => Color::srgb(0.9, 0.1, 0.1),
+        }
+    }
+
+    fn radius(self) -> f32 {
+        match self {
+            XpTier::Blue => 5.0,
+            XpTier::Green => 6.5,
+            XpTier::Red => 8.0,
+        }
+    }
+}
+
+/// Kademe başına paylaşılan mesh ve materyal - her küreye yenisi yaratılmaz
+#[derive(Resource)]
+pub struct XpOrbAssets {
+    tiers: Vec<(Handle<Mesh>, Handle<ColorMaterial>)>,
+}
+
+impl FromWorld for XpOrbAssets {
+    fn from_world(world: &mut World) -> Self {
+        let meshes: Vec<_> = {
+            let mut meshes = world.resource_mut::<Assets<Mesh>>();
+            XpTier::ALL.iter().map(|tier| meshes.add(Circle::new(tier.radius()))).collect()
+        };
+        let mut materials = world.resource_mut::<Assets<ColorMaterial>>();
+        Self {
+            tiers: XpTier::ALL
+                .iter()
+                .zip(meshes)
+                .map(|(tier, mesh)| (mesh, materials.add(ColorMaterial::from(tier.color()))))
+                .collect(),
+        }
+    }
+}
+
+impl XpOrbAssets {
+    pub fn visuals(&self, amount: i32) -> (Mesh2d, MeshMaterial2d<ColorMaterial>) {
+        let (mesh, material) = &self.tiers[XpTier::for_amount(amount) as usize];
+        (Mesh2d(mesh.clone()), MeshMaterial2d(material.clone()))
+    }
+}
+
+pub fn spawn_xp_orb(commands: &mut Commands, assets: &XpOrbAssets, amount: i32, translation: Vec3) -> Entity {
+    let mut aabb = AABB { min_x: 0.0, min_y: 0.0, max_x: 0.0, max_y: 0.0, width: 20.0, height: 20.0 };
+    aabb.change_point(translation);
+    commands
+        .spawn((GameEntity, Collectible, XP { amount }, Transform::from_translation(translation), aabb, assets.visuals(amount)))
+        .id()
+}
+
+/// Sınır aşılınca oyuncuya en uzak küreler, kalanlardan kendilerine en yakın olana eklenir.
+/// Oyuncuya çekilmekte olan küreler birleşmez
+fn merge_xp_orbs(
+    mut commands: Commands,
+    mut orbs: Query<(Entity, &Transform, &mut XP), Without<XPMagnetite>>,
+    all_orbs: Query<(), With<XP>>,
+    player_query: Query<&Transform, With<Player>>,
+    assets: Res<XpOrbAssets>,
+) {
+    let excess = all_orbs.iter().count().saturating_sub(MAX_XP_ORBS);
+    if excess == 0 {
+        return;
+    }
+    let center = player_query.single().map_or(Vec2::ZERO, |transform| transform.translation.truncate());
+    let mut candidates: Vec<(Entity, Vec2)> = orbs.iter().map(|(entity, transform, _)| (entity, transform.translation.truncate())).collect();
+    // Eşit uzaklıkta entity sırası: kayıttan oynatılan koşu aynı küreleri birleştirsin
+    candidates.sort_by(|a, b| b.1.distance_squared(center).total_cmp(&a.1.distance_squared(center)).then(a.0.cmp(&b.0)));
+    let (merged, kept) = candidates.split_at(excess.min(candidates.len().saturating_sub(1)));
+
+    let mut grown = Vec::new();
+    for &(entity, position) in merged {
+        let nearest = kept
+            .iter()
+            .min_by(|a, b| a.1.distance_squared(position).total_cmp(&b.1.distance_squared(position)).then(a.0.cmp(&b.0)));
+        let Some(&(target, _)) = nearest else {
+            break;
+        };
+        let Ok([(_, _, mut xp), (_, _, mut target_xp)]) = orbs.get_many_mut([entity, target]) else {
+            continue;
+        };
+        target_xp.amount += std::mem::take(&mut xp.amount);
+        commands.entity(entity).despawn();
+        grown.push(target);
+    }
+
+    grown.sort();
+    grown.dedup();
+    for target in grown {
+        if let Ok((_, _, xp)) = orbs.get(target) {
+            commands.entity(target).insert(assets.visuals(xp.amount));
+        }
+    }
+}
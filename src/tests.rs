@@ -0,0 +1,22 @@
+//! Oyunun gerçek sistemleriyle, pencere/render olmadan çalışan entegrasyon testleri
+
+mod harness;
+mod weapons;
+mod progression;
+mod restart;
+mod status_effects;
+mod damage;
+mod hit_feedback;
+mod hud;
+mod pause;
+mod settings;
+mod input;
+mod movement;
+mod player_damage;
+mod loot;
+mod xp_orb;
+mod profile;
+mod run_save;
+mod character;
+mod replay;
+mod boss;
@@ -0,0 +1,87 @@
+use bevy::prelude::*;
+use crate::plugins::boss::{Boss, BossKind};
+use crate::plugins::damage::{DamageEvent, DamageType};
+use crate::plugins::enemy::{Enemy, EnemySprit};
+use crate::plugins::enemy_kind::EnemyKind;
+use crate::plugins::game_state::GameState;
+use crate::plugins::loot::Pickup;
+use crate::tests::harness::TestApp;
+
+const MAX_HEALTH: i32 = 1000;
+
+/// Yerinden kıpırdamayan, `MAX_HEALTH` canlı bir Queen Bee
+fn spawn_boss(test: &mut TestApp, position: Vec2) -> Entity {
+    let boss = test.spawn_enemy(position, MAX_HEALTH);
+    test.world_mut().get_mut::<Enemy>(boss).unwrap().kind = EnemyKind::Boss(BossKind::QueenBee);
+    test.world_mut().entity_mut(boss).insert(Boss::new(BossKind::QueenBee, MAX_HEALTH, 1));
+    test.world_mut().spawn((Sprite::default(), EnemySprit { index: 0, frames: 1, directional: false }, ChildOf(boss)));
+    boss
+}
+
+fn set_health(test: &mut TestApp, boss: Entity, health: i32) {
+    test.world_mut().get_mut::<Enemy>(boss).unwrap().health = health;
+    test.tick();
+}
+
+#[test]
+fn bosses_change_phase_at_the_health_thresholds() {
+    let mut test = TestApp::new();
+    test.spawn_player(Vec2::ZERO);
+    let boss = spawn_boss(&mut test, Vec2::new(600.0, 0.0));
+    let phase = |test: &TestApp| test.world().get::<Boss>(boss).unwrap().phase;
+    let thresholds: Vec<f32> = BossKind::QueenBee.phases().iter().map(|phase| phase.health_threshold).collect();
+
+    set_health(&mut test, boss, (MAX_HEALTH as f32 * thresholds[1]) as i32 + 1);
+    assert_eq!(phase(&test), 0);
+    set_health(&mut test, boss, (MAX_HEALTH as f32 * thresholds[1]) as i32);
+    assert_eq!(phase(&test), 1);
+    let interval = test.world().get::<Boss>(boss).unwrap().attack_timer.duration().as_secs_f32();
+    assert_eq!(interval, BossKind::QueenBee.phases()[1].attack_interval);
+
+    // Son fazdan geri dönülmez
+    set_health(&mut test, boss, (MAX_HEALTH as f32 * thresholds[2]) as i32);
+    assert_eq!(phase(&test), 2);
+    set_health(&mut test, boss, MAX_HEALTH);
+    assert_eq!(phase(&test), 2);
+}
+
+#[test]
+fn killing_a_boss_drops_a_chest() {
+    let mut test = TestApp::new();
+    test.spawn_player(Vec2::ZERO);
+    let boss = spawn_boss(&mut test, Vec2::new(600.0, 0.0));
+
+    test.world_mut().write_message(DamageEvent {
+        source: None,
+        weapon: None,
+        target: boss,
+        amount: 10_000.0,
+        damage_type: DamageType::Energy,
+        crit: false,
+        over_time: false,
+        knockback: Vec3::ZERO,
+    });
+    test.tick();
+
+    assert!(test.world().get_entity(boss).is_err());
+    let world = test.world_mut();
+    let chests: Vec<Vec2> = world
+        .query::<(&Pickup, &Transform)>()
+        .iter(world)
+        .filter(|(pickup, _)| **pickup == Pickup::Chest)
+        .map(|(_, transform)| transform.translation.truncate())
+        .collect();
+    assert_eq!(chests.len(), 1);
+    assert!(chests[0].distance(Vec2::new(600.0, 0.0)) < 50.0, "chest at {}", chests[0]);
+}
+
+#[test]
+fn debug_boss_spawns_are_not_recorded() {
+    let mut test = TestApp::loaded();
+    test.start_run();
+    test.press(KeyCode::F9);
+    assert_eq!(test.count::<With<Boss>>(), 1);
+    // Boss kayıtta yok - bu koşunun kaydı oynatılınca aynı koşuyu vermez
+    test.set_state(GameState::GameOver);
+    assert!(!test.record_path.exists());
+}
@@ -0,0 +1,177 @@
+use bevy::prelude::*;
+use crate::plugins::character::{CharacterAnimation, CharacterDefinitions, CharacterId, SelectedCharacter, SpriteAnimation};
+use crate::plugins::character_select::CharacterButton;
+use crate::plugins::game_state::GameState;
+use crate::plugins::main_menu::MenuButton;
+use crate::plugins::passive_item::PlayerStats;
+use crate::plugins::pause_menu::PauseButton;
+use crate::plugins::player::Player;
+use crate::plugins::profile::{PowerUpKind, Profile};
+use crate::plugins::weapon_definition::WeaponId;
+use crate::plugins::weapon_upgrade::WeaponLevel;
+use crate::tests::harness::TestApp;
+
+fn weapons(test: &mut TestApp) -> Vec<WeaponId> {
+    let world = test.world_mut();
+    world.query::<&WeaponLevel>().iter(world).map(|level| level.weapon_type.clone()).collect()
+}
+
+/// Seçim ekranındaki tıklanabilir karakterler
+fn pickable(test: &mut TestApp) -> Vec<String> {
+    let world = test.world_mut();
+    let mut ids: Vec<_> = world
+        .query::<&CharacterButton>()
+        .iter(world)
+        .filter_map(|button| match button {
+            CharacterButton::Pick(id) => Some(id.to_string()),
+            CharacterButton::Back => None,
+        })
+        .collect();
+    ids.sort();
+    ids
+}
+
+/// Ana menüden seçim ekranına geçip karakterle koşu başlat
+fn play_as(test: &mut TestApp, id: &str) -> Entity {
+    test.click(MenuButton::Play);
+    test.tick();
+    assert_eq!(test.state(), GameState::CharacterSelect);
+    test.click(CharacterButton::Pick(CharacterId::new(id)));
+    test.wait_for_state(GameState::Playing);
+    test.player()
+}
+
+#[test]
+fn the_picked_character_sets_stats_weapon_and_sprite() {
+    let mut test = TestApp::loaded();
+    test.click(MenuButton::Play);
+    test.tick();
+    // Kilitli kartlar düğme değil
+    assert_eq!(pickable(&mut test), ["gabe", "skeleton"]);
+    test.click(CharacterButton::Back);
+    test.tick();
+    assert_eq!(test.state(), GameState::MainMenu);
+
+    let player = play_as(&mut test, "gabe");
+    let stats = test.world().get::<Player>(player).unwrap();
+    assert_eq!((stats.health, stats.max_health, stats.movement), (80, 80, 240.0));
+    assert!((test.world().get::<PlayerStats>(player).unwrap().cooldown - 0.9).abs() < 1e-5);
+    assert_eq!(weapons(&mut test), vec![WeaponId::new("laser")]);
+    assert!(matches!(test.world().get::<CharacterAnimation>(player).unwrap().animation, SpriteAnimation::Strip { .. }));
+    // 24 piksellik kare 2.5 kat büyütülür
+    assert_eq!(test.world().get::<Sprite>(player).unwrap().custom_size, Some(Vec2::splat(60.0)));
+
+    // Yeniden başlatınca aynı karakter
+    test.set_state(GameState::Loading);
+    test.wait_for_state(GameState::Playing);
+    let player = test.player();
+    assert_eq!(test.world().get::<Player>(player).unwrap().movement, 240.0);
+}
+
+#[test]
+fn records_unlock_characters_and_the_shop_weapon_overrides_theirs() {
+    let mut test = TestApp::loaded();
+    test.world_mut().resource_mut::<Profile>().records.level = 5;
+    test.click(MenuButton::Play);
+    test.tick();
+    assert_eq!(pickable(&mut test), ["gabe", "mani", "skeleton"]);
+    test.click(CharacterButton::Back);
+    test.tick();
+
+    let player = play_as(&mut test, "mani");
+    assert_eq!(weapons(&mut test), vec![WeaponId::new("flame")]);
+    assert!((test.world().get::<PlayerStats>(player).unwrap().area - 1.15).abs() < 1e-5);
+
+    test.set_state(GameState::MainMenu);
+    {
+        let mut profile = test.world_mut().resource_mut::<Profile>();
+        profile.gold = 1_000;
+        assert!(profile.buy(PowerUpKind::StartingWeapon));
+        profile.starting_weapon = Some(WeaponId::new("rocket"));
+    }
+    play_as(&mut test, "mani");
+    assert_eq!(weapons(&mut test), vec![WeaponId::new("rocket")]);
+}
+
+/// Seçim ekranındaki karakter resimleri
+fn portraits(test: &mut TestApp) -> usize {
+    let world = test.world_mut();
+    world.query::<&ImageNode>().iter(world).filter(|image| image.texture_atlas.is_some()).count()
+}
+
+#[test]
+fn cards_are_rebuilt_when_a_portrait_loads_late() {
+    let mut test = TestApp::loaded();
+    let texture = test.world().resource::<CharacterDefinitions>().textures[&CharacterId::new("gabe")].clone();
+    test.wait_until("gabe portrait", |world| world.resource::<Assets<Image>>().contains(&texture));
+    let image = test.world_mut().resource_mut::<Assets<Image>>().remove(&texture).unwrap();
+    test.click(MenuButton::Play);
+    test.tick();
+    let cards = test.world().resource::<CharacterDefinitions>().by_id.len();
+    assert_eq!(portraits(&mut test), cards - 1);
+    let layouts = test.world().resource::<Assets<TextureAtlasLayout>>().len();
+
+    test.world_mut().resource_mut::<Assets<Image>>().insert(&texture, image).unwrap();
+    test.tick();
+    assert_eq!(portraits(&mut test), cards);
+    assert_eq!(pickable(&mut test), ["gabe", "skeleton"]);
+
+    // Ekranı yeniden açmak yeni atlas düzeni eklemez
+    test.click(CharacterButton::Back);
+    test.tick();
+    test.click(MenuButton::Play);
+    test.tick();
+    assert_eq!(test.world().resource::<Assets<TextureAtlasLayout>>().len(), layouts + 1);
+}
+
+#[test]
+fn finished_runs_update_the_records() {
+    let mut test = TestApp::loaded();
+    let player = test.start_run();
+    test.advance(2.0);
+    test.world_mut().get_mut::<Player>(player).unwrap().level = 4;
+    test.set_state(GameState::GameOver);
+
+    let records = test.world().resource::<Profile>().records;
+    assert_eq!(records.level, 4);
+    assert!(records.survived >= 2.0);
+    assert_eq!(Profile::load(&test.profile_path).unwrap().records, records);
+
+    // Daha kötü bir koşu rekoru düşürmez
+    test.start_run();
+    test.set_state(GameState::GameOver);
+    assert_eq!(test.world().resource::<Profile>().records, records);
+}
+
+#[test]
+fn a_resumed_run_keeps_its_character() {
+    let mut test = TestApp::loaded();
+    play_as(&mut test, "gabe");
+    test.press(KeyCode::Escape);
+    test.click(PauseButton::SaveAndQuit);
+    test.tick();
+    assert_eq!(test.state(), GameState::MainMenu);
+
+    test.world_mut().resource_mut::<SelectedCharacter>().0 = CharacterId::new("skeleton");
+    test.click(MenuButton::Continue);
+    test.wait_for_state(GameState::Playing);
+    let player = test.player();
+    assert_eq!(test.world().get::<Player>(player).unwrap().movement, 240.0);
+    assert!(matches!(test.world().get::<CharacterAnimation>(player).unwrap().animation, SpriteAnimation::Strip { .. }));
+}
+
+#[test]
+fn strip_animations_loop_the_run_frames_and_mirror_to_the_left() {
+    let animation = CharacterAnimation { columns: 7, animation: SpriteAnimation::Strip { idle: 0, run: (1, 6) } };
+    assert_eq!(animation.next_frame(3, Vec2::X, false, true), 0);
+    assert_eq!(animation.next_frame(0, Vec2::X, true, false), 1);
+    assert_eq!(animation.next_frame(1, Vec2::X, true, false), 1);
+    assert_eq!(animation.next_frame(5, Vec2::X, true, true), 6);
+    assert_eq!(animation.next_frame(6, Vec2::X, true, true), 1);
+
+    assert!(animation.flip_x(Vec2::NEG_X, false));
+    assert!(!animation.flip_x(Vec2::X, true));
+    // Dikey harekette son yatay yön korunur
+    assert!(animation.flip_x(Vec2::Y, true));
+    assert!(!CharacterAnimation::default().flip_x(Vec2::NEG_X, false));
+}
@@ -0,0 +1,125 @@
+use bevy::prelude::*;
+use crate::plugins::damage::{DamageEvent, DamageType, CRIT_MULTIPLIER};
+use crate::plugins::enemy::{Enemy, EnemyDiedEvent, XP};
+use crate::plugins::enemy_kind::EnemyKind;
+use crate::plugins::player::Player;
+use crate::plugins::status_effect::{StatusApplication, StatusEffects, StatusKind};
+use crate::plugins::weapon_definition::{WeaponBehaviour, WeaponId};
+use crate::plugins::weapons::WeaponHitEvent;
+use crate::tests::harness::TestApp;
+
+fn hit(target: Entity, amount: f32, damage_type: DamageType) -> DamageEvent {
+    DamageEvent {
+        source: Some(WeaponId::new("laser")),
+        weapon: None,
+        target,
+        amount,
+        damage_type,
+        crit: false,
+        over_time: false,
+        knockback: Vec3::ZERO,
+    }
+}
+
+fn deal(test: &mut TestApp, event: DamageEvent) {
+    test.world_mut().write_message(event);
+    test.tick();
+}
+
+fn spawn_kind(test: &mut TestApp, position: Vec2, kind: EnemyKind, health: i32) -> Entity {
+    let enemy = test.spawn_enemy(position, health);
+    test.world_mut().get_mut::<Enemy>(enemy).unwrap().kind = kind;
+    enemy
+}
+
+fn health(test: &TestApp, enemy: Entity) -> i32 {
+    test.world().get::<Enemy>(enemy).expect("enemy despawned").health
+}
+
+#[test]
+fn armor_and_resistances_reduce_damage() {
+    let mut test = TestApp::new();
+    test.spawn_player(Vec2::ZERO);
+    let tank = spawn_kind(&mut test, Vec2::new(300.0, 0.0), EnemyKind::Tank, 1000);
+
+    // Fiziksel: 100 * 0.75 - 5 armor
+    deal(&mut test, hit(tank, 100.0, DamageType::Physical));
+    assert_eq!(health(&test, tank), 1000 - 70);
+    // Direnç yok, sadece armor
+    deal(&mut test, hit(tank, 100.0, DamageType::Energy));
+    assert_eq!(health(&test, tank), 930 - 95);
+    // Armor'dan küçük isabetler bile en az 1 hasar verir
+    deal(&mut test, hit(tank, 2.0, DamageType::Fire));
+    assert_eq!(health(&test, tank), 835 - 1);
+}
+
+#[test]
+fn crits_multiply_damage_and_knockback_respects_resistance() {
+    let mut test = TestApp::new();
+    test.record::<WeaponHitEvent>();
+    test.spawn_player(Vec2::ZERO);
+    let swarmer = test.spawn_enemy(Vec2::new(300.0, 0.0), 1000);
+    let charger = spawn_kind(&mut test, Vec2::new(-300.0, 0.0), EnemyKind::Charger, 1000);
+
+    deal(&mut test, DamageEvent { crit: true, knockback: Vec3::new(10.0, 0.0, 0.0), ..hit(swarmer, 50.0, DamageType::Energy) });
+    deal(&mut test, DamageEvent { knockback: Vec3::new(-10.0, 0.0, 0.0), ..hit(charger, 50.0, DamageType::Energy) });
+
+    assert_eq!(health(&test, swarmer), 1000 - (50.0 * CRIT_MULTIPLIER) as i32);
+    let x = |enemy: Entity| test.world().get::<Transform>(enemy).unwrap().translation.x;
+    assert!((x(swarmer) - 310.0).abs() < 0.01);
+    assert!((x(charger) + 307.0).abs() < 0.01);
+    let crits: Vec<bool> = test.recorded::<WeaponHitEvent>().iter().map(|hit| hit.crit).collect();
+    assert_eq!(crits, [true, false]);
+}
+
+#[test]
+fn kills_are_attributed_and_drop_xp_once() {
+    let mut test = TestApp::new();
+    test.record::<EnemyDiedEvent>();
+    let player = test.spawn_player(Vec2::ZERO);
+    let enemy = test.spawn_enemy(Vec2::new(300.0, 0.0), 30);
+
+    // Aynı tick'teki fazla isabetler ikinci bir ölüm üretmez
+    for _ in 0..3 {
+        test.world_mut().write_message(DamageEvent { source: Some(WeaponId::new("rocket")), ..hit(enemy, 40.0, DamageType::Physical) });
+    }
+    test.tick();
+
+    assert!(test.world().get_entity(enemy).is_err());
+    assert_eq!(test.count::<With<XP>>(), 1);
+    assert_eq!(test.world().get::<Player>(player).unwrap().score, 1);
+    let deaths = test.recorded::<EnemyDiedEvent>();
+    assert_eq!(deaths.len(), 1);
+    assert_eq!(deaths[0].killer, Some(WeaponId::new("rocket")));
+}
+
+#[test]
+fn damage_over_time_kills_credit_the_applying_weapon() {
+    let mut test = TestApp::new();
+    test.record::<EnemyDiedEvent>();
+    test.spawn_player(Vec2::ZERO);
+    let enemy = test.spawn_enemy(Vec2::new(300.0, 0.0), 5);
+    let burn = StatusApplication { kind: StatusKind::Burn, duration: 2.0, magnitude: 20.0, chance: 1.0 };
+    test.world_mut().get_mut::<StatusEffects>(enemy).unwrap().apply(&burn, Some(WeaponId::new("flame")));
+
+    let killed = test.advance_until(1.0, |world| world.get_entity(enemy).is_err());
+    assert!(killed, "burn never killed the enemy");
+    let deaths = test.recorded::<EnemyDiedEvent>();
+    assert_eq!(deaths.len(), 1);
+    assert_eq!(deaths[0].killer, Some(WeaponId::new("flame")));
+}
+
+#[test]
+fn life_drain_heals_the_player_on_kill() {
+    let mut test = TestApp::new();
+    let player = test.spawn_player(Vec2::ZERO);
+    test.world_mut().get_mut::<Player>(player).unwrap().health = 50;
+    let weapon = test.world_mut().spawn(WeaponBehaviour::LifeDrain { heal_per_kill: 3 }).id();
+    let survivor = test.spawn_enemy(Vec2::new(300.0, 0.0), 1000);
+    let victim = test.spawn_enemy(Vec2::new(-300.0, 0.0), 10);
+
+    deal(&mut test, DamageEvent { weapon: Some(weapon), ..hit(survivor, 10.0, DamageType::Fire) });
+    assert_eq!(test.world().get::<Player>(player).unwrap().health, 50);
+    deal(&mut test, DamageEvent { weapon: Some(weapon), ..hit(victim, 10.0, DamageType::Fire) });
+    assert_eq!(test.world().get::<Player>(player).unwrap().health, 53);
+}
@@ -0,0 +1,324 @@
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+use bevy::ecs::system::RunSystemOnce;
+use bevy::input::ButtonState;
+use bevy::input::keyboard::{Key, KeyboardInput, NativeKey};
+use bevy::prelude::*;
+use crate::GamePlugin;
+use crate::RunData;
+use crate::plugins::aabb::AABB;
+use crate::plugins::damage::DamageProfile;
+use crate::plugins::enemy::{Collectible, Enemy, XP};
+use crate::plugins::enemy_kind::EnemyKind;
+use crate::plugins::game_state::GameState;
+use crate::plugins::headless::HeadlessPlugin;
+use crate::plugins::loot::Pickup;
+use crate::plugins::passive_item::{PassiveInventory, PlayerStats};
+use crate::plugins::player::Player;
+use crate::plugins::profile::ProfileFile;
+use crate::plugins::replay::ReplaySettings;
+use crate::plugins::run_save::RunSaveFile;
+use crate::plugins::rng::SeedSettings;
+use crate::plugins::settings::SettingsFile;
+use crate::plugins::weapon_definition::{WeaponDefinitions, WeaponId};
+use crate::plugins::weapon_stats::WeaponSpawner;
+use crate::plugins::weapons::GameEntity;
+
+/// Asset'lerin yüklenmesi için beklenecek en uzun süre
+const LOAD_TIMEOUT: Duration = Duration::from_secs(60);
+/// Testlerde her koşu aynı seed ile başlar
+pub const TEST_SEED: u64 = 1;
+
+/// Her test kendi replay dosyasını yazsın, paralel testler birbirini ezmesin
+static NEXT_APP_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// `HeadlessPlugin` + `GamePlugin` ile kurulmuş test uygulaması.
+/// Her `update` bir sabit tick ilerler; zaman sadece `tick`/`advance` ile akar.
+pub struct TestApp {
+    pub app: App,
+    pub record_path: PathBuf,
+    /// Kullanıcının gerçek ayar dosyası yerine
+    pub settings_path: PathBuf,
+    /// Kullanıcının gerçek profili yerine
+    pub profile_path: PathBuf,
+    /// Kullanıcının yarım kalan koşusu yerine
+    pub run_save_path: PathBuf,
+}
+
+impl Drop for TestApp {
+    fn drop(&mut self) {
+        // GameOver'a giren testlerin yazdığı replay
+        let _ = std::fs::remove_file(&self.record_path);
+        let _ = std::fs::remove_file(&self.settings_path);
+        let _ = std::fs::remove_file(&self.profile_path);
+        let _ = std::fs::remove_file(&self.run_save_path);
+    }
+}
+
+impl TestApp {
+    /// Veri dosyaları yüklenmiş, boş bir arenada `Playing` durumunda başlar.
+    /// Oyuncu ve atlaslar hazır olmadığı için spawn director düşman üretmez;
+    /// dünyayı testin kendisi kurar.
+    pub fn new() -> Self {
+        let mut test = Self::loaded();
+        test.set_state(GameState::Playing);
+        test.tick();
+        test
+    }
+
+    /// Ana menüde, veri dosyaları yüklenmiş halde bekleyen uygulama - `start_run` ile gerçek koşu başlatılır
+    pub fn loaded() -> Self {
+        Self::build(None)
+    }
+
+    /// `--replay <replay>` ile açılmış uygulama - kayıtlı koşu yüklenmeye başlamıştır, `wait_for_state` ile beklenir
+    pub fn playing_back(replay: &Path) -> Self {
+        Self::build(Some(replay.to_path_buf()))
+    }
+
+    fn build(playback_path: Option<PathBuf>) -> Self {
+        let id = NEXT_APP_ID.fetch_add(1, Ordering::Relaxed);
+        let record_path: PathBuf = std::env::temp_dir().join(format!("vs-test-{}-{}.replay.ron", std::process::id(), id));
+        let settings_path: PathBuf = std::env::temp_dir().join(format!("vs-test-{}-{}.settings.ron", std::process::id(), id));
+        let profile_path: PathBuf = std::env::temp_dir().join(format!("vs-test-{}-{}.profile.ron", std::process::id(), id));
+        let run_save_path: PathBuf = std::env::temp_dir().join(format!("vs-test-{}-{}.run.ron", std::process::id(), id));
+
+        let mut app = App::new();
+        app.add_plugins(HeadlessPlugin)
+            .insert_resource(SeedSettings { fixed: Some(TEST_SEED) })
+            .insert_resource(ReplaySettings { record_path: record_path.clone(), playback_path, ..default() })
+            .insert_resource(SettingsFile { path: Some(settings_path.clone()) })
+            .insert_resource(ProfileFile { path: Some(profile_path.clone()) })
+            .insert_resource(RunSaveFile { path: Some(run_save_path.clone()) })
+            .add_plugins(GamePlugin);
+        app.finish();
+        app.cleanup();
+
+        let mut test = Self { app, record_path, settings_path, profile_path, run_save_path };
+        test.wait_until("data assets", |world| {
+            world
+                .run_system_once(|data: RunData, definitions: Res<WeaponDefinitions>| definitions.ready && data.loaded())
+                .unwrap_or(false)
+        });
+        test
+    }
+
+    /// Ana menüden gerçek yükleme akışıyla yeni bir koşu başlat ve oyuncu spawn olana kadar bekle
+    pub fn start_run(&mut self) -> Entity {
+        self.set_state(GameState::Loading);
+        self.wait_for_state(GameState::Playing);
+        self.player()
+    }
+
+    /// Yükleme gibi asenkron geçişlerin `state`'e ulaşmasını bekle
+    pub fn wait_for_state(&mut self, state: GameState) {
+        self.wait_until(&format!("{:?}", state), |world| *world.resource::<State<GameState>>().get() == state);
+    }
+
+    pub fn world(&self) -> &World {
+        self.app.world()
+    }
+
+    pub fn world_mut(&mut self) -> &mut World {
+        self.app.world_mut()
+    }
+
+    /// Tek frame = tek sabit tick
+    pub fn tick(&mut self) {
+        self.app.update();
+    }
+
+    /// `seconds` kadar oyun zamanı ilerlet
+    pub fn advance(&mut self, seconds: f32) {
+        for _ in 0..self.ticks_for(seconds) {
+            self.tick();
+        }
+    }
+
+    /// `done` doğru olana kadar en fazla `seconds` ilerlet; doğru olduysa true
+    pub fn advance_until(&mut self, seconds: f32, mut done: impl FnMut(&mut World) -> bool) -> bool {
+        for _ in 0..self.ticks_for(seconds) {
+            self.tick();
+            if done(self.world_mut()) {
+                return true;
+            }
+        }
+        false
+    }
+
+    fn ticks_for(&self, seconds: f32) -> u32 {
+        let timestep = self.world().resource::<Time<Fixed>>().timestep().as_secs_f32();
+        (seconds / timestep).ceil() as u32
+    }
+
+    /// Asenkron yüklemeler için gerçek zamanda bekle
+    pub fn wait_until(&mut self, what: &str, mut done: impl FnMut(&mut World) -> bool) {
+        let started = Instant::now();
+        while !done(self.world_mut()) {
+            assert!(started.elapsed() < LOAD_TIMEOUT, "timed out waiting for {}", what);
+            self.tick();
+            std::thread::sleep(Duration::from_millis(1));
+        }
+    }
+
+    pub fn state(&self) -> GameState {
+        *self.world().resource::<State<GameState>>().get()
+    }
+
+    pub fn set_state(&mut self, state: GameState) {
+        self.world_mut().resource_mut::<NextState<GameState>>().set(state);
+        self.tick();
+    }
+
+    /// Tuşa bir frame boyunca bas - `ButtonInput` her frame sıfırlandığı için gerçek klavye mesajı gönderilir
+    pub fn press(&mut self, key: KeyCode) {
+        for state in [ButtonState::Pressed, ButtonState::Released] {
+            self.world_mut().write_message(KeyboardInput {
+                key_code: key,
+                logical_key: Key::Unidentified(NativeKey::Unidentified),
+                state,
+                text: None,
+                repeat: false,
+                window: Entity::PLACEHOLDER,
+            });
+            self.tick();
+        }
+    }
+
+    /// Tuşu `release` edilene kadar basılı tut
+    pub fn hold(&mut self, key: KeyCode) {
+        self.world_mut().resource_mut::<ButtonInput<KeyCode>>().press(key);
+    }
+
+    pub fn release(&mut self, key: KeyCode) {
+        self.world_mut().resource_mut::<ButtonInput<KeyCode>>().release(key);
+    }
+
+    /// Düğmeye tıkla - headless'ta UI odak sistemi yok, `Interaction` elle değiştirilir
+    pub fn click<B: Component + PartialEq>(&mut self, button: B) {
+        let world = self.world_mut();
+        let (mut interaction, _) = world
+            .query::<(&mut Interaction, &B)>()
+            .iter_mut(world)
+            .find(|(_, candidate)| **candidate == button)
+            .expect("no such button");
+        *interaction = Interaction::Pressed;
+        self.tick();
+    }
+
+    pub fn player(&mut self) -> Entity {
+        self.world_mut()
+            .query_filtered::<Entity, With<Player>>()
+            .single(self.world())
+            .expect("exactly one player")
+    }
+
+    pub fn spawn_player(&mut self, position: Vec2) -> Entity {
+        self.world_mut()
+            .spawn((
+                GameEntity,
+                Transform::from_translation(position.extend(0.0)),
+                Player::default(),
+                PassiveInventory::default(),
+                PlayerStats::default(),
+                square(position, 40.0),
+            ))
+            .id()
+    }
+
+    /// Yerinden kıpırdamayan, hasar vermeyen bir hedef düşman
+    pub fn spawn_enemy(&mut self, position: Vec2, health: i32) -> Entity {
+        self.world_mut()
+            .spawn((
+                GameEntity,
+                Transform::from_translation(position.extend(0.0)),
+                Enemy {
+                    health,
+                    speed: 0.0,
+                    damage: 0,
+                    kind: EnemyKind::Swarmer,
+                    xp: 1,
+                    direction: Vec3::ZERO,
+                },
+                square(position, 40.0),
+            ))
+            .id()
+    }
+
+    pub fn spawn_xp(&mut self, position: Vec2, amount: i32) -> Entity {
+        self.world_mut()
+            .spawn((
+                GameEntity,
+                Collectible,
+                XP { amount },
+                Transform::from_translation(position.extend(0.0)),
+                square(position, 20.0),
+            ))
+            .id()
+    }
+
+    pub fn spawn_pickup(&mut self, position: Vec2, pickup: Pickup) -> Entity {
+        self.world_mut()
+            .spawn((
+                GameEntity,
+                Collectible,
+                pickup,
+                Transform::from_translation(position.extend(0.0)),
+                square(position, 24.0),
+            ))
+            .id()
+    }
+
+    /// Oyuncuya tanım dosyasından bir silah ver. Kritikler tam hasar kontrollerini
+    /// bozmasın diye kapalı; kritik testleri `DamageProfile`'ı kendisi açar.
+    pub fn spawn_weapon(&mut self, player: Entity, id: &str) -> Entity {
+        let weapon_id = WeaponId::new(id);
+        let weapon = self
+            .world_mut()
+            .run_system_once(move |mut commands: Commands, mut spawner: WeaponSpawner| {
+                spawner.spawn(&mut commands, player, Vec3::ZERO, &weapon_id)
+            })
+            .expect("weapon spawn system failed")
+            .unwrap_or_else(|| panic!("no definition for {id}"));
+        self.world_mut().flush();
+        self.world_mut().get_mut::<DamageProfile>(weapon).expect("weapon without damage profile").crit_chance = 0.0;
+        weapon
+    }
+
+    /// `M` mesajlarını testin okuyabileceği `Recorded<M>` kaynağına topla
+    pub fn record<M: Message + Clone>(&mut self) {
+        self.app
+            .init_resource::<Recorded<M>>()
+            .add_systems(Last, record_messages::<M>);
+    }
+
+    pub fn recorded<M: Message + Clone>(&self) -> &[M] {
+        &self.world().resource::<Recorded<M>>().0
+    }
+
+    pub fn count<F: bevy::ecs::query::QueryFilter>(&mut self) -> usize {
+        self.world_mut().query_filtered::<(), F>().iter(self.world()).count()
+    }
+}
+
+/// Test boyunca yazılan mesajlar
+#[derive(Resource)]
+pub struct Recorded<M>(pub Vec<M>);
+
+impl<M> Default for Recorded<M> {
+    fn default() -> Self {
+        Self(Vec::new())
+    }
+}
+
+fn record_messages<M: Message + Clone>(mut reader: MessageReader<M>, mut recorded: ResMut<Recorded<M>>) {
+    recorded.0.extend(reader.read().cloned());
+}
+
+/// `position` merkezli, `size` kenarlı kare
+pub fn square(position: Vec2, size: f32) -> AABB {
+    let mut aabb = AABB { min_x: 0.0, min_y: 0.0, max_x: 0.0, max_y: 0.0, width: size, height: size };
+    aabb.change_point(position.extend(0.0));
+    aabb
+}
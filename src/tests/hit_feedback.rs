@@ -0,0 +1,160 @@
+use bevy::prelude::*;
+use crate::plugins::enemy::EnemySprit;
+use crate::plugins::enemy_kind::{Charger, ChargerState};
+use crate::plugins::hit_feedback::{DamageNumber, DamageNumberPool, DAMAGE_NUMBER_LIFETIME, HIT_FLASH_COLOR, HIT_FLASH_SECS, MAX_DAMAGE_NUMBERS};
+use crate::plugins::settings::Settings;
+use crate::plugins::player::{Player, PlayerHealedEvent};
+use crate::plugins::status_effect::{StatusApplication, StatusEffects, StatusKind};
+use crate::plugins::weapon_definition::WeaponId;
+use crate::plugins::weapons::WeaponHitEvent;
+use crate::tests::harness::TestApp;
+
+fn hit(target: Entity, damage: i32) -> WeaponHitEvent {
+    WeaponHitEvent {
+        weapon: WeaponId::new("flame"),
+        target,
+        position: Vec3::new(300.0, 0.0, 0.0),
+        damage,
+        crit: false,
+        over_time: false,
+    }
+}
+
+fn visible_numbers(test: &mut TestApp) -> Vec<(String, Color)> {
+    let world = test.world_mut();
+    world
+        .query_filtered::<(&Text2d, &TextColor, &Visibility), With<DamageNumber>>()
+        .iter(world)
+        .filter(|(_, _, visibility)| **visibility != Visibility::Hidden)
+        .map(|(text, color, _)| (text.0.clone(), color.0))
+        .collect()
+}
+
+#[test]
+fn damage_numbers_are_pooled_and_capped() {
+    let mut test = TestApp::new();
+    test.spawn_player(Vec2::ZERO);
+    let enemy = test.spawn_enemy(Vec2::new(300.0, 0.0), 1000);
+
+    // Birkaç frame'e yayılmış yoğun isabet: sınır aşılmaz, en eskiler yeniden kullanılır
+    for _ in 0..4 {
+        for _ in 0..MAX_DAMAGE_NUMBERS {
+            test.world_mut().write_message(hit(enemy, 5));
+        }
+        test.tick();
+    }
+    assert_eq!(test.count::<With<DamageNumber>>(), MAX_DAMAGE_NUMBERS);
+    assert_eq!(visible_numbers(&mut test).len(), MAX_DAMAGE_NUMBERS);
+
+    test.advance(DAMAGE_NUMBER_LIFETIME + 0.1);
+    assert!(visible_numbers(&mut test).is_empty());
+    assert_eq!(test.world().resource::<DamageNumberPool>().free.len(), MAX_DAMAGE_NUMBERS);
+
+    // Yeni isabetler gizli sayıları yeniden kullanır
+    test.world_mut().write_message(hit(enemy, 7));
+    test.tick();
+    assert_eq!(test.count::<With<DamageNumber>>(), MAX_DAMAGE_NUMBERS);
+    assert_eq!(visible_numbers(&mut test).len(), 1);
+}
+
+#[test]
+fn crits_damage_over_time_and_heals_have_distinct_colors() {
+    let mut test = TestApp::new();
+    test.spawn_player(Vec2::ZERO);
+    let enemy = test.spawn_enemy(Vec2::new(300.0, 0.0), 1000);
+
+    test.world_mut().write_message(hit(enemy, 10));
+    test.world_mut().write_message(WeaponHitEvent { crit: true, ..hit(enemy, 20) });
+    test.world_mut().write_message(WeaponHitEvent { over_time: true, ..hit(enemy, 3) });
+    test.world_mut().write_message(PlayerHealedEvent { amount: 4 });
+    test.tick();
+
+    let numbers = visible_numbers(&mut test);
+    let color = |label: &str| numbers.iter().find(|(text, _)| text == label).map(|(_, color)| color.to_srgba()).unwrap();
+    let colors = ["10", "20", "3", "+4"].map(|label| color(label).with_alpha(1.0));
+    for (i, a) in colors.iter().enumerate() {
+        for b in &colors[i + 1..] {
+            assert_ne!(a, b);
+        }
+    }
+}
+
+#[test]
+fn damage_numbers_can_be_turned_off() {
+    let mut test = TestApp::new();
+    let player = test.spawn_player(Vec2::ZERO);
+    test.world_mut().resource_mut::<Settings>().damage_numbers = false;
+    test.world_mut().get_mut::<Player>(player).unwrap().health = 50;
+    let enemy = test.spawn_enemy(Vec2::new(300.0, 0.0), 1000);
+
+    test.world_mut().write_message(hit(enemy, 10));
+    test.world_mut().write_message(PlayerHealedEvent { amount: 4 });
+    test.tick();
+
+    assert_eq!(test.count::<With<DamageNumber>>(), 0);
+}
+
+#[test]
+fn hit_flash_restores_the_status_tint() {
+    let mut test = TestApp::new();
+    test.spawn_player(Vec2::ZERO);
+    let enemy = test.spawn_enemy(Vec2::new(300.0, 0.0), 1000);
+    let sprite = test
+        .world_mut()
+        .spawn((Sprite::default(), EnemySprit { index: 0, frames: 1, directional: false }, ChildOf(enemy)))
+        .id();
+    let slow = StatusApplication { kind: StatusKind::Slow, duration: 5.0, magnitude: 0.5, chance: 1.0 };
+    test.world_mut().get_mut::<StatusEffects>(enemy).unwrap().apply(&slow, None);
+    test.tick();
+    let color = |test: &TestApp| test.world().get::<Sprite>(sprite).unwrap().color;
+    assert_eq!(color(&test), StatusKind::Slow.tint());
+
+    // Süreli hasar parlatmaz
+    test.world_mut().write_message(WeaponHitEvent { over_time: true, ..hit(enemy, 3) });
+    test.tick();
+    assert_eq!(color(&test), StatusKind::Slow.tint());
+
+    test.world_mut().write_message(hit(enemy, 10));
+    test.tick();
+    assert_eq!(color(&test), HIT_FLASH_COLOR);
+
+    test.advance(HIT_FLASH_SECS + 0.05);
+    assert_eq!(color(&test), StatusKind::Slow.tint());
+}
+
+#[test]
+fn telegraphing_chargers_stay_tinted_after_a_hit() {
+    let mut test = TestApp::new();
+    test.spawn_player(Vec2::ZERO);
+    let charger = test.spawn_enemy(Vec2::new(300.0, 0.0), 1000);
+    test.world_mut().entity_mut(charger).insert(Charger {
+        state: ChargerState::Approaching,
+        timer: Timer::from_seconds(0.0, TimerMode::Once),
+        dash_direction: Vec3::ZERO,
+        trigger_range: 350.0,
+        windup: 5.0,
+        dash_speed: 0.0,
+        dash_duration: 0.2,
+        cooldown: 10.0,
+    });
+    let sprite = test
+        .world_mut()
+        .spawn((Sprite::default(), EnemySprit { index: 0, frames: 1, directional: false }, ChildOf(charger)))
+        .id();
+    test.tick();
+    assert_eq!(test.world().get::<Charger>(charger).unwrap().state, ChargerState::Telegraphing);
+    let color = |test: &TestApp| test.world().get::<Sprite>(sprite).unwrap().color;
+    let telegraph = color(&test);
+    assert_ne!(telegraph, Color::WHITE);
+
+    // Ne yeni bir durum etkisi ne de isabet parlaması uyarıyı siler
+    let slow = StatusApplication { kind: StatusKind::Slow, duration: 5.0, magnitude: 0.5, chance: 1.0 };
+    test.world_mut().get_mut::<StatusEffects>(charger).unwrap().apply(&slow, None);
+    test.world_mut().write_message(hit(charger, 10));
+    test.tick();
+    assert_eq!(color(&test), HIT_FLASH_COLOR);
+
+    test.advance(HIT_FLASH_SECS + 0.05);
+    assert_eq!(test.world().get::<Charger>(charger).unwrap().state, ChargerState::Telegraphing);
+    assert_eq!(color(&test), telegraph);
+}
@@ -0,0 +1,98 @@
+use bevy::prelude::*;
+use crate::plugins::hud::{format_survival_time, HealthBarFill, Hud, HudSlot, HudText, XpFill, HEALTH_BAR_WIDTH};
+use crate::plugins::localization::Language;
+use crate::plugins::passive_item::{PassiveInventory, PassiveKind};
+use crate::plugins::player::Player;
+use crate::plugins::settings::Settings;
+use crate::plugins::spawn_director::SpawnDirector;
+use crate::plugins::weapon_upgrade::WeaponLevel;
+use crate::tests::harness::TestApp;
+
+fn hud_text(test: &mut TestApp, kind: HudText) -> String {
+    let world = test.world_mut();
+    world
+        .query::<(&Text, &HudText)>()
+        .iter(world)
+        .find(|(_, text_kind)| **text_kind == kind)
+        .map(|(text, _)| text.0.clone())
+        .expect("missing HUD text")
+}
+
+fn slots(test: &mut TestApp) -> Vec<HudSlot> {
+    let world = test.world_mut();
+    world.query::<&HudSlot>().iter(world).cloned().collect()
+}
+
+#[test]
+fn hud_shows_level_xp_kills_and_gold() {
+    let mut test = TestApp::new();
+    let player = test.spawn_player(Vec2::ZERO);
+    test.tick();
+    assert_eq!(test.count::<With<Hud>>(), 1);
+
+    {
+        let mut stats = test.world_mut().get_mut::<Player>(player).unwrap();
+        stats.level = 3;
+        stats.xp = 25.0;
+        stats.xp_to_next_level = 100.0;
+        stats.score = 42;
+        stats.gold = 7;
+    }
+    test.tick();
+
+    assert_eq!(hud_text(&mut test, HudText::Level), "LV 3");
+    assert_eq!(hud_text(&mut test, HudText::Kills), "Kills 42");
+    assert_eq!(hud_text(&mut test, HudText::Gold), "Gold 7");
+    let world = test.world_mut();
+    let width = world.query_filtered::<&Node, With<XpFill>>().single(world).unwrap().width;
+    assert_eq!(width, Val::Percent(25.0));
+
+    // Dil değişince oyuncu değişmese de yenilenir
+    test.world_mut().resource_mut::<Settings>().language = Language::Turkish;
+    test.tick();
+    assert_eq!(hud_text(&mut test, HudText::Level), "SV 3");
+    assert_eq!(hud_text(&mut test, HudText::Kills), "Öldürme 42");
+    assert_eq!(hud_text(&mut test, HudText::Gold), "Altın 7");
+}
+
+#[test]
+fn player_health_bar_follows_health() {
+    let mut test = TestApp::new();
+    let player = test.spawn_player(Vec2::ZERO);
+    test.tick();
+    test.world_mut().get_mut::<Player>(player).unwrap().health = 40;
+    test.tick();
+
+    let world = test.world_mut();
+    let (sprite, parent) = world.query_filtered::<(&Sprite, &ChildOf), With<HealthBarFill>>().single(world).unwrap();
+    assert_eq!(parent.parent(), player);
+    assert_eq!(sprite.custom_size.unwrap().x, HEALTH_BAR_WIDTH * 0.4);
+}
+
+#[test]
+fn survival_timer_uses_run_time() {
+    assert_eq!(format_survival_time(0.0), "00:00");
+    assert_eq!(format_survival_time(125.9), "02:05");
+
+    let mut test = TestApp::new();
+    test.spawn_player(Vec2::ZERO);
+    test.world_mut().resource_mut::<SpawnDirector>().elapsed = 61.0;
+    test.tick();
+    assert_eq!(hud_text(&mut test, HudText::Timer), "01:01");
+}
+
+#[test]
+fn slots_show_weapons_and_passives_with_levels() {
+    let mut test = TestApp::new();
+    let player = test.spawn_player(Vec2::ZERO);
+    let laser = test.spawn_weapon(player, "laser");
+    test.tick();
+    assert_eq!(slots(&mut test), [HudSlot { label: "Las".into(), level: 1 }]);
+
+    test.world_mut().get_mut::<WeaponLevel>(laser).unwrap().level = 4;
+    test.world_mut().get_mut::<PassiveInventory>(player).unwrap().items.insert(PassiveKind::Might, 2);
+    test.tick();
+    let mut shown = slots(&mut test);
+    shown.sort_by(|a, b| a.label.cmp(&b.label));
+    assert_eq!(shown, [HudSlot { label: "Las".into(), level: 4 }, HudSlot { label: "Mig".into(), level: 2 }]);
+}
@@ -0,0 +1,155 @@
+use bevy::prelude::*;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use crate::plugins::boss::BossKind;
+use crate::plugins::chest_reward::ChestRewards;
+use crate::plugins::enemy::{EnemyDiedEvent, XP};
+use crate::plugins::enemy_kind::EnemyKind;
+use crate::plugins::game_state::GameState;
+use crate::plugins::localization::Language;
+use crate::plugins::loot::{ChestOpenedEvent, ChestRewardWeight, LootDrop, LootRule, LootTable, Pickup, BOMB_RADIUS};
+use crate::plugins::passive_item::{PassiveInventory, PassiveKind};
+use crate::plugins::player::{Player, XPMagnetite};
+use crate::plugins::settings::Settings;
+use crate::plugins::status_effect::StatusEffects;
+use crate::plugins::weapon_upgrade::WeaponLevel;
+use crate::tests::harness::TestApp;
+
+fn player_position(test: &TestApp, player: Entity) -> Vec2 {
+    test.world().get::<Transform>(player).unwrap().translation.truncate()
+}
+
+#[test]
+fn chicken_heals_and_gold_is_counted() {
+    let mut test = TestApp::new();
+    let player = test.spawn_player(Vec2::ZERO);
+    test.world_mut().get_mut::<Player>(player).unwrap().health = 50;
+    let chicken = test.spawn_pickup(Vec2::ZERO, Pickup::Chicken(30));
+    test.spawn_pickup(Vec2::new(5.0, 0.0), Pickup::Gold(5));
+    test.tick();
+
+    let stats = test.world().get::<Player>(player).unwrap();
+    assert_eq!((stats.health, stats.gold), (80, 5));
+    assert!(test.world().get_entity(chicken).is_err());
+}
+
+#[test]
+fn magnet_pulls_every_xp_orb() {
+    let mut test = TestApp::new();
+    test.spawn_player(Vec2::ZERO);
+    let far = test.spawn_xp(Vec2::new(3000.0, 0.0), 1);
+    test.spawn_pickup(Vec2::ZERO, Pickup::Magnet);
+    test.tick();
+
+    assert!(test.world().get::<XPMagnetite>(far).is_some());
+    assert_eq!(test.count::<With<XP>>(), test.count::<With<XPMagnetite>>());
+}
+
+#[test]
+fn bomb_clears_nearby_enemies_and_clock_freezes_all() {
+    let mut test = TestApp::new();
+    test.spawn_player(Vec2::ZERO);
+    let near = test.spawn_enemy(Vec2::new(300.0, 0.0), 100);
+    let far = test.spawn_enemy(Vec2::new(BOMB_RADIUS + 500.0, 0.0), 100);
+    test.spawn_pickup(Vec2::ZERO, Pickup::Bomb(500.0));
+    test.advance(0.1);
+
+    assert!(test.world().get_entity(near).is_err());
+    assert!(test.world().get_entity(far).is_ok());
+
+    test.spawn_pickup(Vec2::ZERO, Pickup::Clock(5.0));
+    test.tick();
+    assert!(test.world().get::<StatusEffects>(far).unwrap().is_frozen());
+}
+
+#[test]
+fn bosses_always_drop_a_chest() {
+    let mut test = TestApp::new();
+    test.world_mut().write_message(EnemyDiedEvent { kind: EnemyKind::Boss(BossKind::QueenBee), position: Vec3::ZERO, killer: None });
+    test.tick();
+
+    let chests = test.world_mut().query::<&Pickup>().iter(test.world()).filter(|pickup| **pickup == Pickup::Chest).count();
+    assert_eq!(chests, 1);
+}
+
+#[test]
+fn chest_grants_its_rewards_before_play_resumes() {
+    let mut test = TestApp::loaded();
+    test.record::<ChestOpenedEvent>();
+    let player = test.start_run();
+    let position = player_position(&test, player);
+    test.spawn_pickup(position, Pickup::Chest);
+
+    test.tick();
+    test.tick();
+    assert_eq!(test.state(), GameState::ChestReward);
+    let rewards = test.recorded::<ChestOpenedEvent>()[0].rewards;
+    let granted = test.advance_until(1.0, |world| world.resource::<ChestRewards>().granted.len() == rewards as usize);
+    assert!(granted, "{rewards} reward(s) were not granted");
+
+    // İlk Confirm animasyonu atlar, ikincisi oyuna döner
+    test.press(KeyCode::Enter);
+    assert_eq!(test.state(), GameState::ChestReward);
+    test.press(KeyCode::Enter);
+    assert_eq!(test.state(), GameState::Playing);
+    assert!(test.world().resource::<ChestRewards>().granted.is_empty());
+}
+
+#[test]
+fn chest_opens_after_a_level_up_in_the_same_tick() {
+    let mut test = TestApp::loaded();
+    let player = test.start_run();
+    let position = player_position(&test, player);
+    test.spawn_xp(position, 120);
+    test.spawn_pickup(position, Pickup::Chest);
+
+    test.tick();
+    test.tick();
+    assert_eq!(test.state(), GameState::UpgradeSelection);
+    assert_eq!(test.world().get::<Player>(player).unwrap().level, 2);
+
+    // Seçimden sonra bekleyen sandık açılır
+    test.press(KeyCode::Enter);
+    test.tick();
+    assert_eq!(test.state(), GameState::ChestReward);
+    let granted = test.advance_until(1.0, |world| !world.resource::<ChestRewards>().granted.is_empty());
+    assert!(granted);
+}
+
+#[test]
+fn chest_labels_follow_the_language() {
+    let mut test = TestApp::loaded();
+    test.world_mut().resource_mut::<Settings>().language = Language::Turkish;
+    let player = test.start_run();
+    // Maks seviyedeki lazer + Might: ilk ödül evrim
+    let world = test.world_mut();
+    for mut level in world.query::<&mut WeaponLevel>().iter_mut(world) {
+        level.level = level.max_level;
+    }
+    test.world_mut().get_mut::<PassiveInventory>(player).unwrap().items.insert(PassiveKind::Might, 1);
+    let position = player_position(&test, player);
+    test.spawn_pickup(position, Pickup::Chest);
+
+    let granted = test.advance_until(1.0, |world| !world.resource::<ChestRewards>().granted.is_empty());
+    assert!(granted);
+    assert_eq!(test.world().resource::<ChestRewards>().granted[0].label, "Evrim: Prism Beam");
+}
+
+#[test]
+fn luck_raises_drop_chance_and_chest_size() {
+    let table = LootTable {
+        rules: vec![LootRule { enemies: vec![], drop_chance: 0.1, drops: vec![LootDrop { item: Pickup::Gold(1), weight: 1 }] }],
+        chest_rewards: vec![ChestRewardWeight { count: 1, weight: 70 }, ChestRewardWeight { count: 3, weight: 30 }],
+    };
+    let drops = |luck: f32| {
+        let mut rng = StdRng::seed_from_u64(3);
+        (0..2000).filter(|_| table.roll_drop(EnemyKind::Swarmer, luck, &mut rng).is_some()).count()
+    };
+    let big_chests = |luck: f32| {
+        let mut rng = StdRng::seed_from_u64(3);
+        (0..2000).filter(|_| table.roll_chest_rewards(luck, &mut rng) > 1).count()
+    };
+
+    assert!(drops(1.0) > drops(0.0) * 3 / 2, "{} vs {}", drops(1.0), drops(0.0));
+    assert!(big_chests(1.0) > big_chests(0.0) * 3 / 2, "{} vs {}", big_chests(1.0), big_chests(0.0));
+}
@@ -0,0 +1,139 @@
+use bevy::prelude::*;
+use crate::plugins::enemy::Enemy;
+use crate::plugins::player::{Player, PlayerMotion};
+use crate::tests::harness::{square, TestApp};
+
+fn position(test: &TestApp, player: Entity) -> Vec2 {
+    test.world().get::<Transform>(player).unwrap().translation.truncate()
+}
+
+fn motion(test: &TestApp, player: Entity) -> &PlayerMotion {
+    test.world().get::<PlayerMotion>(player).unwrap()
+}
+
+/// Tuşları bir saniye basılı tutunca alınan yol
+fn distance_walked(keys: &[KeyCode]) -> Vec2 {
+    let mut test = TestApp::loaded();
+    let player = test.start_run();
+    let start = position(&test, player);
+    for key in keys {
+        test.hold(*key);
+    }
+    test.advance(1.0);
+    position(&test, player) - start
+}
+
+#[test]
+fn diagonal_movement_is_not_faster() {
+    let straight = distance_walked(&[KeyCode::KeyD]);
+    let diagonal = distance_walked(&[KeyCode::KeyD, KeyCode::KeyW]);
+    assert!(straight.x > 150.0);
+    assert!((diagonal.length() - straight.length()).abs() < 2.0, "{} vs {}", diagonal.length(), straight.length());
+    assert!((diagonal.x - diagonal.y).abs() < 1.0);
+}
+
+#[test]
+fn analog_stick_scales_the_speed() {
+    let full = distance_walked(&[KeyCode::KeyD]);
+
+    let mut test = TestApp::loaded();
+    let player = test.start_run();
+    let start = position(&test, player);
+    let gamepad = test.world_mut().spawn(Gamepad::default()).id();
+    // Ölü bölgeden sonra yarı yol
+    test.world_mut().get_mut::<Gamepad>(gamepad).unwrap().analog_mut().set(GamepadAxis::LeftStickX, 0.6);
+    test.advance(1.0);
+
+    let half = position(&test, player) - start;
+    assert!((half.x / full.x - 0.5).abs() < 0.02, "{} vs {}", half.x, full.x);
+}
+
+#[test]
+fn player_accelerates_and_slides_to_a_stop() {
+    let mut test = TestApp::loaded();
+    let player = test.start_run();
+    let speed = test.world().get::<Player>(player).unwrap().movement;
+
+    test.hold(KeyCode::KeyA);
+    test.tick();
+    let first = motion(&test, player).velocity.x;
+    assert!(first < 0.0 && first > -speed);
+    test.advance(0.5);
+    assert!((motion(&test, player).velocity.x + speed).abs() < 1e-3);
+
+    test.release(KeyCode::KeyA);
+    test.tick();
+    assert!(motion(&test, player).velocity.x < 0.0);
+    test.advance(0.5);
+    assert_eq!(motion(&test, player).velocity, Vec2::ZERO);
+}
+
+#[test]
+fn animation_row_follows_the_velocity() {
+    let mut test = TestApp::loaded();
+    let player = test.start_run();
+    let row = |test: &TestApp| test.world().get::<Sprite>(player).unwrap().texture_atlas.as_ref().unwrap().index / 9;
+
+    test.hold(KeyCode::KeyA);
+    test.advance(0.5);
+    assert_eq!(row(&test), 1);
+
+    // Karşılıklı tuşlar hareketi iptal eder; yön son harekette kalır
+    test.hold(KeyCode::KeyD);
+    test.advance(0.5);
+    assert_eq!(row(&test), 1);
+
+    test.release(KeyCode::KeyA);
+    test.advance(0.5);
+    assert_eq!(row(&test), 3);
+
+    test.release(KeyCode::KeyD);
+    test.hold(KeyCode::KeyS);
+    test.advance(0.5);
+    assert_eq!(row(&test), 2);
+}
+
+#[test]
+fn dash_moves_further_and_ignores_contact_damage() {
+    let mut test = TestApp::loaded();
+    let player = test.start_run();
+    let start = position(&test, player);
+    // Atılma yolunu da kaplayan, vuran ama ölmeyen bir düşman
+    let enemy = test.spawn_enemy(start, i32::MAX);
+    test.world_mut().entity_mut(enemy).insert(square(start, 1000.0));
+    test.world_mut().get_mut::<Enemy>(enemy).unwrap().damage = 5;
+    let health = test.world().get::<Player>(player).unwrap().health;
+
+    test.press(KeyCode::ShiftLeft);
+    assert!(motion(&test, player).is_invulnerable());
+    assert!(!motion(&test, player).dash_ready());
+    test.advance(0.2);
+    // Başlangıç yönü yukarı
+    let dashed = position(&test, player) - start;
+    assert!(dashed.y > 80.0 && dashed.x.abs() < 1e-3, "{dashed}");
+    assert_eq!(test.world().get::<Player>(player).unwrap().health, health);
+
+    test.advance(0.5);
+    assert!(test.world().get::<Player>(player).unwrap().health < health);
+}
+
+#[test]
+fn dash_has_a_cooldown() {
+    let mut test = TestApp::loaded();
+    let player = test.start_run();
+    let start = position(&test, player);
+
+    test.press(KeyCode::ShiftLeft);
+    test.advance(0.5);
+    let after_first = position(&test, player);
+    test.press(KeyCode::ShiftLeft);
+    test.advance(0.5);
+    assert_eq!(position(&test, player), after_first);
+    assert!(after_first.y > start.y);
+
+    test.advance(1.0);
+    assert!(motion(&test, player).dash_ready());
+    test.press(KeyCode::ShiftLeft);
+    test.advance(0.5);
+    assert!(position(&test, player).y > after_first.y);
+}
@@ -0,0 +1,117 @@
+use std::collections::HashSet;
+use bevy::prelude::*;
+use crate::plugins::damage::DamageMeter;
+use crate::plugins::game_state::GameState;
+use crate::plugins::pause_menu::{PauseButton, PauseStats};
+use crate::plugins::player::Player;
+use crate::plugins::replay::ReplaySession;
+use crate::plugins::score::GameScore;
+use crate::plugins::spawn_director::SpawnDirector;
+use crate::plugins::weapon_definition::WeaponId;
+use crate::plugins::weapons::GameEntity;
+use crate::tests::harness::TestApp;
+
+fn game_entities(test: &mut TestApp) -> HashSet<Entity> {
+    let world = test.world_mut();
+    world.query_filtered::<Entity, With<GameEntity>>().iter(world).collect()
+}
+
+#[test]
+fn escape_freezes_gameplay_until_resumed() {
+    let mut test = TestApp::loaded();
+    let player = test.start_run();
+    test.advance(1.0);
+
+    test.press(KeyCode::Escape);
+    assert_eq!(test.state(), GameState::Paused);
+    assert!(test.world().resource::<Time<Virtual>>().is_paused());
+    assert_eq!(test.count::<With<PauseButton>>(), 5);
+
+    let tick = test.world().resource::<ReplaySession>().tick;
+    let elapsed = test.world().resource::<SpawnDirector>().elapsed;
+    let position = test.world().get::<Transform>(player).unwrap().translation;
+    test.advance(2.0);
+    assert_eq!(test.world().resource::<ReplaySession>().tick, tick);
+    assert_eq!(test.world().resource::<SpawnDirector>().elapsed, elapsed);
+    assert_eq!(test.world().get::<Transform>(player).unwrap().translation, position);
+
+    test.press(KeyCode::Escape);
+    assert_eq!(test.state(), GameState::Playing);
+    assert!(!test.world().resource::<Time<Virtual>>().is_paused());
+    assert_eq!(test.count::<With<PauseButton>>(), 0);
+    test.advance(0.5);
+    assert!(test.world().resource::<ReplaySession>().tick > tick);
+}
+
+#[test]
+fn pause_menu_shows_build_and_dps() {
+    let mut test = TestApp::loaded();
+    test.start_run();
+    test.advance(1.0);
+    test.world_mut().resource_mut::<DamageMeter>().per_weapon.insert(WeaponId::new("laser"), 500);
+
+    test.press(KeyCode::Escape);
+    let elapsed = test.world().resource::<SpawnDirector>().elapsed;
+    let world = test.world_mut();
+    let stats = world.query_filtered::<&Text, With<PauseStats>>().single(world).unwrap().0.clone();
+    assert!(stats.contains("Laser"), "{}", stats);
+    assert!(stats.contains("Lv 1"), "{}", stats);
+    assert!(stats.contains(&format!("Total DPS: {:.1}", 500.0 / elapsed)), "{}", stats);
+}
+
+#[test]
+fn resume_button_continues_the_run() {
+    let mut test = TestApp::loaded();
+    let player = test.start_run();
+    test.press(KeyCode::Escape);
+
+    test.click(PauseButton::Resume);
+    test.tick();
+    assert_eq!(test.state(), GameState::Playing);
+    assert_eq!(test.player(), player);
+    assert!(!test.world().resource::<Time<Virtual>>().is_paused());
+}
+
+#[test]
+fn restart_from_pause_starts_a_fresh_run() {
+    let mut test = TestApp::loaded();
+    let first_player = test.start_run();
+    test.advance(1.0);
+    test.world_mut().get_mut::<Player>(first_player).unwrap().score = 5;
+    let old_entities = game_entities(&mut test);
+
+    test.press(KeyCode::Escape);
+    test.click(PauseButton::Restart);
+    test.wait_for_state(GameState::Playing);
+
+    assert!(game_entities(&mut test).is_disjoint(&old_entities));
+    let player = test.player();
+    assert_ne!(player, first_player);
+    assert_eq!(test.world().get::<Player>(player).unwrap().score, 0);
+    assert!(test.world().resource::<DamageMeter>().per_weapon.is_empty());
+    assert!(test.world().resource::<ReplaySession>().tick <= 2);
+    assert!(!test.world().resource::<Time<Virtual>>().is_paused());
+}
+
+#[test]
+fn quit_to_menu_cleans_up_and_a_new_run_can_start() {
+    let mut test = TestApp::loaded();
+    let first_player = test.start_run();
+    test.advance(1.0);
+    test.world_mut().resource_mut::<GameScore>().score = 3;
+
+    test.press(KeyCode::Escape);
+    test.click(PauseButton::Quit);
+    test.tick();
+    assert_eq!(test.state(), GameState::MainMenu);
+    assert_eq!(test.count::<With<GameEntity>>(), 0);
+    assert_eq!(test.count::<With<PauseButton>>(), 0);
+    assert_eq!(test.world().resource::<GameScore>().score, 0);
+    assert!(!test.world().resource::<Time<Virtual>>().is_paused());
+
+    // Önceki koşunun atlasları ve spawn director'ı sıfırlanmazsa yükleme bitmez
+    let player = test.start_run();
+    assert_ne!(player, first_player);
+    let timestep = test.world().resource::<Time<Fixed>>().timestep().as_secs_f32();
+    assert!(test.world().resource::<SpawnDirector>().elapsed <= timestep * 2.0);
+}
@@ -0,0 +1,98 @@
+use bevy::prelude::*;
+use crate::plugins::enemy::Enemy;
+use crate::plugins::game_state::GameState;
+use crate::plugins::passive_item::{PassiveInventory, PassiveKind};
+use crate::plugins::player::{Player, PlayerMotion};
+use crate::plugins::player_damage::{PlayerDamageEvent, PlayerDeath, PlayerHurtEvent, DEATH_SEQUENCE_SECS};
+use crate::tests::harness::{square, TestApp};
+
+fn health(test: &TestApp, player: Entity) -> u32 {
+    test.world().get::<Player>(player).unwrap().health
+}
+
+fn hit(test: &mut TestApp, amount: u32, source: Option<Vec2>) {
+    test.world_mut().write_message(PlayerDamageEvent { amount, source, over_time: false });
+    test.tick();
+}
+
+#[test]
+fn hits_give_invulnerability_and_knock_the_player_back() {
+    let mut test = TestApp::loaded();
+    let player = test.start_run();
+    let position = test.world().get::<Transform>(player).unwrap().translation.truncate();
+    let start = health(&test, player);
+
+    // Soldan gelen isabet sağa iter
+    hit(&mut test, 10, Some(position - Vec2::new(100.0, 0.0)));
+    assert_eq!(health(&test, player), start - 10);
+    let motion = test.world().get::<PlayerMotion>(player).unwrap();
+    assert!(motion.is_invulnerable());
+    assert!(motion.velocity.x > 0.0 && motion.velocity.y.abs() < 1e-3);
+
+    hit(&mut test, 10, None);
+    assert_eq!(health(&test, player), start - 10);
+
+    test.advance(0.7);
+    hit(&mut test, 10, None);
+    assert_eq!(health(&test, player), start - 20);
+}
+
+#[test]
+fn armor_reduces_hits_but_not_damage_over_time() {
+    let mut test = TestApp::new();
+    test.record::<PlayerHurtEvent>();
+    let player = test.spawn_player(Vec2::ZERO);
+    test.world_mut().get_mut::<PassiveInventory>(player).unwrap().items.insert(PassiveKind::Armor, 4);
+    test.tick();
+
+    hit(&mut test, 10, None);
+    assert_eq!(health(&test, player), 94);
+    // Süreli hasar dokunulmazlığa takılmaz
+    test.world_mut().write_message(PlayerDamageEvent { amount: 10, source: None, over_time: true });
+    test.tick();
+    assert_eq!(health(&test, player), 84);
+
+    let hurts: Vec<_> = test.recorded::<PlayerHurtEvent>().iter().map(|hurt| (hurt.amount, hurt.over_time)).collect();
+    assert_eq!(hurts, vec![(6, false), (10, true)]);
+}
+
+#[test]
+fn contact_damage_waits_for_invulnerability() {
+    let mut test = TestApp::loaded();
+    let player = test.start_run();
+    let position = test.world().get::<Transform>(player).unwrap().translation.truncate();
+    // Geri itilse de temas sürsün diye geniş
+    let enemy = test.spawn_enemy(position, i32::MAX);
+    test.world_mut().entity_mut(enemy).insert(square(position, 1000.0));
+    test.world_mut().get_mut::<Enemy>(enemy).unwrap().damage = 5;
+    let start = health(&test, player);
+
+    test.tick();
+    assert_eq!(health(&test, player), start - 5);
+    test.advance(0.4);
+    assert_eq!(health(&test, player), start - 5);
+    test.advance(0.4);
+    assert_eq!(health(&test, player), start - 10);
+}
+
+#[test]
+fn death_plays_out_before_game_over() {
+    let mut test = TestApp::loaded();
+    let player = test.start_run();
+    test.world_mut().get_mut::<Player>(player).unwrap().health = 5;
+
+    hit(&mut test, 50, None);
+    assert_eq!(health(&test, player), 0);
+    assert!(test.world().get::<PlayerDeath>(player).is_some());
+
+    test.advance(DEATH_SEQUENCE_SECS / 2.0);
+    assert_eq!(test.state(), GameState::Playing);
+    let scale = test.world().get::<Transform>(player).unwrap().scale.x;
+    assert!(scale < 0.6, "{scale}");
+    // Ölürken gelen isabetler yok sayılır
+    hit(&mut test, 50, None);
+    assert_eq!(health(&test, player), 0);
+
+    test.advance(DEATH_SEQUENCE_SECS);
+    assert_eq!(test.state(), GameState::GameOver);
+}
@@ -0,0 +1,191 @@
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+use crate::plugins::game_state::GameState;
+use crate::plugins::main_menu::MenuButton;
+use crate::plugins::passive_item::PlayerStats;
+use crate::plugins::player::Player;
+use crate::plugins::player_damage::{PlayerDamageEvent, PlayerDeath};
+use crate::plugins::power_up_shop::{PowerUpShopUI, ShopButton};
+use crate::plugins::profile::{PowerUpKind, Profile, ProfileError, ProfileFile, PROFILE_VERSION};
+use crate::plugins::replay::Replay;
+use crate::plugins::weapon_definition::WeaponId;
+use crate::plugins::weapon_evolution::EvolutionRules;
+use crate::plugins::weapon_upgrade::{RerollButton, UpgradeChoices, WeaponLevel};
+use crate::tests::harness::TestApp;
+
+fn profile(test: &TestApp) -> Profile {
+    test.world().resource::<Profile>().clone()
+}
+
+#[test]
+fn buying_power_ups_costs_more_each_level_and_stops_at_max() {
+    let mut profile = Profile { gold: 100, ..default() };
+    assert!(profile.buy(PowerUpKind::MaxHealth));
+    assert!(profile.buy(PowerUpKind::MaxHealth));
+    assert_eq!(profile.gold, 100 - 20 - 40);
+    assert_eq!(profile.level(PowerUpKind::MaxHealth), 2);
+
+    // Altın yetmezse hiçbir şey değişmez
+    assert!(!profile.buy(PowerUpKind::Revival));
+    assert_eq!(profile.gold, 40);
+
+    profile.gold = 10_000;
+    while profile.buy(PowerUpKind::Revival) {}
+    assert_eq!(profile.level(PowerUpKind::Revival), PowerUpKind::Revival.max_level());
+    assert_eq!(profile.next_cost(PowerUpKind::Revival), None);
+
+    // Kilit açılınca başlangıç silahı seçilebilir
+    let choices = [WeaponId::new("laser"), WeaponId::new("rocket")];
+    profile.cycle_starting_weapon(&choices);
+    assert_eq!(profile.loadout().starting_weapon, None);
+    assert!(profile.buy(PowerUpKind::StartingWeapon));
+    profile.cycle_starting_weapon(&choices);
+    assert_eq!(profile.loadout().starting_weapon, Some(WeaponId::new("laser")));
+    profile.cycle_starting_weapon(&choices);
+    profile.cycle_starting_weapon(&choices);
+    assert_eq!(profile.loadout().starting_weapon, Some(WeaponId::new("laser")));
+}
+
+#[test]
+fn unversioned_profiles_are_migrated_and_newer_ones_are_never_overwritten() {
+    let test = TestApp::loaded();
+    let path = test.profile_path.clone();
+
+    std::fs::write(&path, "(gold: 12, power_ups: (might: 9))").unwrap();
+    let profile = Profile::load(&path).unwrap();
+    assert_eq!(profile.version, PROFILE_VERSION);
+    assert_eq!(profile.gold, 12);
+    // Elle yazılmış aralık dışı seviye kırpılır
+    assert_eq!(profile.level(PowerUpKind::Might), PowerUpKind::Might.max_level());
+
+    // 1. sürüm silahı enum adıyla yazıyordu - seçim düşer, gerisi okunur
+    std::fs::write(&path, "(version: 1, gold: 7, power_ups: (starting_weapon: 1), starting_weapon: Some(Laser))").unwrap();
+    let profile = Profile::load(&path).unwrap();
+    assert_eq!((profile.version, profile.gold, profile.level(PowerUpKind::StartingWeapon)), (PROFILE_VERSION, 7, 1));
+    assert_eq!(profile.starting_weapon, None);
+
+    // Yeni sürümün alanları ve silahları atlanır, bilinenler okunur
+    let newer = "(version: 99, gold: 50, shards: 3, power_ups: (reroll: 2, curse: 1), starting_weapon: Some(Whip))";
+    std::fs::write(&path, newer).unwrap();
+    let profile = Profile::from_file(&ProfileFile { path: Some(path.clone()) });
+    assert_eq!((profile.version, profile.gold, profile.level(PowerUpKind::Reroll)), (99, 50, 2));
+    assert_eq!(profile.starting_weapon, None);
+    assert!(matches!(profile.save(&path), Err(ProfileError::Newer(99))));
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), newer);
+}
+
+#[test]
+fn unreadable_profiles_are_backed_up_instead_of_lost() {
+    let test = TestApp::loaded();
+    let path = test.profile_path.clone();
+    std::fs::write(&path, "not ron at all").unwrap();
+
+    assert_eq!(Profile::from_file(&ProfileFile { path: Some(path.clone()) }), Profile::default());
+    let backup = path.with_extension("ron.bak");
+    assert_eq!(std::fs::read_to_string(&backup).unwrap(), "not ron at all");
+    std::fs::remove_file(backup).unwrap();
+}
+
+#[test]
+fn gold_is_banked_when_the_run_ends() {
+    let mut test = TestApp::loaded();
+    let player = test.start_run();
+    test.world_mut().get_mut::<Player>(player).unwrap().gold = 30;
+
+    test.set_state(GameState::GameOver);
+    assert_eq!(profile(&test).gold, 30);
+    assert_eq!(Profile::load(&test.profile_path).unwrap().gold, 30);
+
+    // Oyuncu silindi - yeniden başlatmak altını iki kez eklemez
+    test.start_run();
+    assert_eq!(profile(&test).gold, 30);
+}
+
+#[test]
+fn power_ups_are_applied_when_the_player_spawns() {
+    let mut test = TestApp::loaded();
+    {
+        let mut profile = test.world_mut().resource_mut::<Profile>();
+        profile.gold = 10_000;
+        for kind in [PowerUpKind::MaxHealth, PowerUpKind::MaxHealth, PowerUpKind::Might, PowerUpKind::Revival, PowerUpKind::Reroll, PowerUpKind::StartingWeapon] {
+            assert!(profile.buy(kind));
+        }
+    }
+    // Evrimler başlangıç silahı olamaz
+    let choices = test.world_mut().run_system_once(|rules: EvolutionRules| rules.starting_choices()).unwrap();
+    assert_eq!(choices, ["flame", "laser", "rocket"].map(WeaponId::new));
+    test.world_mut().resource_mut::<Profile>().starting_weapon = Some(WeaponId::new("rocket"));
+
+    let player = test.start_run();
+    test.tick();
+
+    let stats = test.world().get::<Player>(player).unwrap();
+    assert_eq!((stats.health, stats.max_health), (120, 120));
+    assert_eq!((stats.revivals, stats.rerolls), (1, 1));
+    assert!((test.world().get::<PlayerStats>(player).unwrap().might - 1.05).abs() < 1e-5);
+    let world = test.world_mut();
+    let weapons: Vec<WeaponId> = world.query::<&WeaponLevel>().iter(world).map(|level| level.weapon_type.clone()).collect();
+    assert_eq!(weapons, vec![WeaponId::new("rocket")]);
+}
+
+#[test]
+fn revival_restores_half_health_instead_of_dying() {
+    let mut test = TestApp::new();
+    let player = test.spawn_player(Vec2::ZERO);
+    test.world_mut().get_mut::<Player>(player).unwrap().revivals = 1;
+
+    test.world_mut().write_message(PlayerDamageEvent { amount: 500, source: None, over_time: false });
+    test.tick();
+    let stats = test.world().get::<Player>(player).unwrap();
+    assert_eq!((stats.health, stats.revivals), (50, 0));
+    assert!(test.world().get::<PlayerDeath>(player).is_none());
+
+    // Hak bitti - süreli hasar dokunulmazlığa takılmaz, ikinci ölüm gerçek
+    test.world_mut().write_message(PlayerDamageEvent { amount: 500, source: None, over_time: true });
+    test.tick();
+    assert!(test.world().get::<PlayerDeath>(player).is_some());
+}
+
+#[test]
+fn rerolls_replace_the_choices_and_are_recorded() {
+    let mut test = TestApp::new();
+    let player = test.spawn_player(Vec2::ZERO);
+    test.world_mut().get_mut::<Player>(player).unwrap().rerolls = 1;
+    test.spawn_xp(Vec2::new(5.0, 0.0), 120);
+    test.tick();
+    test.tick();
+    assert_eq!(test.state(), GameState::UpgradeSelection);
+    assert_eq!(test.count::<With<RerollButton>>(), 1);
+
+    test.click(RerollButton);
+    test.tick();
+    assert_eq!(test.world().get::<Player>(player).unwrap().rerolls, 0);
+    assert!(test.world().resource::<UpgradeChoices>().waiting_for_choice);
+    // Hak bitti, düğme kalkar
+    assert_eq!(test.count::<With<RerollButton>>(), 0);
+
+    test.set_state(GameState::GameOver);
+    let replay = Replay::load(&test.record_path).unwrap();
+    assert_eq!(replay.rerolls.len(), 1);
+    assert_eq!(replay.rerolls[0].1, 0);
+}
+
+#[test]
+fn shop_buys_power_ups_and_saves_on_close() {
+    let mut test = TestApp::loaded();
+    test.world_mut().resource_mut::<Profile>().gold = 25;
+    test.click(MenuButton::PowerUps);
+    test.tick();
+    assert_eq!(test.count::<With<PowerUpShopUI>>(), 1);
+
+    test.click(ShopButton::Buy(PowerUpKind::MaxHealth));
+    // 40 altın yok - ikincisi alınmaz
+    test.click(ShopButton::Buy(PowerUpKind::MaxHealth));
+    assert_eq!(profile(&test).level(PowerUpKind::MaxHealth), 1);
+    assert_eq!(profile(&test).gold, 5);
+
+    test.press(KeyCode::Escape);
+    assert_eq!(test.count::<With<PowerUpShopUI>>(), 0);
+    assert_eq!(test.state(), GameState::MainMenu);
+    assert_eq!(Profile::load(&test.profile_path).unwrap().level(PowerUpKind::MaxHealth), 1);
+}
@@ -0,0 +1,59 @@
+use bevy::prelude::*;
+use crate::plugins::enemy::XP;
+use crate::plugins::game_state::GameState;
+use crate::plugins::player::Player;
+use crate::plugins::weapon_upgrade::{LevelUpEvent, UpgradeChoices};
+use crate::tests::harness::TestApp;
+
+#[test]
+fn collecting_xp_below_threshold_does_not_level_up() {
+    let mut test = TestApp::new();
+    test.record::<LevelUpEvent>();
+    let player = test.spawn_player(Vec2::ZERO);
+    test.spawn_xp(Vec2::new(5.0, 0.0), 40);
+
+    test.tick();
+
+    let stats = test.world().get::<Player>(player).unwrap();
+    assert_eq!(stats.xp, 40.0);
+    assert_eq!(stats.level, 1);
+    assert_eq!(test.count::<With<XP>>(), 0);
+    assert!(test.recorded::<LevelUpEvent>().is_empty());
+    assert_eq!(test.state(), GameState::Playing);
+}
+
+#[test]
+fn gain_xp_emits_level_up_event() {
+    let mut test = TestApp::new();
+    test.record::<LevelUpEvent>();
+    let player = test.spawn_player(Vec2::ZERO);
+    test.spawn_xp(Vec2::new(5.0, 0.0), 120);
+
+    test.tick();
+    test.tick();
+
+    let levels: Vec<i32> = test.recorded::<LevelUpEvent>().iter().map(|event| event.level).collect();
+    assert_eq!(levels, vec![2]);
+    let stats = test.world().get::<Player>(player).unwrap();
+    assert_eq!(stats.level, 2);
+    assert_eq!(stats.xp, 20.0);
+    assert_eq!(stats.xp_to_next_level, 150.0);
+    assert_eq!(test.state(), GameState::UpgradeSelection);
+    assert!(test.world().resource::<UpgradeChoices>().waiting_for_choice);
+}
+
+#[test]
+fn gameplay_pauses_during_upgrade_selection() {
+    let mut test = TestApp::new();
+    let player = test.spawn_player(Vec2::ZERO);
+    test.spawn_xp(Vec2::new(5.0, 0.0), 120);
+    test.tick();
+    test.tick();
+    assert_eq!(test.state(), GameState::UpgradeSelection);
+
+    // Seçim yapılana kadar XP toplanmaz
+    test.spawn_xp(Vec2::new(5.0, 0.0), 500);
+    test.advance(1.0);
+    assert_eq!(test.world().get::<Player>(player).unwrap().level, 2);
+    assert_eq!(test.count::<With<XP>>(), 1);
+}
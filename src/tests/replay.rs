@@ -0,0 +1,83 @@
+use bevy::prelude::*;
+use crate::plugins::enemy::Enemy;
+use crate::plugins::game_state::GameState;
+use crate::plugins::player::Player;
+use crate::plugins::profile::{PowerUpKind, Profile};
+use crate::plugins::replay::{Replay, ReplaySession};
+use crate::plugins::weapon_upgrade::RerollButton;
+use crate::tests::harness::TestApp;
+
+/// Kaydedilip oynatılan koşunun uzunluğu (64 Hz'de ~40 sn)
+const RUN_TICKS: u64 = 2560;
+const DIRECTIONS: [KeyCode; 4] = [KeyCode::KeyD, KeyCode::KeyW, KeyCode::KeyA, KeyCode::KeyS];
+
+/// Koşunun karşılaştırılan sonu: oyuncu konumu, can, seviye, öldürme ve düşman sayısı
+fn end_state(test: &mut TestApp) -> (Vec2, u32, i32, u32, usize) {
+    let world = test.world_mut();
+    let (transform, player) = world.query::<(&Transform, &Player)>().single(world).unwrap();
+    let state = (transform.translation.truncate(), player.health, player.level, player.score);
+    let enemies = test.count::<With<Enemy>>();
+    (state.0, state.1, state.2, state.3, enemies)
+}
+
+fn tick(test: &TestApp) -> u64 {
+    test.world().resource::<ReplaySession>().tick
+}
+
+/// Yön tuşlarını sırayla basılı tut, ara sıra atıl; ilk seviye atlamada bir kez yeniden çek, sonra odaktakini seç
+fn play_scripted_run(test: &mut TestApp) {
+    let mut rerolled = false;
+    while tick(test) < RUN_TICKS {
+        match test.state() {
+            GameState::UpgradeSelection if !rerolled && test.count::<With<RerollButton>>() > 0 => {
+                test.click(RerollButton);
+                rerolled = true;
+            }
+            GameState::UpgradeSelection | GameState::ChestReward => test.press(KeyCode::Enter),
+            GameState::Playing => {
+                let now = tick(test);
+                let direction = DIRECTIONS[(now / 128) as usize % DIRECTIONS.len()];
+                for key in DIRECTIONS {
+                    test.release(key);
+                }
+                test.hold(direction);
+                if now % 300 == 150 {
+                    test.press(KeyCode::ShiftLeft);
+                } else {
+                    test.tick();
+                }
+            }
+            state => panic!("scripted run left play: {state:?}"),
+        }
+    }
+}
+
+#[test]
+fn replays_reproduce_the_recorded_run() {
+    let mut test = TestApp::loaded();
+    {
+        let mut profile = test.world_mut().resource_mut::<Profile>();
+        profile.gold = 1_000;
+        assert!(profile.buy(PowerUpKind::Reroll));
+    }
+    test.start_run();
+    play_scripted_run(&mut test);
+    let ticks = tick(&test);
+    let recorded = end_state(&mut test);
+    assert!(recorded.3 > 0, "no kills in the recorded run");
+    test.set_state(GameState::GameOver);
+
+    let replay = Replay::load(&test.record_path).unwrap();
+    assert!(!replay.upgrades.is_empty());
+    assert_eq!(replay.rerolls.len(), 1);
+    assert!(replay.checksums.len() as u64 >= RUN_TICKS / 64);
+
+    // Aynı seed + aynı girdi = aynı koşu
+    let mut playback = TestApp::playing_back(&test.record_path);
+    playback.wait_for_state(GameState::Playing);
+    while tick(&playback) < ticks {
+        playback.tick();
+    }
+    assert_eq!(playback.world().resource::<ReplaySession>().desyncs(), Some(0));
+    assert_eq!(end_state(&mut playback), recorded);
+}
@@ -0,0 +1,66 @@
+use std::collections::HashSet;
+use bevy::prelude::*;
+use crate::plugins::enemy::{Enemy, XP};
+use crate::plugins::game_state::GameState;
+use crate::plugins::passive_item::PassiveInventory;
+use crate::plugins::player::Player;
+use crate::plugins::replay::ReplaySession;
+use crate::plugins::rng::GameRng;
+use crate::plugins::score::GameScore;
+use crate::plugins::spawn_director::SpawnDirector;
+use crate::plugins::weapon_upgrade::{UpgradeChoices, WeaponLevel};
+use crate::plugins::weapons::{GameEntity, Projectile};
+use crate::tests::harness::{TestApp, TEST_SEED};
+
+#[test]
+fn restart_on_key_resets_the_world() {
+    let mut test = TestApp::loaded();
+    let first_player = test.start_run();
+
+    // Düşmanlar gelsin, oyuncu biraz oynamış olsun
+    let spawned = test.advance_until(30.0, |world| world.query::<&Enemy>().iter(world).count() >= 5);
+    assert!(spawned, "spawn director never spawned enemies");
+    {
+        let mut player = test.world_mut().get_mut::<Player>(first_player).unwrap();
+        player.score = 7;
+        player.xp = 42.0;
+    }
+    test.world_mut().resource_mut::<GameScore>().score = 7;
+    let old_entities: HashSet<Entity> = test
+        .world_mut()
+        .query_filtered::<Entity, With<GameEntity>>()
+        .iter(test.world())
+        .collect();
+
+    test.set_state(GameState::GameOver);
+    assert_eq!(test.state(), GameState::GameOver);
+    test.press(KeyCode::KeyR);
+    test.wait_for_state(GameState::Playing);
+
+    let world = test.world_mut();
+    let leftovers = world
+        .query_filtered::<Entity, With<GameEntity>>()
+        .iter(world)
+        .filter(|entity| old_entities.contains(entity))
+        .count();
+    assert_eq!(leftovers, 0, "entities from the previous run survived the restart");
+
+    let player = test.player();
+    assert_ne!(player, first_player);
+    let stats = test.world().get::<Player>(player).unwrap();
+    assert_eq!((stats.health, stats.level, stats.xp, stats.score), (100, 1, 0.0, 0));
+    assert!(test.world().get::<PassiveInventory>(player).unwrap().items.is_empty());
+
+    let world = test.world_mut();
+    let weapons: Vec<i32> = world.query::<&WeaponLevel>().iter(world).map(|level| level.level).collect();
+    assert_eq!(weapons, vec![1]);
+    assert_eq!(test.count::<With<Projectile>>(), 0);
+    assert_eq!(test.count::<With<XP>>(), 0);
+
+    assert_eq!(test.world().resource::<GameScore>().score, 0);
+    let timestep = test.world().resource::<Time<Fixed>>().timestep().as_secs_f32();
+    assert!(test.world().resource::<SpawnDirector>().elapsed <= timestep * 2.0);
+    assert!(test.world().resource::<ReplaySession>().tick <= 2);
+    assert!(!test.world().resource::<UpgradeChoices>().waiting_for_choice);
+    assert_eq!(test.world().resource::<GameRng>().seed(), TEST_SEED);
+}
@@ -0,0 +1,157 @@
+use std::time::Duration;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use rand::Rng;
+use crate::plugins::enemy::{Enemy, XP};
+use crate::plugins::enemy_kind::EnemyKind;
+use crate::plugins::game_state::GameState;
+use crate::plugins::loot::Pickup;
+use crate::plugins::main_menu::MenuButton;
+use crate::plugins::passive_item::{PassiveInventory, PassiveKind};
+use crate::plugins::pause_menu::PauseButton;
+use crate::plugins::player::Player;
+use crate::plugins::profile::Profile;
+use crate::plugins::rng::{GameRng, RngStream};
+use crate::plugins::run_save::RunSave;
+use crate::plugins::score::GameScore;
+use crate::plugins::spawn_director::SpawnDirector;
+use crate::plugins::weapon_definition::WeaponId;
+use crate::plugins::weapon_upgrade::WeaponLevel;
+use crate::plugins::weapons::Weapon;
+use crate::tests::harness::TestApp;
+
+/// Kayıtla birebir aynı olması gereken koşu durumu
+#[derive(PartialEq, Debug)]
+struct RunState {
+    /// Konum, can, altın, seviye, XP
+    player: (Vec3, u32, u32, i32, f32),
+    passives: Vec<(PassiveKind, i32)>,
+    /// Tip, seviye, hasar, ateş zamanlayıcısı
+    weapons: Vec<(WeaponId, i32, f32, Duration)>,
+    enemies: Vec<(EnemyKind, i32, [i32; 2])>,
+    /// Küre sayısı, toplam XP, eşya sayısı
+    floor: (usize, i32, usize),
+    elapsed: f32,
+    score: u32,
+    rng: GameRng,
+}
+
+fn run_state(test: &mut TestApp) -> RunState {
+    let player = test.player();
+    let world = test.world_mut();
+    let stats = world.get::<Player>(player).unwrap();
+    let position = world.get::<Transform>(player).unwrap().translation;
+    let player_state = (position, stats.health, stats.gold, stats.level, stats.xp);
+    let passives = world.get::<PassiveInventory>(player).unwrap().items.iter().map(|(kind, level)| (*kind, *level)).collect();
+
+    let mut weapons: Vec<_> = world
+        .query::<(&WeaponLevel, &Weapon)>()
+        .iter(world)
+        .map(|(level, weapon)| (level.weapon_type.clone(), level.level, weapon.damage, weapon.fire_timer.elapsed()))
+        .collect();
+    weapons.sort_by(|a, b| a.0.cmp(&b.0));
+    let mut enemies: Vec<_> = world
+        .query::<(&Enemy, &Transform)>()
+        .iter(world)
+        .map(|(enemy, transform)| (enemy.kind, enemy.health, transform.translation.truncate().round().as_ivec2().to_array()))
+        .collect();
+    enemies.sort();
+    let orbs: Vec<i32> = world.query::<&XP>().iter(world).map(|xp| xp.amount).collect();
+    let pickups = world.query::<&Pickup>().iter(world).count();
+
+    RunState {
+        player: player_state,
+        passives,
+        weapons,
+        enemies,
+        floor: (orbs.len(), orbs.iter().sum(), pickups),
+        elapsed: world.resource::<SpawnDirector>().elapsed,
+        score: world.resource::<GameScore>().score,
+        rng: world.resource::<GameRng>().clone(),
+    }
+}
+
+fn menu_buttons(test: &mut TestApp) -> Vec<MenuButton> {
+    let world = test.world_mut();
+    world.query::<&MenuButton>().iter(world).copied().collect()
+}
+
+#[test]
+fn save_and_quit_resumes_the_run_where_it_stopped() {
+    let mut test = TestApp::loaded();
+    let player = test.start_run();
+    test.advance(4.0);
+    let rocket = test.spawn_weapon(player, "rocket");
+    test.world_mut().get_mut::<WeaponLevel>(rocket).unwrap().level = 3;
+    test.world_mut().get_mut::<Player>(player).unwrap().gold = 30;
+    test.world_mut().get_mut::<PassiveInventory>(player).unwrap().items.insert(PassiveKind::Armor, 2);
+    // Toplama yarıçapının dışında
+    let position = test.world().get::<Transform>(player).unwrap().translation.truncate();
+    test.spawn_xp(position + Vec2::new(400.0, 0.0), 7);
+    test.spawn_pickup(position + Vec2::new(0.0, 400.0), Pickup::Gold(5));
+    test.advance(0.5);
+
+    test.press(KeyCode::Escape);
+    let expected = run_state(&mut test);
+    assert!(!expected.enemies.is_empty());
+    test.click(PauseButton::SaveAndQuit);
+    test.tick();
+    assert_eq!(test.state(), GameState::MainMenu);
+    // Altın profile değil, kayda yazıldı
+    assert_eq!(test.world().resource::<Profile>().gold, 0);
+    assert_eq!(RunSave::load(&test.run_save_path).unwrap().player.player.gold, 30);
+    assert!(menu_buttons(&mut test).contains(&MenuButton::Continue));
+
+    // Devam edilen frame'de oyun tick'i çalışmasın, dünya kayıtla birebir karşılaştırılsın
+    let timestep = test.world().resource::<Time<Fixed>>().timestep();
+    test.world_mut().insert_resource(TimeUpdateStrategy::ManualDuration(Duration::ZERO));
+    test.click(MenuButton::Continue);
+    test.wait_for_state(GameState::Playing);
+    assert_eq!(run_state(&mut test), expected);
+    // Aynı koşuya ikinci kez devam edilemez
+    assert!(!test.run_save_path.exists());
+
+    test.world_mut().insert_resource(TimeUpdateStrategy::ManualDuration(timestep));
+    test.advance(1.0);
+    assert!(test.world().resource::<SpawnDirector>().elapsed > expected.elapsed);
+
+    // Devam eden koşunun kaydı baştan oynatılamaz; altın yine de bankaya gider
+    let player = test.player();
+    let gold = test.world().get::<Player>(player).unwrap().gold;
+    test.set_state(GameState::GameOver);
+    assert!(!test.record_path.exists());
+    assert_eq!(test.world().resource::<Profile>().gold, gold);
+}
+
+#[test]
+fn continue_is_offered_only_for_a_readable_save() {
+    let mut test = TestApp::loaded();
+    assert!(!menu_buttons(&mut test).contains(&MenuButton::Continue));
+
+    std::fs::write(&test.run_save_path, "(version: 0)").unwrap();
+    test.start_run();
+    test.set_state(GameState::MainMenu);
+    assert!(menu_buttons(&mut test).contains(&MenuButton::Continue));
+
+    // Eski sürümün kaydı açılmaz, yedeklenir
+    test.click(MenuButton::Continue);
+    test.tick();
+    assert_eq!(test.state(), GameState::MainMenu);
+    assert!(!test.run_save_path.exists());
+    let backup = test.run_save_path.with_extension("ron.bak");
+    assert_eq!(std::fs::read_to_string(&backup).unwrap(), "(version: 0)");
+    std::fs::remove_file(backup).unwrap();
+}
+
+#[test]
+fn rng_streams_continue_after_a_round_trip() {
+    let mut rng = GameRng::new(7);
+    for _ in 0..5 {
+        rng.stream(RngStream::Spawning).random::<u64>();
+    }
+    let mut restored: GameRng = ron::from_str(&ron::to_string(&rng).unwrap()).unwrap();
+    assert_eq!(restored, rng);
+    for stream in RngStream::ALL {
+        assert_eq!(restored.stream(stream).random::<u64>(), rng.stream(stream).random::<u64>());
+    }
+}
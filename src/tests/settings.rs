@@ -0,0 +1,137 @@
+use bevy::prelude::*;
+use crate::plugins::audio::GameAudioEntity;
+use crate::plugins::game_state::GameState;
+use crate::plugins::localization::{Language, Localized, UiText};
+use crate::plugins::main_menu::MenuButton;
+use crate::plugins::pause_menu::PauseButton;
+use crate::plugins::screen_shake::ScreenShake;
+use crate::plugins::settings::{SettingField, Settings, SettingsFile, WindowModeSetting};
+use crate::plugins::settings_menu::{SettingsButton, SettingsMenuUI};
+use crate::tests::harness::TestApp;
+
+fn localized(test: &mut TestApp, text: UiText) -> String {
+    let world = test.world_mut();
+    world
+        .query::<(&Text, &Localized)>()
+        .iter(world)
+        .find(|(_, localized)| localized.0 == text)
+        .map(|(text, _)| text.0.clone())
+        .expect("missing localized text")
+}
+
+#[test]
+fn settings_round_trip_through_the_file() {
+    let test = TestApp::loaded();
+    let mut settings = Settings::default();
+    for field in [SettingField::SfxVolume, SettingField::WindowMode, SettingField::Resolution, SettingField::VSync, SettingField::ScreenShake, SettingField::Language] {
+        settings.cycle(field);
+    }
+    assert_eq!(settings.sfx_volume, 0.9);
+    assert_eq!(settings.window_mode, WindowModeSetting::Borderless);
+    assert_eq!(settings.screen_shake, 0.0);
+    assert_eq!(settings.language, Language::Turkish);
+
+    settings.save(&test.settings_path).unwrap();
+    assert_eq!(Settings::load(&test.settings_path).unwrap(), settings);
+    assert_eq!(Settings::from_file(&SettingsFile { path: Some(test.settings_path.clone()) }), settings);
+}
+
+#[test]
+fn missing_broken_or_partial_files_fall_back_to_defaults() {
+    let test = TestApp::loaded();
+    let path = test.settings_path.clone();
+    assert_eq!(Settings::from_file(&SettingsFile { path: Some(path.clone()) }), Settings::default());
+
+    std::fs::write(&path, "not ron at all").unwrap();
+    assert_eq!(Settings::from_file(&SettingsFile { path: Some(path.clone()) }), Settings::default());
+
+    // Eksik alanlar varsayılan, aralık dışı değerler kırpılır
+    std::fs::write(&path, "(sfx_volume: 0.3, master_volume: 4.0)").unwrap();
+    let settings = Settings::from_file(&SettingsFile { path: Some(path) });
+    assert_eq!(settings.sfx_volume, 0.3);
+    assert_eq!(settings.master_volume, 1.0);
+    assert_eq!(settings.music_volume, Settings::default().music_volume);
+}
+
+#[test]
+fn settings_menu_changes_and_saves_settings() {
+    let mut test = TestApp::loaded();
+    test.click(MenuButton::Settings);
+    test.tick();
+    assert_eq!(test.count::<With<SettingsMenuUI>>(), 1);
+
+    test.click(SettingsButton::Field(SettingField::DamageNumbers));
+    assert!(!test.world().resource::<Settings>().damage_numbers);
+
+    test.click(SettingsButton::Back);
+    test.tick();
+    assert_eq!(test.count::<With<SettingsMenuUI>>(), 0);
+    assert!(!Settings::load(&test.settings_path).unwrap().damage_numbers);
+}
+
+#[test]
+fn language_change_relabels_the_menus() {
+    let mut test = TestApp::loaded();
+    assert_eq!(localized(&mut test, UiText::Play), "Play");
+
+    test.click(MenuButton::Settings);
+    test.tick();
+    test.click(SettingsButton::Field(SettingField::Language));
+    test.tick();
+    assert_eq!(localized(&mut test, UiText::Play), "Oyna");
+    assert_eq!(localized(&mut test, UiText::Back), "Geri");
+}
+
+#[test]
+fn escape_closes_settings_before_resuming_the_pause() {
+    let mut test = TestApp::loaded();
+    test.start_run();
+    test.press(KeyCode::Escape);
+    test.click(PauseButton::Settings);
+    test.tick();
+    assert_eq!(test.count::<With<SettingsMenuUI>>(), 1);
+
+    test.press(KeyCode::Escape);
+    assert_eq!(test.state(), GameState::Paused);
+    assert_eq!(test.count::<With<SettingsMenuUI>>(), 0);
+    assert!(test.settings_path.exists());
+
+    test.press(KeyCode::Escape);
+    assert_eq!(test.state(), GameState::Playing);
+}
+
+#[test]
+fn sounds_use_music_and_effect_volumes() {
+    let mut test = TestApp::new();
+    {
+        let mut settings = test.world_mut().resource_mut::<Settings>();
+        settings.music_volume = 0.2;
+        settings.sfx_volume = 0.6;
+    }
+    let effect = test.world_mut().spawn((AudioPlayer::<AudioSource>(Handle::default()), PlaybackSettings::DESPAWN)).id();
+    let music = test.world_mut().spawn((GameAudioEntity, AudioPlayer::<AudioSource>(Handle::default()), PlaybackSettings::DESPAWN)).id();
+    test.tick();
+
+    let volume = |entity: Entity| test.world().get::<PlaybackSettings>(entity).unwrap().volume.to_linear();
+    assert_eq!(volume(effect), 0.6);
+    assert_eq!(volume(music), 0.2);
+}
+
+#[test]
+fn screen_shake_follows_the_intensity_setting() {
+    let mut test = TestApp::new();
+    let camera_position = |test: &mut TestApp| {
+        let world = test.world_mut();
+        world.query_filtered::<&Transform, With<Camera2d>>().single(world).unwrap().translation
+    };
+    let rest = camera_position(&mut test);
+
+    test.world_mut().resource_mut::<ScreenShake>().add_trauma(1.0);
+    test.tick();
+    assert_ne!(camera_position(&mut test), rest);
+
+    test.world_mut().resource_mut::<Settings>().screen_shake = 0.0;
+    test.world_mut().resource_mut::<ScreenShake>().add_trauma(1.0);
+    test.tick();
+    assert!(camera_position(&mut test).distance(rest) < 1e-3);
+}
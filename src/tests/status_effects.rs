@@ -0,0 +1,145 @@
+use bevy::prelude::*;
+use crate::plugins::enemy::{Enemy, EnemySprit};
+use crate::plugins::enemy_kind::{Charger, ChargerState};
+use crate::plugins::player::Player;
+use crate::plugins::status_effect::{StatusApplication, StatusEffects, StatusKind};
+use crate::plugins::weapon_definition::WeaponId;
+use crate::plugins::weapons::{Weapon, WeaponHitEvent};
+use crate::tests::harness::TestApp;
+
+fn effect(kind: StatusKind, duration: f32, magnitude: f32) -> StatusApplication {
+    StatusApplication { kind, duration, magnitude, chance: 1.0 }
+}
+
+fn apply(test: &mut TestApp, target: Entity, application: StatusApplication, source: Option<WeaponId>) {
+    test.world_mut().get_mut::<StatusEffects>(target).unwrap().apply(&application, source);
+}
+
+#[test]
+fn poison_stacks_and_burn_refreshes() {
+    let mut effects = StatusEffects::default();
+    for _ in 0..7 {
+        effects.apply(&effect(StatusKind::Poison, 3.0, 2.0), None);
+    }
+    assert_eq!(effects.get(StatusKind::Poison).unwrap().stacks, 5);
+
+    effects.apply(&effect(StatusKind::Burn, 2.0, 5.0), None);
+    effects.apply(&effect(StatusKind::Burn, 1.0, 8.0), None);
+    let burn = effects.get(StatusKind::Burn).unwrap().clone();
+    assert_eq!((burn.stacks, burn.remaining, burn.magnitude), (1, 2.0, 8.0));
+    assert_eq!(effects.effects.len(), 2);
+}
+
+#[test]
+fn burn_deals_damage_over_time_and_expires() {
+    let mut test = TestApp::new();
+    test.record::<WeaponHitEvent>();
+    test.spawn_player(Vec2::ZERO);
+    let enemy = test.spawn_enemy(Vec2::new(300.0, 0.0), 1000);
+    apply(&mut test, enemy, effect(StatusKind::Burn, 2.0, 10.0), Some(WeaponId::new("flame")));
+
+    test.advance(3.0);
+
+    assert_eq!(test.world().get::<Enemy>(enemy).unwrap().health, 980);
+    assert!(test.world().get::<StatusEffects>(enemy).unwrap().effects.is_empty());
+    let hits = test.recorded::<WeaponHitEvent>();
+    assert_eq!(hits.len(), 4);
+    assert!(hits.iter().all(|hit| hit.weapon == WeaponId::new("flame") && hit.damage == 5));
+}
+
+#[test]
+fn slow_and_freeze_reduce_enemy_movement() {
+    let mut test = TestApp::new();
+    test.spawn_player(Vec2::ZERO);
+    let starts = [Vec2::new(400.0, 0.0), Vec2::new(-400.0, 0.0), Vec2::new(0.0, 400.0)];
+    let [normal, slowed, frozen] = starts.map(|position| test.spawn_enemy(position, 1000));
+    for enemy in [normal, slowed, frozen] {
+        test.world_mut().get_mut::<Enemy>(enemy).unwrap().speed = 100.0;
+    }
+    apply(&mut test, slowed, effect(StatusKind::Slow, 5.0, 0.5), None);
+    apply(&mut test, frozen, effect(StatusKind::Freeze, 5.0, 0.0), None);
+
+    test.advance(1.0);
+
+    let moved = |enemy: Entity, start: Vec2| test.world().get::<Transform>(enemy).unwrap().translation.truncate().distance(start);
+    assert!((moved(normal, starts[0]) - 100.0).abs() < 2.0);
+    assert!((moved(slowed, starts[1]) - 50.0).abs() < 2.0);
+    assert_eq!(moved(frozen, starts[2]), 0.0);
+}
+
+#[test]
+fn vulnerable_enemies_take_extra_damage() {
+    let mut test = TestApp::new();
+    let player = test.spawn_player(Vec2::ZERO);
+    let laser = test.spawn_weapon(player, "laser");
+    let enemy = test.spawn_enemy(Vec2::new(150.0, 0.0), 1000);
+    apply(&mut test, enemy, effect(StatusKind::Vulnerable, 10.0, 0.5), None);
+
+    let hit = test.advance_until(3.0, |world| world.get::<Enemy>(enemy).unwrap().health < 1000);
+    assert!(hit);
+
+    let damage = test.world().get::<Weapon>(laser).unwrap().damage;
+    assert_eq!(test.world().get::<Enemy>(enemy).unwrap().health, 1000 - (damage * 1.5) as i32);
+}
+
+#[test]
+fn flame_applies_burn_and_tints_enemy_sprites() {
+    let mut test = TestApp::new();
+    let player = test.spawn_player(Vec2::ZERO);
+    test.spawn_weapon(player, "flame");
+    let enemy = test.spawn_enemy(Vec2::new(40.0, 0.0), 100_000);
+    let sprite = test
+        .world_mut()
+        .spawn((Sprite::default(), EnemySprit { index: 0, frames: 1, directional: false }, ChildOf(enemy)))
+        .id();
+
+    let tinted = test.advance_until(2.0, |world| world.get::<Sprite>(sprite).unwrap().color == StatusKind::Burn.tint());
+    assert!(tinted, "enemy sprite never took the burn tint");
+    let burn = test.world().get::<StatusEffects>(enemy).unwrap().get(StatusKind::Burn).unwrap().clone();
+    assert_eq!(burn.source, Some(WeaponId::new("flame")));
+
+    // Alev uzaklaşınca yanma biter ve renk geri döner
+    test.world_mut().get_mut::<Transform>(player).unwrap().translation = Vec3::new(-2000.0, 0.0, 0.0);
+    test.advance(burn.remaining + 0.5);
+    assert_eq!(test.world().get::<Sprite>(sprite).unwrap().color, Color::WHITE);
+}
+
+#[test]
+fn a_finished_charge_restores_the_status_tint() {
+    let mut test = TestApp::new();
+    test.spawn_player(Vec2::ZERO);
+    let charger = test.spawn_enemy(Vec2::new(300.0, 0.0), 1000);
+    test.world_mut().entity_mut(charger).insert(Charger {
+        state: ChargerState::Dashing,
+        timer: Timer::from_seconds(0.2, TimerMode::Once),
+        dash_direction: Vec3::ZERO,
+        trigger_range: 0.0,
+        windup: 0.5,
+        dash_speed: 0.0,
+        dash_duration: 0.2,
+        cooldown: 10.0,
+    });
+    let sprite = test
+        .world_mut()
+        .spawn((Sprite::default(), EnemySprit { index: 0, frames: 1, directional: false }, ChildOf(charger)))
+        .id();
+    apply(&mut test, charger, effect(StatusKind::Burn, 10.0, 0.0), None);
+
+    test.advance(0.5);
+
+    assert_eq!(test.world().get::<Charger>(charger).unwrap().state, ChargerState::Recovering);
+    assert_eq!(test.world().get::<Sprite>(sprite).unwrap().color, StatusKind::Burn.tint());
+}
+
+#[test]
+fn poison_damages_the_player() {
+    let mut test = TestApp::new();
+    let player = test.spawn_player(Vec2::ZERO);
+    for _ in 0..3 {
+        apply(&mut test, player, effect(StatusKind::Poison, 2.0, 2.0), None);
+    }
+
+    test.advance(3.0);
+
+    assert_eq!(test.world().get::<Player>(player).unwrap().health, 100 - 12);
+}
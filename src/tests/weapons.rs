@@ -0,0 +1,83 @@
+use bevy::prelude::*;
+use crate::plugins::enemy::Enemy;
+use crate::plugins::weapon_definition::WeaponId;
+use crate::plugins::weapons::{Explosion, RocketWeapon, Weapon, WeaponHitEvent};
+use crate::tests::harness::TestApp;
+
+const ENEMY_HEALTH: i32 = 1000;
+
+fn health(test: &TestApp, enemy: Entity) -> i32 {
+    test.world().get::<Enemy>(enemy).expect("enemy despawned").health
+}
+
+#[test]
+fn laser_hits_nearest_enemy() {
+    let mut test = TestApp::new();
+    test.record::<WeaponHitEvent>();
+    let player = test.spawn_player(Vec2::ZERO);
+    let laser = test.spawn_weapon(player, "laser");
+    let nearest = test.spawn_enemy(Vec2::new(150.0, 0.0), ENEMY_HEALTH);
+    let above = test.spawn_enemy(Vec2::new(0.0, 300.0), ENEMY_HEALTH);
+    let behind = test.spawn_enemy(Vec2::new(-350.0, 0.0), ENEMY_HEALTH);
+
+    let hit = test.advance_until(3.0, |world| world.get::<Enemy>(nearest).unwrap().health < ENEMY_HEALTH);
+    assert!(hit, "laser never reached the nearest enemy");
+
+    let damage = test.world().get::<Weapon>(laser).unwrap().damage as i32;
+    assert_eq!(health(&test, nearest), ENEMY_HEALTH - damage);
+    assert_eq!(health(&test, above), ENEMY_HEALTH);
+    assert_eq!(health(&test, behind), ENEMY_HEALTH);
+
+    let hits = test.recorded::<WeaponHitEvent>();
+    assert_eq!(hits.len(), 1);
+    assert_eq!(hits[0].weapon, WeaponId::new("laser"));
+    assert_eq!(hits[0].damage, damage);
+}
+
+#[test]
+fn laser_kill_drops_xp_and_scores() {
+    let mut test = TestApp::new();
+    let player = test.spawn_player(Vec2::ZERO);
+    test.spawn_weapon(player, "laser");
+    let enemy = test.spawn_enemy(Vec2::new(100.0, 0.0), 1);
+
+    let killed = test.advance_until(3.0, |world| world.get_entity(enemy).is_err());
+    assert!(killed, "enemy survived the laser");
+    assert_eq!(test.count::<With<crate::plugins::enemy::XP>>(), 1);
+    assert_eq!(test.world().get::<crate::plugins::player::Player>(player).unwrap().score, 1);
+}
+
+#[test]
+fn rocket_explosion_hits_everything_in_radius() {
+    let mut test = TestApp::new();
+    test.record::<WeaponHitEvent>();
+    let player = test.spawn_player(Vec2::ZERO);
+    let rocket = test.spawn_weapon(player, "rocket");
+    let target = test.spawn_enemy(Vec2::new(200.0, 0.0), ENEMY_HEALTH);
+    let inside = [
+        test.spawn_enemy(Vec2::new(200.0, 60.0), ENEMY_HEALTH),
+        test.spawn_enemy(Vec2::new(200.0, -60.0), ENEMY_HEALTH),
+    ];
+    let outside = [
+        test.spawn_enemy(Vec2::new(200.0, 170.0), ENEMY_HEALTH),
+        test.spawn_enemy(Vec2::new(200.0, -170.0), ENEMY_HEALTH),
+    ];
+
+    let exploded = test.advance_until(3.0, |world| {
+        world.query_filtered::<(), With<Explosion>>().iter(world).next().is_some()
+    });
+    assert!(exploded, "rocket never exploded");
+
+    let radius = test.world().get::<RocketWeapon>(rocket).unwrap().explosion_radius;
+    assert!(radius < 170.0 - 25.0, "explosion radius {} covers the outside enemies", radius);
+    let damage = test.world().get::<Weapon>(rocket).unwrap().damage as i32;
+    for enemy in std::iter::once(target).chain(inside) {
+        assert_eq!(health(&test, enemy), ENEMY_HEALTH - damage);
+    }
+    for enemy in outside {
+        assert_eq!(health(&test, enemy), ENEMY_HEALTH);
+    }
+    let hits = test.recorded::<WeaponHitEvent>();
+    assert_eq!(hits.len(), 3);
+    assert!(hits.iter().all(|hit| hit.weapon == WeaponId::new("rocket")));
+}
@@ -0,0 +1,66 @@
+use bevy::prelude::*;
+use crate::plugins::enemy::XP;
+use crate::plugins::passive_item::{PassiveInventory, PassiveKind, BASE_PICKUP_RADIUS};
+use crate::plugins::player::{Player, XPMagnetite};
+use crate::plugins::xp_orb::{XpTier, MAX_XP_ORBS};
+use crate::tests::harness::TestApp;
+
+fn xp(test: &TestApp, player: Entity) -> f32 {
+    test.world().get::<Player>(player).unwrap().xp
+}
+
+#[test]
+fn orbs_in_the_pickup_radius_fly_to_the_player() {
+    let mut test = TestApp::new();
+    let player = test.spawn_player(Vec2::ZERO);
+    let near = test.spawn_xp(Vec2::new(BASE_PICKUP_RADIUS - 10.0, 0.0), 3);
+    let far = test.spawn_xp(Vec2::new(BASE_PICKUP_RADIUS + 100.0, 0.0), 5);
+
+    test.tick();
+    // Durarak başlar, zamanla hızlanır
+    let speed = test.world().get::<XPMagnetite>(near).unwrap().speed;
+    test.advance(0.05);
+    assert!(test.world().get::<XPMagnetite>(near).unwrap().speed > speed);
+
+    test.advance(0.5);
+    assert_eq!(xp(&test, player), 3.0);
+    assert!(test.world().get_entity(near).is_err());
+    assert!(test.world().get::<XPMagnetite>(far).is_none());
+}
+
+#[test]
+fn magnet_passive_grows_the_pickup_radius() {
+    let mut test = TestApp::new();
+    let player = test.spawn_player(Vec2::ZERO);
+    test.world_mut().get_mut::<PassiveInventory>(player).unwrap().items.insert(PassiveKind::Magnet, 5);
+    test.tick();
+    test.spawn_xp(Vec2::new(BASE_PICKUP_RADIUS + 150.0, 0.0), 5);
+
+    test.advance(1.0);
+    assert_eq!(xp(&test, player), 5.0);
+}
+
+#[test]
+fn gem_tier_follows_value() {
+    assert_eq!(XpTier::for_amount(5), XpTier::Blue);
+    assert_eq!(XpTier::for_amount(35), XpTier::Green);
+    assert_eq!(XpTier::for_amount(80), XpTier::Red);
+}
+
+#[test]
+fn far_orbs_merge_when_there_are_too_many() {
+    let mut test = TestApp::new();
+    test.spawn_player(Vec2::ZERO);
+    let extra = 10;
+    for i in 0..MAX_XP_ORBS + extra {
+        test.spawn_xp(Vec2::new(500.0 + i as f32 * 10.0, 0.0), 1);
+    }
+    test.tick();
+
+    let world = test.world_mut();
+    let amounts: Vec<i32> = world.query::<&XP>().iter(world).map(|xp| xp.amount).collect();
+    assert_eq!(amounts.len(), MAX_XP_ORBS);
+    assert_eq!(amounts.iter().sum::<i32>(), (MAX_XP_ORBS + extra) as i32);
+    // En uzaktakiler kalanların en uzağında toplandı
+    assert_eq!(amounts.iter().max(), Some(&(extra as i32 + 1)));
+}